@@ -0,0 +1,59 @@
+use crate::errors::update_error::UpdateError;
+use crate::partial_download::PartialDownloadGuard;
+use bytes::Bytes;
+use reqwest::Response;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+
+/// Where the content of an asset being downloaded comes from.
+pub(crate) enum AssetSource {
+    /// An asset downloaded from GitHub, or from any HTTP server.
+    Http(Response),
+    /// An asset mirrored on the local file system, see `ReleaseSource::LocalJson`.
+    File(File),
+    /// An asset downloaded by an external downloader into a temporary directory, which is removed
+    /// along with the body, see `GithubUpdater::with_external_downloader`.
+    DownloadedFile(File, #[allow(dead_code)] PartialDownloadGuard),
+}
+
+/// The content of an asset being downloaded, read in chunks of at most `chunk_size` bytes.
+pub(crate) struct AssetBody {
+    source: AssetSource,
+    chunk_size: usize,
+    pending: Bytes,
+}
+
+impl AssetBody {
+    pub(crate) fn new(source: AssetSource, chunk_size: usize) -> Self {
+        Self {
+            source,
+            chunk_size: chunk_size.max(1),
+            pending: Bytes::new(),
+        }
+    }
+
+    /// Returns the next chunk of the asset, or `None` once the whole asset has been read.
+    pub(crate) async fn next_chunk(&mut self) -> Result<Option<Bytes>, UpdateError> {
+        if self.pending.is_empty() {
+            self.pending = match &mut self.source {
+                AssetSource::Http(response) => match response.chunk().await? {
+                    Some(chunk) => chunk,
+                    None => return Ok(None),
+                },
+                AssetSource::File(file) | AssetSource::DownloadedFile(file, _) => {
+                    let mut buffer: Vec<u8> = vec![0; self.chunk_size];
+                    let read_length: usize = file.read(&mut buffer).await?;
+                    if read_length == 0 {
+                        return Ok(None);
+                    }
+                    buffer.truncate(read_length);
+                    Bytes::from(buffer)
+                }
+            };
+        }
+
+        // Chunks received from the network may be larger than requested
+        let length: usize = self.pending.len().min(self.chunk_size);
+        Ok(Some(self.pending.split_to(length)))
+    }
+}
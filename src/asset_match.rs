@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Asset match struct, selecting the assets by the substrings of their name, see
+/// `GithubUpdater::with_asset_match`.
+///
+/// An asset matches if its name contains all the `must_contain` substrings and none of the
+/// `must_not_contain` ones. The comparison is case-sensitive.
+///
+/// # Example
+///
+/// ```rust
+/// use github_updater::AssetMatch;
+///
+/// let asset_match = AssetMatch {
+///     must_contain: vec!["linux".to_owned(), "musl".to_owned()],
+///     must_not_contain: vec!["debug".to_owned(), "sha256".to_owned()],
+/// };
+///
+/// assert!(asset_match.matches("afetch-x86_64-unknown-linux-musl"));
+/// assert!(!asset_match.matches("afetch-x86_64-unknown-linux-gnu"));
+/// assert!(!asset_match.matches("afetch-x86_64-unknown-linux-musl-debug"));
+/// assert!(!asset_match.matches("afetch-x86_64-unknown-linux-musl.sha256"));
+/// assert!(AssetMatch::default().matches("afetch"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetMatch {
+    /// The substrings the name of the asset must all contain, e.g.: `linux` and `musl`.
+    #[serde(default)]
+    pub must_contain: Vec<String>,
+    /// The substrings the name of the asset must not contain, e.g.: `debug` or `sha256`.
+    #[serde(default)]
+    pub must_not_contain: Vec<String>,
+}
+
+impl AssetMatch {
+    /// Returns whether the asset named `asset_name` matches.
+    pub fn matches(&self, asset_name: &str) -> bool {
+        self.must_contain
+            .iter()
+            .all(|substring| asset_name.contains(substring.as_str()))
+            && !self
+                .must_not_contain
+                .iter()
+                .any(|substring| asset_name.contains(substring.as_str()))
+    }
+}
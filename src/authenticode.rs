@@ -0,0 +1,137 @@
+use crate::errors::update_error::UpdateError;
+use std::os::windows::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use windows_sys::Win32::Foundation::FALSE;
+use windows_sys::Win32::Security::Cryptography::{
+    CertGetNameStringW, CERT_NAME_SIMPLE_DISPLAY_TYPE,
+};
+use windows_sys::Win32::Security::WinTrust::{
+    WTHelperGetProvCertFromChain, WTHelperGetProvSignerFromChain, WTHelperProvDataFromStateData,
+    WinVerifyTrust, WINTRUST_ACTION_GENERIC_VERIFY_V2, WINTRUST_DATA, WINTRUST_DATA_0,
+    WINTRUST_FILE_INFO, WTD_CHOICE_FILE, WTD_REVOCATION_CHECK_CHAIN_EXCLUDE_ROOT,
+    WTD_REVOKE_WHOLECHAIN, WTD_STATEACTION_CLOSE, WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+};
+
+/// Verifies the Authenticode signature of `file` with `WinVerifyTrust`, then, if
+/// `expected_subject` is set, that it is the simple display name (usually the common name) of the
+/// signing certificate.
+///
+/// The calls are blocking, so they are run on the blocking thread pool of Tokio.
+pub(crate) async fn verify(file: &Path, expected_subject: Option<&str>) -> Result<(), UpdateError> {
+    let file: PathBuf = file.to_owned();
+    let expected_subject: Option<String> = expected_subject.map(str::to_owned);
+
+    tokio::task::spawn_blocking(move || verify_blocking(&file, expected_subject.as_deref()))
+        .await
+        .map_err(|error| UpdateError::new(format!("Authenticode verification failed: {}", error)))?
+}
+
+fn verify_blocking(file: &Path, expected_subject: Option<&str>) -> Result<(), UpdateError> {
+    let wide_path: Vec<u16> = file.as_os_str().encode_wide().chain([0]).collect();
+    let mut file_info = WINTRUST_FILE_INFO {
+        cbStruct: std::mem::size_of::<WINTRUST_FILE_INFO>() as u32,
+        pcwszFilePath: wide_path.as_ptr(),
+        hFile: std::ptr::null_mut(),
+        pgKnownSubject: std::ptr::null_mut(),
+    };
+    let mut trust_data = WINTRUST_DATA {
+        cbStruct: std::mem::size_of::<WINTRUST_DATA>() as u32,
+        pPolicyCallbackData: std::ptr::null_mut(),
+        pSIPClientData: std::ptr::null_mut(),
+        dwUIChoice: WTD_UI_NONE,
+        fdwRevocationChecks: WTD_REVOKE_WHOLECHAIN,
+        dwUnionChoice: WTD_CHOICE_FILE,
+        Anonymous: WINTRUST_DATA_0 {
+            pFile: &mut file_info,
+        },
+        dwStateAction: WTD_STATEACTION_VERIFY,
+        hWVTStateData: std::ptr::null_mut(),
+        pwszURLReference: std::ptr::null_mut(),
+        dwProvFlags: WTD_REVOCATION_CHECK_CHAIN_EXCLUDE_ROOT,
+        dwUIContext: 0,
+        pSignatureSettings: std::ptr::null_mut(),
+    };
+    let mut action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+
+    // SAFETY: `trust_data` and `file_info` are valid for the duration of the calls, and the state
+    // data allocated by the verification is released by the `WTD_STATEACTION_CLOSE` call.
+    unsafe {
+        let status: i32 = WinVerifyTrust(
+            std::ptr::null_mut(),
+            &mut action,
+            &mut trust_data as *mut WINTRUST_DATA as *mut _,
+        );
+        let result = if status != 0 {
+            Err(UpdateError::new(format!(
+                "Authenticode verification failed: WinVerifyTrust returned 0x{:08X}.",
+                status as u32
+            )))
+        } else if let Some(expected_subject) = expected_subject {
+            match signer_name(&trust_data) {
+                Some(subject) if subject == expected_subject => Ok(()),
+                Some(subject) => Err(UpdateError::new(format!(
+                    "Authenticode verification failed: the file is signed by \"{}\" instead of \"{}\".",
+                    subject, expected_subject
+                ))),
+                None => Err(UpdateError::new(
+                    "Authenticode verification failed: unable to read the signer name.".to_owned(),
+                )),
+            }
+        } else {
+            Ok(())
+        };
+
+        trust_data.dwStateAction = WTD_STATEACTION_CLOSE;
+        WinVerifyTrust(
+            std::ptr::null_mut(),
+            &mut action,
+            &mut trust_data as *mut WINTRUST_DATA as *mut _,
+        );
+
+        result
+    }
+}
+
+/// Returns the simple display name of the signing certificate of a successful verification.
+///
+/// # Safety
+///
+/// `trust_data` must hold the state data of a `WTD_STATEACTION_VERIFY` call not yet closed.
+unsafe fn signer_name(trust_data: &WINTRUST_DATA) -> Option<String> {
+    let provider_data = WTHelperProvDataFromStateData(trust_data.hWVTStateData);
+    if provider_data.is_null() {
+        return None;
+    }
+    let signer = WTHelperGetProvSignerFromChain(provider_data, 0, FALSE, 0);
+    if signer.is_null() {
+        return None;
+    }
+    let certificate = WTHelperGetProvCertFromChain(signer, 0);
+    if certificate.is_null() || (*certificate).pCert.is_null() {
+        return None;
+    }
+
+    let length: u32 = CertGetNameStringW(
+        (*certificate).pCert,
+        CERT_NAME_SIMPLE_DISPLAY_TYPE,
+        0,
+        std::ptr::null(),
+        std::ptr::null_mut(),
+        0,
+    );
+    if length <= 1 {
+        return None;
+    }
+    let mut name: Vec<u16> = vec![0; length as usize];
+    CertGetNameStringW(
+        (*certificate).pCert,
+        CERT_NAME_SIMPLE_DISPLAY_TYPE,
+        0,
+        std::ptr::null(),
+        name.as_mut_ptr(),
+        length,
+    );
+    name.truncate(length as usize - 1);
+
+    Some(String::from_utf16_lossy(&name))
+}
@@ -0,0 +1,73 @@
+use crate::errors::update_error::UpdateError;
+use crate::{DownloadInfos, GithubUpdater, UpdateStatus};
+use tokio::runtime::Runtime;
+
+/// Blocking wrapper of `GithubUpdater`, for applications without a Tokio runtime, e.g.: a simple
+/// synchronous CLI. This wrapper requires the `blocking` feature.
+///
+/// Each call runs the asynchronous implementation to completion on a runtime owned by the
+/// wrapper, so the updater behaves exactly as configured with its builder, client included.
+///
+/// The methods must not be called from an asynchronous context, e.g.: inside `#[tokio::main]`:
+/// blocking a runtime thread this way panics. Use `GithubUpdater` directly there.
+///
+/// # Example
+///
+/// ```rust
+/// use github_updater::{GithubUpdater, GithubUpdaterBlocking};
+///
+/// let updater_builder = GithubUpdater::builder()
+///     .with_initialized_reqwest_client()
+///     .with_app_name("afetch")
+///     .with_repository("Asthowen/AFetch")
+///     .with_release_file_name_pattern("{app_name}")
+///     .with_download_path(&std::env::temp_dir())
+///     .build()
+///     .unwrap();
+/// let updater = GithubUpdaterBlocking::new(updater_builder).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct GithubUpdaterBlocking {
+    updater: GithubUpdater,
+    runtime: Runtime,
+}
+
+impl GithubUpdaterBlocking {
+    /// Wraps a built `GithubUpdater`, creating the runtime on which its calls are run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) if the runtime cannot be created.
+    pub fn new(updater: GithubUpdater) -> Result<Self, UpdateError> {
+        let runtime: Runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self { updater, runtime })
+    }
+
+    /// Returns the wrapped updater, e.g.: to read its options.
+    pub fn updater(&self) -> &GithubUpdater {
+        &self.updater
+    }
+
+    /// Returns the wrapped updater, dropping the runtime.
+    pub fn into_inner(self) -> GithubUpdater {
+        self.updater
+    }
+
+    /// See `GithubUpdater::check_for_update`.
+    pub fn check_for_update(&mut self) -> Result<UpdateStatus, UpdateError> {
+        self.runtime.block_on(self.updater.check_for_update())
+    }
+
+    /// See `GithubUpdater::update_if_needed`.
+    pub fn update_if_needed(&mut self) -> Result<DownloadInfos, UpdateError> {
+        self.runtime.block_on(self.updater.update_if_needed())
+    }
+
+    /// See `GithubUpdater::force_update`.
+    pub fn force_update(&mut self) -> Result<DownloadInfos, UpdateError> {
+        self.runtime.block_on(self.updater.force_update())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::errors::update_error::UpdateError;
+use async_compression::tokio::bufread::{GzipDecoder, XzDecoder, ZstdDecoder};
+use std::path::{Path, PathBuf};
+use tokio::fs::File;
+use tokio::io::BufReader;
+
+/// The compression of a single-stream compressed asset, e.g.: `afetch.gz`, decompressed before
+/// being stored, see `GithubUpdater::with_store_decompressed`.
+///
+/// Archives holding several files, such as tarballs, are not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// The gzip format, e.g.: an asset compressed with `gzip`.
+    Gzip,
+    /// The Zstandard format, e.g.: an asset compressed with `zstd`.
+    Zstd,
+    /// The xz format, e.g.: an asset compressed with `xz`.
+    Xz,
+}
+
+impl Compression {
+    /// Decompresses `file` in place, through a temporary file next to it.
+    pub(crate) async fn decompress_file(&self, file: &Path) -> Result<(), UpdateError> {
+        let mut decompressed_file = file.as_os_str().to_owned();
+        decompressed_file.push(".decompressed");
+        let decompressed_file: PathBuf = PathBuf::from(decompressed_file);
+
+        let reader = BufReader::new(File::open(file).await?);
+        let mut output: File = File::create(&decompressed_file).await?;
+        let result: Result<u64, std::io::Error> = match self {
+            Self::Gzip => tokio::io::copy(&mut GzipDecoder::new(reader), &mut output).await,
+            Self::Zstd => tokio::io::copy(&mut ZstdDecoder::new(reader), &mut output).await,
+            Self::Xz => tokio::io::copy(&mut XzDecoder::new(reader), &mut output).await,
+        };
+        if let Err(error) = result {
+            let _ = tokio::fs::remove_file(&decompressed_file).await;
+            return Err(UpdateError::new(format!(
+                "Unable to decompress the asset ({:?}): {}",
+                self, error
+            )));
+        }
+        tokio::fs::rename(&decompressed_file, file).await?;
+
+        Ok(())
+    }
+}
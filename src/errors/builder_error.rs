@@ -0,0 +1,29 @@
+use crate::errors::builder_invalid_element::BuilderInvalidElement;
+use crate::errors::builder_missing_element::BuilderMissingElement;
+
+#[derive(Debug, Clone)]
+pub enum BuilderError {
+    MissingElement(BuilderMissingElement),
+    InvalidElement(BuilderInvalidElement),
+}
+
+impl std::fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BuilderError::MissingElement(error) => write!(f, "{}", error),
+            BuilderError::InvalidElement(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<BuilderMissingElement> for BuilderError {
+    fn from(error: BuilderMissingElement) -> Self {
+        BuilderError::MissingElement(error)
+    }
+}
+
+impl From<BuilderInvalidElement> for BuilderError {
+    fn from(error: BuilderInvalidElement) -> Self {
+        BuilderError::InvalidElement(error)
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone)]
+pub struct BuilderInvalidElement(pub String);
+
+impl std::fmt::Display for BuilderInvalidElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "An element filled in the builder is invalid: {}", self.0)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::errors::error_kind::ErrorKind;
+use crate::errors::update_error::UpdateError;
+
+/// Error returned when a download, or one of its redirects, targets a host which is not in the
+/// allowlist set with `GithubUpdater::with_allowed_download_hosts`.
+///
+/// The error is returned as an `UpdateError` of the `ErrorKind::DisallowedHost` kind, which
+/// `DisallowedHost::from_error` retrieves.
+///
+/// # Example
+///
+/// ```rust
+/// use github_updater::errors::disallowed_host::DisallowedHost;
+/// use github_updater::errors::update_error::UpdateError;
+///
+/// let error: UpdateError = DisallowedHost {
+///     host: "example.com".to_owned(),
+/// }
+/// .into();
+/// assert_eq!(DisallowedHost::from_error(&error).unwrap().host, "example.com");
+/// assert!(DisallowedHost::from_error(&UpdateError::new("Timeout.".to_owned())).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisallowedHost {
+    /// The host which is not allowed, empty for a URL without host, e.g.: a local file.
+    pub host: String,
+}
+
+impl DisallowedHost {
+    /// Returns the disallowed host which caused `error`, or `None` if it has another cause.
+    pub fn from_error(error: &UpdateError) -> Option<Self> {
+        match error.kind() {
+            ErrorKind::DisallowedHost(disallowed_host) => Some(disallowed_host.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DisallowedHost {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Downloads from the host \"{}\" are not allowed.",
+            self.host
+        )
+    }
+}
+
+impl From<DisallowedHost> for UpdateError {
+    fn from(error: DisallowedHost) -> Self {
+        UpdateError::with_kind(error.to_string(), ErrorKind::DisallowedHost(error))
+    }
+}
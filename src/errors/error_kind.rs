@@ -0,0 +1,41 @@
+use crate::errors::disallowed_host::DisallowedHost;
+use crate::errors::secondary_rate_limited::SecondaryRateLimited;
+
+/// The cause of an `UpdateError`, for the errors callers may want to handle specifically.
+///
+/// # Example
+///
+/// ```rust
+/// use github_updater::errors::error_kind::ErrorKind;
+/// use github_updater::errors::update_error::UpdateError;
+///
+/// let error = UpdateError::new("Timeout.");
+/// assert!(matches!(error.kind(), ErrorKind::Other));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A download started while the release is not fetched, e.g.: without any asset URL, call
+    /// `GithubUpdater::fetch_last_release` first.
+    NotFetched,
+    /// The file system of the download path does not have enough space for the asset.
+    InsufficientDiskSpace {
+        /// The space needed, in bytes.
+        needed: u64,
+        /// The space available, in bytes.
+        available: u64,
+    },
+    /// A download targets a host which is not allowed, see `DisallowedHost`.
+    DisallowedHost(DisallowedHost),
+    /// GitHub rejected a request because of its secondary rate limits, see
+    /// `SecondaryRateLimited`.
+    SecondaryRateLimited(SecondaryRateLimited),
+    /// The Sigstore signature of the asset is missing or invalid, see
+    /// `GithubUpdater::with_sigstore_verification`.
+    SignatureVerificationFailed,
+    /// The provenance attestation of the asset is missing, cannot be downloaded or does not match
+    /// the asset, see `GithubUpdater::with_provenance_verification`.
+    ProvenanceVerificationFailed,
+    /// Any other cause, only described by the message of the error.
+    Other,
+}
@@ -1,3 +1,8 @@
+pub mod builder_error;
+pub mod builder_invalid_element;
 pub mod builder_missing_element;
 pub mod builder_not_initialized;
+pub mod disallowed_host;
+pub mod error_kind;
+pub mod secondary_rate_limited;
 pub mod update_error;
@@ -0,0 +1,67 @@
+use crate::errors::error_kind::ErrorKind;
+use crate::errors::update_error::UpdateError;
+use std::time::Duration;
+
+/// Error returned when GitHub rejects a request because of its secondary rate limits, which
+/// restrict bursts of requests independently of the quota of the primary rate limit.
+///
+/// The error is returned as an `UpdateError` of the `ErrorKind::SecondaryRateLimited` kind, which
+/// `SecondaryRateLimited::from_error` retrieves. The requests rejected this way are retried by
+/// default, as configured with `GithubUpdater::with_retry`, waiting at least the delay requested
+/// by GitHub.
+///
+/// # Example
+///
+/// ```rust
+/// use github_updater::errors::secondary_rate_limited::SecondaryRateLimited;
+/// use github_updater::errors::update_error::UpdateError;
+/// use std::time::Duration;
+///
+/// let error: UpdateError = SecondaryRateLimited {
+///     retry_after: Some(Duration::from_secs(30)),
+/// }
+/// .into();
+/// let secondary_rate_limit = SecondaryRateLimited::from_error(&error).unwrap();
+/// assert_eq!(secondary_rate_limit.retry_after, Some(Duration::from_secs(30)));
+/// assert!(SecondaryRateLimited::from_error(&UpdateError::new("Timeout.")).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecondaryRateLimited {
+    /// The delay requested by GitHub in the `Retry-After` header before retrying. When it is not
+    /// set, GitHub advises to wait at least one minute.
+    pub retry_after: Option<Duration>,
+}
+
+impl SecondaryRateLimited {
+    /// Returns the secondary rate limit which caused `error`, or `None` if it has another cause.
+    pub fn from_error(error: &UpdateError) -> Option<Self> {
+        match error.kind() {
+            ErrorKind::SecondaryRateLimited(secondary_rate_limited) => {
+                Some(secondary_rate_limited.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SecondaryRateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.retry_after {
+            Some(retry_after) => write!(
+                f,
+                "GitHub secondary rate limit exceeded, retry after {} seconds.",
+                retry_after.as_secs()
+            ),
+            None => write!(
+                f,
+                "GitHub secondary rate limit exceeded, wait at least one minute before retrying."
+            ),
+        }
+    }
+}
+
+impl From<SecondaryRateLimited> for UpdateError {
+    fn from(error: SecondaryRateLimited) -> Self {
+        UpdateError::with_kind(error.to_string(), ErrorKind::SecondaryRateLimited(error))
+    }
+}
@@ -1,9 +1,34 @@
 use crate::errors::builder_not_initialized::BuilderNotInitialized;
-use reqwest::header::ToStrError;
+use crate::errors::error_kind::ErrorKind;
+use reqwest::header::{InvalidHeaderValue, ToStrError};
 use std::num::ParseIntError;
 
+/// Error returned by the updater: a message, along with the `ErrorKind` of its cause, on which
+/// callers can match with `UpdateError::kind`.
 #[derive(Debug, Clone)]
-pub struct UpdateError(pub String);
+pub struct UpdateError(pub String, ErrorKind);
+
+impl UpdateError {
+    /// Returns an error of the `ErrorKind::Other` kind, described by `message`.
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        UpdateError(message.into(), ErrorKind::Other)
+    }
+
+    /// Returns an error of the given `kind`, described by `message`.
+    pub(crate) fn with_kind<S: Into<String>>(message: S, kind: ErrorKind) -> Self {
+        UpdateError(message.into(), kind)
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.1
+    }
+}
 
 impl std::fmt::Display for UpdateError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -13,28 +38,37 @@ impl std::fmt::Display for UpdateError {
 
 impl From<reqwest::Error> for UpdateError {
     fn from(error: reqwest::Error) -> Self {
-        UpdateError(format!("A reqwest error has occurred: {}", error))
+        UpdateError::new(format!("A reqwest error has occurred: {}", error))
     }
 }
 
 impl From<std::io::Error> for UpdateError {
     fn from(error: std::io::Error) -> Self {
-        UpdateError(format!("A std io error has occurred: {}", error))
+        UpdateError::new(format!("A std io error has occurred: {}", error))
     }
 }
 
 impl From<ToStrError> for UpdateError {
     fn from(error: ToStrError) -> Self {
-        UpdateError(format!(
+        UpdateError::new(format!(
             "A error has occurred when converting header to str: {}",
             error
         ))
     }
 }
 
+impl From<InvalidHeaderValue> for UpdateError {
+    fn from(error: InvalidHeaderValue) -> Self {
+        UpdateError::new(format!(
+            "A error has occurred when creating a header: {}",
+            error
+        ))
+    }
+}
+
 impl From<ParseIntError> for UpdateError {
     fn from(error: ParseIntError) -> Self {
-        UpdateError(format!(
+        UpdateError::new(format!(
             "A error has occurred when converting string to integer: {}",
             error
         ))
@@ -43,6 +77,6 @@ impl From<ParseIntError> for UpdateError {
 
 impl From<BuilderNotInitialized> for UpdateError {
     fn from(_: BuilderNotInitialized) -> Self {
-        UpdateError("You must call the build method on the builder to use it.".to_owned())
+        UpdateError::new("You must call the build method on the builder to use it.".to_owned())
     }
 }
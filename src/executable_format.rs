@@ -0,0 +1,73 @@
+/// The number of leading bytes needed to recognize an executable format.
+pub(crate) const MAGIC_BYTES_LENGTH: usize = 4;
+
+/// Executable formats which can be recognized from the first bytes of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExecutableFormat {
+    Elf,
+    MachO,
+    Pe,
+}
+
+impl ExecutableFormat {
+    /// Returns the format expected for a Rust target, e.g.: `x86_64-pc-windows-msvc`, or for an
+    /// operating system name as given by `std::env::consts::OS`, e.g.: `linux`.
+    ///
+    /// Returns `None` when the format cannot be inferred, e.g.: for `wasm32-unknown-unknown`.
+    pub(crate) fn expected_for_target(target: &str) -> Option<Self> {
+        const ELF_SYSTEMS: [&str; 9] = [
+            "linux",
+            "android",
+            "freebsd",
+            "netbsd",
+            "openbsd",
+            "dragonfly",
+            "solaris",
+            "illumos",
+            "haiku",
+        ];
+        const MACH_O_SYSTEMS: [&str; 6] = ["apple", "darwin", "macos", "ios", "tvos", "watchos"];
+
+        let components: Vec<&str> = target.split('-').collect();
+        if components.contains(&"windows") {
+            Some(Self::Pe)
+        } else if components
+            .iter()
+            .any(|component| MACH_O_SYSTEMS.contains(component))
+        {
+            Some(Self::MachO)
+        } else if components
+            .iter()
+            .any(|component| ELF_SYSTEMS.contains(component))
+        {
+            Some(Self::Elf)
+        } else {
+            None
+        }
+    }
+
+    /// Recognizes the format of a file from its first bytes.
+    ///
+    /// Returns `None` for anything which is not an executable, e.g.: an archive or a script.
+    pub(crate) fn detect(magic_bytes: &[u8]) -> Option<Self> {
+        match magic_bytes {
+            [0x7F, b'E', b'L', b'F', ..] => Some(Self::Elf),
+            // 32 and 64 bits Mach-O in both endiannesses, then universal binaries.
+            [0xFE, 0xED, 0xFA, 0xCE | 0xCF, ..]
+            | [0xCE | 0xCF, 0xFA, 0xED, 0xFE, ..]
+            | [0xCA, 0xFE, 0xBA, 0xBE, ..] => Some(Self::MachO),
+            [b'M', b'Z', ..] => Some(Self::Pe),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ExecutableFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Elf => write!(f, "ELF"),
+            Self::MachO => write!(f, "Mach-O"),
+            Self::Pe => write!(f, "PE"),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::errors::update_error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use tokio::io::AsyncWriteExt;
+
+/// External downloader struct, describing the program downloading the assets instead of the
+/// crate, see `GithubUpdater::with_external_downloader`.
+///
+/// The arguments may contain the following placeholders:
+/// * `{url}`: the URL of the asset;
+/// * `{output}`: the path of the file to create;
+/// * `{output_dir}` and `{output_name}`: the directory and the name of this file;
+/// * `{config}`: the path of the configuration file passing the request headers.
+///
+/// The request headers, e.g.: the `User-Agent` or the GitHub token, are never put on the command
+/// line, where other users could read them. They are written to a configuration file readable by
+/// the current user only, one `config_line` per header with the `{header}` placeholder replaced by
+/// `Name: value`, which is removed once the program exits.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalDownloader {
+    /// The path of the program, or its name to search it in the `PATH`.
+    pub program: PathBuf,
+    /// The arguments of the program.
+    pub args: Vec<String>,
+    /// The line of the configuration file passing a request header to the program.
+    pub config_line: String,
+}
+
+impl ExternalDownloader {
+    /// Returns the configuration of `aria2c`, downloading with up to 8 connections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::ExternalDownloader;
+    ///
+    /// let downloader = ExternalDownloader::aria2c();
+    /// assert_eq!(downloader.program.to_str(), Some("aria2c"));
+    /// ```
+    pub fn aria2c() -> Self {
+        Self {
+            program: PathBuf::from("aria2c"),
+            args: [
+                "--conf-path={config}",
+                "--quiet",
+                "--max-connection-per-server=8",
+                "--split=8",
+                "--allow-overwrite=true",
+                "--auto-file-renaming=false",
+                "--dir={output_dir}",
+                "--out={output_name}",
+                "{url}",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+            config_line: "header={header}".to_owned(),
+        }
+    }
+
+    /// Returns the configuration of `curl`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::ExternalDownloader;
+    ///
+    /// let downloader = ExternalDownloader::curl();
+    /// assert_eq!(downloader.program.to_str(), Some("curl"));
+    /// ```
+    pub fn curl() -> Self {
+        Self {
+            program: PathBuf::from("curl"),
+            args: [
+                "--config",
+                "{config}",
+                "--silent",
+                "--show-error",
+                "--fail",
+                "--location",
+                "--output",
+                "{output}",
+                "{url}",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+            config_line: "header = \"{header}\"".to_owned(),
+        }
+    }
+
+    /// Downloads `url` into `output` with the program, sending `headers`.
+    pub(crate) async fn download(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        output: &Path,
+    ) -> Result<(), UpdateError> {
+        let output_dir: &Path = output.parent().unwrap_or_else(|| Path::new(""));
+        let output_name: String = output
+            .file_name()
+            .map(|output_name| output_name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let config: PathBuf = output_dir.join(format!(".{}.headers", output_name));
+        self.write_config(headers, &config).await?;
+
+        let mut command = tokio::process::Command::new(&self.program);
+        command.args(self.args.iter().map(|arg| {
+            arg.replace("{url}", url)
+                .replace("{output}", &output.to_string_lossy())
+                .replace("{output_dir}", &output_dir.to_string_lossy())
+                .replace("{output_name}", &output_name)
+                .replace("{config}", &config.to_string_lossy())
+        }));
+        let output_status = command.kill_on_drop(true).output().await;
+        let _ = tokio::fs::remove_file(&config).await;
+        let output_status: Output = output_status.map_err(|error| {
+            UpdateError::new(format!(
+                "Unable to run the external downloader {}: {}",
+                self.program.display(),
+                error
+            ))
+        })?;
+
+        if !output_status.status.success() {
+            return Err(UpdateError::new(format!(
+                "The external downloader {} failed ({}): {}",
+                self.program.display(),
+                output_status.status,
+                String::from_utf8_lossy(&output_status.stderr).trim()
+            )));
+        }
+        if !output.is_file() {
+            return Err(UpdateError::new(format!(
+                "The external downloader {} did not create {}.",
+                self.program.display(),
+                output.display()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Writes `headers` to the configuration file `config`, readable by the current user only.
+    async fn write_config(
+        &self,
+        headers: &[(&str, String)],
+        config: &Path,
+    ) -> Result<(), UpdateError> {
+        let content: String = headers
+            .iter()
+            .map(|(name, value)| {
+                self.config_line
+                    .replace("{header}", &format!("{}: {}", name, value))
+                    + "\n"
+            })
+            .collect();
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(config).await?;
+        file.write_all(content.as_bytes()).await?;
+        file.flush().await?;
+
+        Ok(())
+    }
+}
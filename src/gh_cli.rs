@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+/// The host entry of GitHub in the `hosts.yml` file of the gh CLI.
+const GH_CLI_HOST: &str = "github.com";
+
+/// Returns the path of the `hosts.yml` file of the gh CLI.
+///
+/// `GH_CONFIG_DIR` is used when set, otherwise:
+///    * on Windows: `%AppData%\GitHub CLI\hosts.yml`;
+///    * elsewhere: `$XDG_CONFIG_HOME/gh/hosts.yml`, defaulting to `~/.config/gh/hosts.yml`.
+fn hosts_file_path() -> Option<PathBuf> {
+    if let Some(config_dir) = std::env::var_os("GH_CONFIG_DIR") {
+        return Some(PathBuf::from(config_dir).join("hosts.yml"));
+    }
+
+    if cfg!(windows) {
+        std::env::var_os("AppData")
+            .map(|app_data| PathBuf::from(app_data).join("GitHub CLI").join("hosts.yml"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .map(|config_dir| config_dir.join("gh").join("hosts.yml"))
+    }
+}
+
+/// Extracts the `oauth_token` of the `github.com` host from the content of a `hosts.yml` file.
+///
+/// Only the small subset of YAML written by the gh CLI is supported. When the host has several
+/// users, the token of the active user, stored directly under the host, is preferred.
+fn parse_hosts_file(content: &str) -> Option<String> {
+    let mut in_host: bool = false;
+    let mut token: Option<(usize, String)> = None;
+
+    for line in content.lines() {
+        let trimmed_line: &str = line.trim_start();
+        if trimmed_line.is_empty() || trimmed_line.starts_with('#') {
+            continue;
+        }
+
+        let indentation: usize = line.len() - trimmed_line.len();
+        if indentation == 0 {
+            in_host = trimmed_line.trim_end() == format!("{}:", GH_CLI_HOST);
+            continue;
+        }
+        if !in_host {
+            continue;
+        }
+
+        if let Some(value) = trimmed_line.strip_prefix("oauth_token:") {
+            let value: &str = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            let is_less_nested: bool = token
+                .as_ref()
+                .is_none_or(|(token_indentation, _)| indentation < *token_indentation);
+            if !value.is_empty() && is_less_nested {
+                token = Some((indentation, value.to_owned()));
+            }
+        }
+    }
+
+    token.map(|(_, token)| token)
+}
+
+/// Finds the GitHub token stored by the gh CLI, falling back to the `GH_TOKEN` and `GITHUB_TOKEN`
+/// environment variables.
+pub(crate) fn find_github_token() -> Option<String> {
+    hosts_file_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| parse_hosts_file(&content))
+        .or_else(|| {
+            ["GH_TOKEN", "GITHUB_TOKEN"]
+                .iter()
+                .filter_map(|name| std::env::var(name).ok())
+                .find(|token| !token.is_empty())
+        })
+}
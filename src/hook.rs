@@ -0,0 +1,56 @@
+use crate::{AssetInfo, PreDownloadInfo, ReleaseInfo, RetryContext, VerificationFailure};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A closure provided by the user, e.g.: `Hook<dyn Fn(&str) -> String + Send + Sync>`.
+///
+/// Closures are neither `Debug` nor `Clone`, this wrapper allows `GithubUpdater` to still derive
+/// both traits.
+pub(crate) struct Hook<F: ?Sized>(Arc<F>);
+
+impl<F: ?Sized> Hook<F> {
+    pub(crate) fn new(closure: Arc<F>) -> Self {
+        Self(closure)
+    }
+}
+
+impl<F: ?Sized> Clone for Hook<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> std::fmt::Debug for Hook<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Hook")
+    }
+}
+
+impl<F: ?Sized> Deref for Hook<F> {
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// See `GithubUpdater::with_version_normalizer`.
+pub(crate) type VersionNormalizer = Hook<dyn Fn(&str) -> String + Send + Sync>;
+
+/// See `GithubUpdater::with_asset_selector`.
+pub(crate) type AssetSelector = Hook<dyn Fn(&[AssetInfo]) -> usize + Send + Sync>;
+
+/// See `GithubUpdater::with_retry_predicate`.
+pub(crate) type RetryPredicate = Hook<dyn Fn(&RetryContext) -> bool + Send + Sync>;
+
+/// See `GithubUpdater::with_on_verification_failure`.
+pub(crate) type VerificationFailureHook = Hook<dyn Fn(&VerificationFailure) + Send + Sync>;
+
+/// See `GithubUpdater::with_pre_download_confirm`.
+pub(crate) type PreDownloadConfirm = Hook<dyn Fn(&PreDownloadInfo) -> bool + Send + Sync>;
+
+/// See `GithubUpdater::with_url_rewriter`.
+pub(crate) type UrlRewriter = Hook<dyn Fn(&str) -> String + Send + Sync>;
+
+/// See `GithubUpdater::with_release_transform`.
+pub(crate) type ReleaseTransform = Hook<dyn Fn(&mut ReleaseInfo) + Send + Sync>;
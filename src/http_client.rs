@@ -0,0 +1,228 @@
+use crate::errors::update_error::UpdateError;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::{stream, Stream, StreamExt};
+use http_body::{Body, Frame, SizeHint};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Response, ResponseBuilderExt, StatusCode, Url};
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// The body of an `HttpResponse`, read chunk by chunk.
+pub type HttpBody = Pin<Box<dyn Stream<Item = Result<Bytes, UpdateError>> + Send>>;
+
+/// HTTP request struct, describing a request sent by the crate through an `HttpClient`.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// The method of the request.
+    pub method: Method,
+    /// The URL of the request.
+    pub url: Url,
+    /// The headers of the request, to send as they are.
+    pub headers: HeaderMap,
+    /// The body of the request. The value is null if the request has no body.
+    pub body: Option<Bytes>,
+    /// The duration after which the request must fail, including the reading of the response
+    /// body. The value is null if the request has no timeout.
+    pub timeout: Option<Duration>,
+}
+
+/// HTTP response struct, describing the response of an `HttpClient`.
+pub struct HttpResponse {
+    /// The status of the response.
+    pub status: StatusCode,
+    /// The headers of the response.
+    pub headers: HeaderMap,
+    /// The final URL of the response, after the redirects followed by the client.
+    pub url: Url,
+    /// The body of the response, decompressed.
+    pub body: HttpBody,
+}
+
+impl HttpResponse {
+    /// Creates a response whose whole body is already known, e.g.: from a client without
+    /// streaming support.
+    pub fn from_bytes<B: Into<Bytes>>(
+        status: StatusCode,
+        headers: HeaderMap,
+        url: Url,
+        body: B,
+    ) -> Self {
+        let body: Bytes = body.into();
+
+        Self {
+            status,
+            headers,
+            url,
+            body: Box::pin(stream::once(async move { Ok(body) })),
+        }
+    }
+}
+
+impl std::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("url", &self.url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The transport sending the HTTP requests of the crate, see `GithubUpdater::with_http_client`,
+/// e.g.: to use another HTTP library, or the `fetch` API of a WebAssembly host.
+///
+/// The implementations must respect the following contract:
+/// * the headers of the request are sent as they are: the crate sets the `User-Agent`, the
+///   `Accept` header and, for the GitHub API only, the `Authorization` and `X-GitHub-Api-Version`
+///   headers;
+/// * the redirects are followed, unless `GithubUpdater::with_manual_redirect_handling` is enabled
+///   or the downloads are restricted with `GithubUpdater::with_allowed_download_hosts`, and the
+///   `Authorization` header must not be sent to another host than the one of the request: GitHub
+///   redirects the downloads to its storage;
+/// * the body of the response is decompressed, and streamed rather than read at once, since the
+///   assets may be large. The `Content-Length` header, used to verify the size of the assets, must
+///   be the one of the decompressed body;
+/// * an `Err` is returned only when no response is received: the responses with an error status
+///   are returned as they are, the crate handles them.
+///
+/// `reqwest::Client` implements this trait, as the default transport of the crate.
+///
+/// # Example
+///
+/// A client serving a release from memory:
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use github_updater::errors::update_error::UpdateError;
+/// use github_updater::{GithubUpdater, HttpClient, HttpRequest, HttpResponse};
+/// use reqwest::header::{HeaderMap, HeaderValue, CONTENT_LENGTH};
+/// use reqwest::StatusCode;
+///
+/// struct MemoryClient;
+///
+/// #[async_trait]
+/// impl HttpClient for MemoryClient {
+///     async fn send(&self, request: HttpRequest) -> Result<HttpResponse, UpdateError> {
+///         let body: &'static str = if request.url.path().ends_with("/latest") {
+///             r#"{"name": "1.0.0", "body": null, "published_at": null, "assets": [{
+///                 "url": "https://api.github.com/repos/Asthowen/AFetch/releases/assets/1",
+///                 "browser_download_url": "afetch", "name": "afetch"}]}"#
+///         } else {
+///             "binary"
+///         };
+///         let mut headers = HeaderMap::new();
+///         headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+///
+///         Ok(HttpResponse::from_bytes(StatusCode::OK, headers, request.url, body))
+///     }
+/// }
+///
+/// let updater_builder = GithubUpdater::builder()
+///     .with_http_client(MemoryClient)
+///     .with_app_name("afetch")
+///     .with_repository("Asthowen/AFetch")
+///     .with_release_file_name_pattern("{app_name}")
+///     .with_download_path(&std::env::temp_dir())
+///     .build()
+///     .unwrap();
+/// ```
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    /// Sends `request`, returning the response as soon as its headers are received.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, UpdateError>;
+}
+
+#[async_trait]
+impl HttpClient for reqwest::Client {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, UpdateError> {
+        let mut reqwest_request = reqwest::Request::new(request.method, request.url);
+        *reqwest_request.headers_mut() = request.headers;
+        *reqwest_request.body_mut() = request.body.map(reqwest::Body::from);
+        *reqwest_request.timeout_mut() = request.timeout;
+
+        let response: Response = self.execute(reqwest_request).await?;
+        let status: StatusCode = response.status();
+        let headers: HeaderMap = response.headers().clone();
+        let url: Url = response.url().clone();
+        let body: HttpBody = Box::pin(stream::unfold(Some(response), |response| async move {
+            let mut response: Response = response?;
+            match response.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk), Some(response))),
+                Ok(None) => None,
+                Err(error) => Some((Err(error.into()), None)),
+            }
+        }));
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            url,
+            body,
+        })
+    }
+}
+
+impl From<reqwest::Request> for HttpRequest {
+    fn from(request: reqwest::Request) -> Self {
+        Self {
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .map(Bytes::copy_from_slice),
+            timeout: request.timeout().copied(),
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+        }
+    }
+}
+
+/// Adapts the body of an `HttpResponse` to the `Body` trait, to build a Reqwest response from it.
+struct StreamBody(Mutex<HttpBody>);
+
+impl Body for StreamBody {
+    type Data = Bytes;
+    type Error = std::io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let body: &mut HttpBody = match self.get_mut().0.get_mut() {
+            Ok(body) => body,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        body.poll_next_unpin(cx).map(|chunk| {
+            chunk.map(|chunk| {
+                chunk
+                    .map(Frame::data)
+                    .map_err(|error| std::io::Error::other(error.0))
+            })
+        })
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::default()
+    }
+}
+
+impl From<HttpResponse> for Response {
+    fn from(response: HttpResponse) -> Self {
+        let mut builder = http::Response::builder()
+            .status(response.status)
+            .url(response.url);
+        if let Some(headers) = builder.headers_mut() {
+            *headers = response.headers;
+        }
+
+        // The builder only fails on invalid parts, which are already parsed here
+        builder
+            .body(reqwest::Body::wrap(StreamBody(Mutex::new(response.body))))
+            .unwrap_or_else(|_| http::Response::new(reqwest::Body::from(Bytes::new())))
+            .into()
+    }
+}
@@ -0,0 +1,281 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use md5::Digest;
+use reqwest::header::HeaderMap;
+
+/// Hash algorithms that can be used to verify a downloaded file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DigestAlgorithm {
+    Md5,
+    Sha256,
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    /// Returns the algorithm matching a key of the RFC 9530 `Hash Algorithms` registry.
+    fn from_rfc9530_key(key: &str) -> Option<Self> {
+        match key {
+            "md5" => Some(Self::Md5),
+            "sha-256" => Some(Self::Sha256),
+            "sha-512" => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+}
+
+impl DigestAlgorithm {
+    /// Returns the length of a digest computed with this algorithm, in bytes.
+    fn output_length(&self) -> usize {
+        match self {
+            Self::Md5 => 16,
+            Self::Sha256 => 32,
+            Self::Sha512 => 64,
+        }
+    }
+}
+
+impl std::fmt::Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Md5 => write!(f, "MD5"),
+            Self::Sha256 => write!(f, "SHA-256"),
+            Self::Sha512 => write!(f, "SHA-512"),
+        }
+    }
+}
+
+/// A digest announced by the server, encoded in base64.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExpectedDigest {
+    pub(crate) algorithm: DigestAlgorithm,
+    pub(crate) value: String,
+    /// The name of the header from which the digest has been read.
+    pub(crate) header: &'static str,
+}
+
+impl ExpectedDigest {
+    /// Finds the digest to check in the response headers.
+    ///
+    /// `Content-Digest` is preferred, then `Repr-Digest` (both equivalent since assets are
+    /// requested without content encoding) and finally the legacy `Content-MD5` header.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header_value = |name: &str| headers.get(name).and_then(|h| h.to_str().ok());
+
+        header_value("content-digest")
+            .and_then(|value| parse_digest_header(value, "content-digest"))
+            .or_else(|| {
+                header_value("repr-digest")
+                    .and_then(|value| parse_digest_header(value, "repr-digest"))
+            })
+            .or_else(|| {
+                header_value("content-md5").map(|value| Self {
+                    algorithm: DigestAlgorithm::Md5,
+                    value: value.trim().to_owned(),
+                    header: "content-md5",
+                })
+            })
+    }
+
+    /// Returns whether the digest is valid base64 encoding a digest of the expected length, e.g.:
+    /// a truncated `content-md5` header is not well-formed.
+    pub(crate) fn is_well_formed(&self) -> bool {
+        STANDARD
+            .decode(&self.value)
+            .is_ok_and(|digest| digest.len() == self.algorithm.output_length())
+    }
+}
+
+/// Parses a `Content-Digest` or `Repr-Digest` header as defined by RFC 9530.
+///
+/// The header is a structured field dictionary, e.g.: `sha-256=:base64:, sha-512=:base64:`.
+/// Members using an unsupported algorithm are ignored and, when several members are well-formed,
+/// the strongest algorithm is selected. Without any well-formed member, a malformed one is
+/// returned, so that it is reported like a malformed `Content-MD5` header.
+pub(crate) fn parse_digest_header(
+    header_value: &str,
+    header: &'static str,
+) -> Option<ExpectedDigest> {
+    let digests: Vec<ExpectedDigest> = header_value
+        .split(',')
+        .filter_map(|member| {
+            let (key, value) = member.trim().split_once('=')?;
+            let algorithm = DigestAlgorithm::from_rfc9530_key(key.trim())?;
+            // Drop the parameters of the member, if any.
+            let value = value.split(';').next()?.trim();
+            let value = value
+                .strip_prefix(':')
+                .and_then(|value| value.strip_suffix(':'))
+                .unwrap_or(value);
+
+            Some(ExpectedDigest {
+                algorithm,
+                value: value.to_owned(),
+                header,
+            })
+        })
+        .collect();
+
+    digests
+        .iter()
+        .filter(|digest| digest.is_well_formed())
+        .max_by_key(|digest| digest.algorithm)
+        .or(digests.first())
+        .cloned()
+}
+
+/// Incremental hasher for one of the supported algorithms.
+pub(crate) enum Hasher {
+    Md5(md5::Md5),
+    Sha256(sha2::Sha256),
+    Sha512(sha2::Sha512),
+}
+
+impl Hasher {
+    pub(crate) fn new(algorithm: DigestAlgorithm) -> Self {
+        match algorithm {
+            DigestAlgorithm::Md5 => Self::Md5(md5::Md5::new()),
+            DigestAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+            DigestAlgorithm::Sha512 => Self::Sha512(sha2::Sha512::new()),
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Md5(hasher) => hasher.update(data),
+            Self::Sha256(hasher) => hasher.update(data),
+            Self::Sha512(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Consumes the hasher and returns the digest encoded in base64.
+    pub(crate) fn finalize_base64(self) -> String {
+        match self {
+            Self::Md5(hasher) => STANDARD.encode(hasher.finalize()),
+            Self::Sha256(hasher) => STANDARD.encode(hasher.finalize()),
+            Self::Sha512(hasher) => STANDARD.encode(hasher.finalize()),
+        }
+    }
+
+    /// Consumes the hasher and returns the digest encoded in lowercase hexadecimal.
+    pub(crate) fn finalize_hex(self) -> String {
+        match self {
+            Self::Md5(hasher) => format!("{:x}", hasher.finalize()),
+            Self::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+            Self::Sha512(hasher) => format!("{:x}", hasher.finalize()),
+        }
+    }
+}
+
+/// Extracts the SHA-256 of `file_name` from the content of a checksum file, in the format written
+/// by `sha256sum`: one `<hex digest>  <file name>` line per file, the name being prefixed with `*`
+/// in binary mode. A file holding a single digest without any name is also accepted.
+pub(crate) fn parse_checksum_file(content: &str, file_name: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let digest: &str = parts.next()?;
+        if digest.len() != 64
+            || !digest
+                .chars()
+                .all(|character| character.is_ascii_hexdigit())
+        {
+            return None;
+        }
+
+        match parts.next() {
+            Some(name) if name.trim_start_matches('*') != file_name => None,
+            _ => Some(digest.to_ascii_lowercase()),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MD5: &str = "nXGD8WrM5wZY9oaufxpNIA==";
+    const SHA256: &str = "mjpF0BUxog6JrGrhCwsL6wSSrNchajaKoGLRpf7K+c0=";
+    const SHA512: &str =
+        "pmPvbtUXt4iWp/C3jVeODZpHCnx880TJyw3sBq6DLUiqCKexIdTvHfrO5Qyf73LFq0o5sObi0YU0zQCauQi5sA==";
+
+    fn parse(header_value: &str) -> Option<ExpectedDigest> {
+        parse_digest_header(header_value, "content-digest")
+    }
+
+    #[test]
+    fn parses_each_algorithm() {
+        for (key, algorithm, value) in [
+            ("md5", DigestAlgorithm::Md5, MD5),
+            ("sha-256", DigestAlgorithm::Sha256, SHA256),
+            ("sha-512", DigestAlgorithm::Sha512, SHA512),
+        ] {
+            let digest: ExpectedDigest = parse(&format!("{}=:{}:", key, value)).unwrap();
+            assert_eq!(digest.algorithm, algorithm);
+            assert_eq!(digest.value, value);
+            assert_eq!(digest.header, "content-digest");
+        }
+    }
+
+    #[test]
+    fn selects_the_strongest_algorithm() {
+        let header_value: String =
+            format!("sha-256=:{}:, sha-512=:{}:, md5=:{}:", SHA256, SHA512, MD5);
+
+        assert_eq!(
+            parse(&header_value).unwrap().algorithm,
+            DigestAlgorithm::Sha512
+        );
+    }
+
+    #[test]
+    fn drops_the_parameters_of_a_member() {
+        let digest: ExpectedDigest = parse(&format!("sha-256=:{}:;foo=bar", SHA256)).unwrap();
+
+        assert_eq!(digest.value, SHA256);
+    }
+
+    #[test]
+    fn ignores_unknown_algorithms() {
+        assert!(parse("sha-1=:aGVsbG8=:, unixsum=:MTIz:").is_none());
+
+        let digest: ExpectedDigest =
+            parse(&format!("sha-1=:aGVsbG8=:, sha-256=:{}:", SHA256)).unwrap();
+        assert_eq!(digest.algorithm, DigestAlgorithm::Sha256);
+    }
+
+    #[test]
+    fn reports_malformed_base64() {
+        let digest: ExpectedDigest = parse("sha-256=:not base64!:").unwrap();
+        assert_eq!(digest.algorithm, DigestAlgorithm::Sha256);
+        assert!(!digest.is_well_formed());
+        // A digest of another length than the one of the algorithm
+        assert!(!parse(&format!("sha-512=:{}:", SHA256))
+            .unwrap()
+            .is_well_formed());
+
+        let digest: ExpectedDigest =
+            parse(&format!("sha-512=:%%%:, sha-256=:{}:", SHA256)).unwrap();
+        assert_eq!(digest.algorithm, DigestAlgorithm::Sha256);
+        assert!(digest.is_well_formed());
+    }
+
+    #[test]
+    fn chunked_and_buffered_hashing_match() {
+        let content: Vec<u8> = (0..10_000u32)
+            .map(|index| (index * 31 % 251) as u8)
+            .collect();
+
+        for algorithm in [
+            DigestAlgorithm::Md5,
+            DigestAlgorithm::Sha256,
+            DigestAlgorithm::Sha512,
+        ] {
+            let mut buffered: Hasher = Hasher::new(algorithm);
+            buffered.update(&content);
+            let mut chunked: Hasher = Hasher::new(algorithm);
+            for chunk in content.chunks(7) {
+                chunked.update(chunk);
+            }
+
+            assert_eq!(chunked.finalize_hex(), buffered.finalize_hex());
+        }
+    }
+}
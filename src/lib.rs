@@ -1,16 +1,138 @@
+use crate::asset_body::{AssetBody, AssetSource};
 use crate::errors::builder_not_initialized::BuilderNotInitialized;
+use crate::errors::disallowed_host::DisallowedHost;
+use crate::errors::error_kind::ErrorKind;
+use crate::errors::secondary_rate_limited::SecondaryRateLimited;
 use crate::errors::update_error::UpdateError;
-use base64::{engine::general_purpose::STANDARD, Engine as _};
+use crate::executable_format::{ExecutableFormat, MAGIC_BYTES_LENGTH};
+use crate::hook::{
+    AssetSelector, Hook, PreDownloadConfirm, ReleaseTransform, RetryPredicate, UrlRewriter,
+    VerificationFailureHook, VersionNormalizer,
+};
+use crate::integrity::{parse_checksum_file, DigestAlgorithm, ExpectedDigest, Hasher};
+use crate::partial_download::PartialDownloadGuard;
+use crate::path_expansion::expand_path;
+use crate::rate_limit::{detect_secondary_rate_limit, RateLimitResponse};
+use crate::resolution_cache::ResolvedRelease;
+use crate::smoke_test::SmokeTest;
+use crate::staged_update::create_staging_dir;
+use crate::throttle::Throttle;
+use crate::timestamp::parse_rfc3339;
+use crate::tls_config::{custom_tls_config, parse_fingerprint, parse_server_name};
+use crate::transactional_update::{install_file, Backup};
+use crate::version::{compare_versions, parse_version};
+use errors::builder_error::BuilderError;
+use errors::builder_invalid_element::BuilderInvalidElement;
 use errors::builder_missing_element::BuilderMissingElement;
-use md5::Digest;
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::Client;
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, USER_AGENT,
+};
+use reqwest::{Client, Method, Response, Url};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use tokio::fs::File;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt, BufWriter};
+use tokio::time::Instant;
 
+mod asset_body;
+mod asset_match;
+#[cfg(all(windows, feature = "authenticode"))]
+mod authenticode;
+#[cfg(feature = "blocking")]
+mod blocking;
+mod compression;
 pub mod errors;
+mod executable_format;
+mod external_downloader;
+mod gh_cli;
+mod hook;
+mod http_client;
+mod integrity;
+#[cfg(target_os = "linux")]
+mod libc;
+mod manifest_sync;
+mod notify_socket;
+mod partial_download;
+mod path_expansion;
+#[cfg(feature = "provenance")]
+mod provenance;
+mod rate_limit;
+#[cfg(all(windows, feature = "registry"))]
+mod registry;
+mod resolution_cache;
+mod retry;
+#[cfg(feature = "sigstore")]
+mod sigstore;
+mod smoke_test;
+mod staged_update;
+mod throttle;
+mod timestamp;
+mod tls_config;
+mod transactional_update;
+mod updater;
+mod version;
+mod version_record;
+mod version_store;
+
+pub use asset_match::AssetMatch;
+#[cfg(feature = "blocking")]
+pub use blocking::GithubUpdaterBlocking;
+pub use compression::Compression;
+pub use external_downloader::ExternalDownloader;
+pub use http_client::{HttpBody, HttpClient, HttpRequest, HttpResponse};
+#[cfg(target_os = "linux")]
+pub use libc::Libc;
+pub use manifest_sync::{Manifest, ManifestFile, ManifestSyncReport};
+pub use notify_socket::UpdateEvent;
+#[cfg(feature = "provenance")]
+pub use provenance::ProvenanceConfig;
+pub use rate_limit::{RateLimit, RateLimitBucket};
+pub use regex::Regex;
+#[cfg(all(windows, feature = "registry"))]
+pub use registry::RegistryVersionStore;
+pub use retry::{RetryContext, RetryErrorKind};
+#[cfg(feature = "sigstore")]
+pub use sigstore::SigstoreConfig;
+pub use staged_update::StagedUpdate;
+pub use transactional_update::TransactionalUpdate;
+pub use updater::Updater;
+pub use version_record::VersionRecord;
+pub use version_store::{KeyringVersionStore, VersionStore};
+
+/// The host of the GitHub API, the only one to which the GitHub token is sent.
+const GITHUB_API_HOST: &str = "api.github.com";
+
+/// The version of the GitHub REST API requested by default, see `GithubUpdater::with_api_version`.
+pub const DEFAULT_GITHUB_API_VERSION: &str = "2022-11-28";
+
+/// The size of the chunks in which files are downloaded by default, see
+/// `GithubUpdater::with_chunk_buffer_size`.
+pub const DEFAULT_CHUNK_BUFFER_SIZE: usize = 64 * 1024;
+
+/// The regular expression replacing `{app_version}` in the pattern with
+/// `GithubUpdater::with_asset_version_autodetect`, capturing the version without its `v` prefix.
+const ASSET_VERSION_REGEX: &str = r"[vV]?(\d+(?:\.\d+)*(?:[-+][0-9A-Za-z.-]+)?)";
+
+/// The delay before retrying a request rejected by the secondary rate limits of GitHub without
+/// `Retry-After` header, the minimum advised by GitHub.
+const SECONDARY_RATE_LIMIT_DELAY: Duration = Duration::from_secs(60);
+
+/// The maximum number of redirects followed by `GithubUpdater::with_manual_redirect_handling`.
+const MAX_REDIRECTS: usize = 10;
+
+/// The maximum number of checks run at the same time by `GithubUpdater::check_many`.
+pub const CHECK_MANY_CONCURRENCY: usize = 8;
+
+/// The number of releases requested per page by `GithubUpdater::releases_stream`, the maximum
+/// allowed by the GitHub API.
+const RELEASES_PER_PAGE: u32 = 100;
 
 /// Download information struct.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -23,53 +145,868 @@ pub struct DownloadInfos {
     pub has_been_updated: bool,
     /// This shows whether or not the update has been forced.
     pub forced_update: bool,
+    /// The latest version available on GitHub when the check was made, even if no update has
+    /// been downloaded. The value is null if the release could not be resolved.
+    pub latest_available: Option<String>,
+    /// Whether the latest release has not been installed because it is older than the installed
+    /// version (see `DowngradePolicy::Refuse`).
+    #[serde(default)]
+    pub downgrade_refused: bool,
+    /// The SHA-256 of the downloaded file, encoded in lowercase hexadecimal. The value is null if
+    /// nothing has been downloaded.
+    pub downloaded_sha256: Option<String>,
+    /// When the latest release was published. The value is null if the release could not be
+    /// resolved or if its publication date is unknown.
+    #[serde(default)]
+    pub released_at: Option<SystemTime>,
+    /// Whether the latest release has not been installed because it was published more recently
+    /// than the minimum age (see `GithubUpdater::with_minimum_release_age`).
+    #[serde(default)]
+    pub release_too_recent: bool,
+    /// The time spent in each step of the update. The value is null if nothing has been
+    /// downloaded, or if the timings are not measured (see `GithubUpdater::with_timing_metrics`).
+    #[serde(default)]
+    pub timings: Option<UpdateTimings>,
+    /// Whether the download has been declined by the function set with
+    /// `GithubUpdater::with_pre_download_confirm`, which is not an error.
+    #[serde(default)]
+    pub update_declined: bool,
+    /// Whether the last release has not been fetched because the previous check is too recent
+    /// (see `GithubUpdater::with_min_check_interval`).
+    #[serde(default)]
+    pub check_skipped: bool,
+    /// Whether the installed file differs from the one it replaced, e.g.: `false` when a forced
+    /// update downloads the same file again, unlike `has_been_updated`. The value is always `true`
+    /// when the file is not installed in the download path, e.g.: with `download_to_writer`. The
+    /// replaced file is compared through the SHA-256 of its version record, if any, and hashed
+    /// if it has been modified since the record was written.
+    #[serde(default)]
+    pub changed: bool,
 }
 
-#[derive(Debug, Deserialize)]
+/// Update timings struct, breaking down the duration of an update, see
+/// `GithubUpdater::with_timing_metrics`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateTimings {
+    /// The time spent fetching the metadata of the release, zero if it was already fetched.
+    pub metadata: Duration,
+    /// The time spent downloading the asset, checksums and size verification included.
+    pub download: Duration,
+    /// The time spent verifying the downloaded file: signatures, provenance and Authenticode.
+    pub verification: Duration,
+    /// The time spent on the file operations: renames, version file and additional paths.
+    pub file_operations: Duration,
+}
+
+/// Update status struct.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateStatus {
+    /// The version installed. The value is null if the file is not present.
+    pub current_version: Option<String>,
+    /// The latest version available on GitHub.
+    pub latest_version: String,
+    /// To find out whether or not the latest version would be installed by `update_if_needed`.
+    pub update_available: bool,
+    /// Whether the last release has not been fetched because the previous check is too recent
+    /// (see `GithubUpdater::with_min_check_interval`), the latest version being the one found
+    /// then.
+    #[serde(default)]
+    pub check_skipped: bool,
+}
+
+/// How far the latest version is from the installed one, see `GithubUpdater::update_severity`.
+///
+/// Versions are compared following the semantic versioning precedence, a leading `v` being
+/// ignored and missing minor or patch components being treated as `0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpdateSeverity {
+    /// The latest version is not newer than the installed one.
+    #[default]
+    None,
+    /// Only the patch component, or the pre-release, of the version changes.
+    Patch,
+    /// The minor component of the version changes.
+    Minor,
+    /// The major component of the version changes, or nothing is installed yet.
+    Major,
+    /// One of the versions is not a semantic version, so the severity cannot be determined.
+    NonSemver,
+}
+
+impl UpdateSeverity {
+    /// Returns the severity of an update from `current_version` to `latest_version`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::UpdateSeverity;
+    ///
+    /// assert_eq!(UpdateSeverity::between("1.2.3", "v1.2.4"), UpdateSeverity::Patch);
+    /// assert_eq!(UpdateSeverity::between("1.2.3", "1.3"), UpdateSeverity::Minor);
+    /// assert_eq!(UpdateSeverity::between("1.2.3", "2.0.0-rc.1"), UpdateSeverity::Major);
+    /// assert_eq!(UpdateSeverity::between("1.2.3", "1.2.3"), UpdateSeverity::None);
+    /// assert_eq!(UpdateSeverity::between("1.2.3", "1.0.0"), UpdateSeverity::None);
+    /// assert_eq!(UpdateSeverity::between("1.2.3", "nightly"), UpdateSeverity::NonSemver);
+    /// ```
+    pub fn between(current_version: &str, latest_version: &str) -> Self {
+        let (Some(current_version), Some(latest_version)) = (
+            parse_version(current_version),
+            parse_version(latest_version),
+        ) else {
+            return Self::NonSemver;
+        };
+
+        if latest_version.cmp_precedence(&current_version) != Ordering::Greater {
+            Self::None
+        } else if latest_version.major != current_version.major {
+            Self::Major
+        } else if latest_version.minor != current_version.minor {
+            Self::Minor
+        } else {
+            Self::Patch
+        }
+    }
+}
+
+/// What `update_if_needed` does when the latest release is older than the installed version, e.g.:
+/// because the "latest" marker has been moved back to a previous release.
+///
+/// Versions are compared following the semantic versioning precedence, a leading `v` being
+/// ignored. When one of the versions is not a semantic version, the release is never considered
+/// as a downgrade.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DowngradePolicy {
+    /// The older release is not installed and `DownloadInfos::downgrade_refused` is set.
+    Refuse,
+    /// The older release is installed like any other version change.
+    #[default]
+    Allow,
+    /// The older release is installed and reported as a forced update
+    /// (`DownloadInfos::forced_update`), so it can be told apart from a regular update.
+    Force,
+}
+
+/// What `force_update` does when the file cannot be installed in one of the additional download
+/// paths, see `GithubUpdater::with_additional_download_paths`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AdditionalPathsFailurePolicy {
+    /// The paths in which the file has already been installed keep the new version.
+    #[default]
+    Keep,
+    /// The previous files and version files of the additional paths are restored. The download
+    /// path keeps the new version.
+    Rollback,
+}
+
+/// What fills the `{app_version}` placeholder of the file name pattern, see
+/// `GithubUpdater::with_version_placeholder_source`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersionSource {
+    /// The name of the release, e.g.: `1.2.0`, or the version extracted from it with
+    /// `GithubUpdater::with_version_extract_regex`.
+    #[default]
+    ReleaseName,
+    /// The name of the Git tag of the release, e.g.: `v1.2.0`.
+    TagName,
+    /// The name of the Git tag of the release without its leading `v`, e.g.: `1.2.0` for `v1.2.0`.
+    TagNameNoPrefix,
+}
+
+impl VersionSource {
+    /// Returns the value of the `{app_version}` placeholder for a release.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::VersionSource;
+    ///
+    /// assert_eq!(VersionSource::ReleaseName.resolve("AFetch 1.2.0", "v1.2.0"), "AFetch 1.2.0");
+    /// assert_eq!(VersionSource::TagName.resolve("AFetch 1.2.0", "v1.2.0"), "v1.2.0");
+    /// assert_eq!(VersionSource::TagNameNoPrefix.resolve("AFetch 1.2.0", "v1.2.0"), "1.2.0");
+    /// assert_eq!(VersionSource::TagNameNoPrefix.resolve("AFetch 1.2.0", "1.2.0"), "1.2.0");
+    /// ```
+    pub fn resolve<'a>(&self, release_name: &'a str, tag_name: &'a str) -> &'a str {
+        match self {
+            Self::ReleaseName => release_name,
+            Self::TagName => tag_name,
+            Self::TagNameNoPrefix => tag_name
+                .strip_prefix('v')
+                .or_else(|| tag_name.strip_prefix('V'))
+                .unwrap_or(tag_name),
+        }
+    }
+}
+
+/// Where the release metadata is read from, see `GithubUpdater::with_release_source`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ReleaseSource {
+    /// The latest release is fetched from the GitHub API.
+    #[default]
+    GitHub,
+    /// The release is read from a JSON file, in the format returned by the GitHub API, e.g.: a
+    /// copy mirrored to a file share for air-gapped environments. The assets are read from the
+    /// directory of the JSON file, using their `name`.
+    LocalJson(PathBuf),
+}
+
+/// Asset information struct, describing a file attached to a GitHub release.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetInfo {
+    /// The file name of the asset, e.g.: `afetch-1.0.0-x86_64-unknown-linux-gnu`.
+    pub name: String,
+    /// The size of the asset, in bytes.
+    pub size: u64,
+    /// The content type of the asset, e.g.: `application/octet-stream`.
+    pub content_type: String,
+    /// The URL from which a browser can download the asset.
+    pub download_url: String,
+    /// The URL of the asset in the GitHub API, from which it is downloaded. The asset is
+    /// downloaded from `download_url` if the value is empty.
+    pub api_url: String,
+    /// The digest of the asset published by GitHub, e.g.: `sha256:9a3a45d0...`. The value is null
+    /// if GitHub does not publish it.
+    pub digest: Option<String>,
+}
+
+/// Release information struct, describing a release as parsed from the GitHub API, which can be
+/// modified before an asset is selected, see `GithubUpdater::with_release_transform`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    /// The identifier of the release in the GitHub API, or `0` if unknown.
+    pub id: u64,
+    /// The URL of the release in the GitHub API. The value is empty if unknown.
+    pub url: String,
+    /// The name of the release, used as its version, e.g.: `1.0.0`.
+    pub name: String,
+    /// The name of the Git tag of the release, e.g.: `v1.0.0`.
+    pub tag_name: String,
+    /// The description of the release. The value is null if the release has no description.
+    pub body: Option<String>,
+    /// When the release was published, in RFC 3339 format, e.g.: `2024-01-01T00:00:00Z`. The value
+    /// is null if its publication date is unknown.
+    pub published_at: Option<String>,
+    /// Whether the release is marked as a pre-release.
+    pub prerelease: bool,
+    /// Whether the release is a draft.
+    pub draft: bool,
+    /// The files attached to the release, in the order in which they are matched.
+    pub assets: Vec<AssetInfo>,
+}
+
+/// Repository information struct, describing the repository checked by
+/// `GithubUpdater::verify_repository`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoInfo {
+    /// Whether the repository is private.
+    pub private: bool,
+    /// The name of the default branch of the repository, e.g.: `main`.
+    pub default_branch: String,
+    /// Whether the repository is archived, in which case no new release will be published.
+    pub archived: bool,
+}
+
+/// Newest release struct, describing the release found by `GithubUpdater::newest_across`.
+#[derive(Debug, Clone)]
+pub struct NewestRelease {
+    /// The index, in the updaters, of the one whose repository published the release.
+    pub index: usize,
+    /// The version of the release, as extracted by this updater.
+    pub version: String,
+    /// The release.
+    pub release: ReleaseSummary,
+    /// The errors of the updaters whose release could not be fetched, with their index.
+    pub errors: Vec<(usize, UpdateError)>,
+}
+
+/// Release summary struct, describing a release yielded by `GithubUpdater::releases_stream`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReleaseSummary {
+    /// The name of the release, used as its version, e.g.: `1.0.0`.
+    pub name: String,
+    /// The name of the Git tag of the release, e.g.: `v1.0.0`.
+    pub tag_name: String,
+    /// When the release was published. The value is null if its publication date is unknown.
+    pub released_at: Option<SystemTime>,
+    /// Whether the release is marked as a pre-release.
+    pub prerelease: bool,
+    /// The description of the release. The value is null if the release has no description.
+    pub body: Option<String>,
+    /// The files attached to the release.
+    pub assets: Vec<AssetInfo>,
+}
+
+/// Verification failure struct, describing a download which failed an integrity check, see
+/// `GithubUpdater::with_on_verification_failure`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VerificationFailure {
+    /// The URL from which the file was downloaded.
+    pub asset_url: String,
+    /// The algorithm of the checksum, e.g.: `SHA-256`. The value is null if the size of the file
+    /// is incorrect.
+    pub algorithm: Option<String>,
+    /// The checksum announced by the server, encoded in base64, or in hexadecimal when published
+    /// by the GitHub API or in a checksum file. The value is null if the size of the file
+    /// is incorrect.
+    pub expected_digest: Option<String>,
+    /// The checksum of the downloaded file, encoded like `expected_digest`. The value is null if
+    /// the size of the file is incorrect.
+    pub actual_digest: Option<String>,
+    /// The size announced by the server, in bytes.
+    pub expected_length: u64,
+    /// The size of the downloaded file, in bytes.
+    pub actual_length: u64,
+}
+
+/// Pre-download information struct, describing the update about to be downloaded, see
+/// `GithubUpdater::with_pre_download_confirm`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PreDownloadInfo {
+    /// The version installed. The value is null if the file is not present.
+    pub previous_version: Option<String>,
+    /// The version about to be downloaded.
+    pub new_version: String,
+    /// The size of the asset, in bytes. The value is null if the release does not announce it.
+    pub size: Option<u64>,
+    /// The description of the release, in Markdown. The value is null if the release has none.
+    pub release_notes: Option<String>,
+    /// When the release was published. The value is null if the publication date is unknown.
+    pub released_at: Option<SystemTime>,
+}
+
+/// A release fetched from the GitHub API along with the validators of the response, to revalidate
+/// it with a conditional request.
+#[derive(Debug, Clone)]
+struct CachedRelease {
+    url: String,
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    release: Release,
+}
+
+/// Information computed while downloading an asset.
+#[derive(Debug)]
+struct DownloadedAsset {
+    sha256: String,
+}
+
+/// A part of a multi-part asset, see `GithubUpdater::with_multipart_asset`.
+#[derive(Debug, Clone)]
+struct AssetPart {
+    name: String,
+    url: String,
+    size: u64,
+    sha256: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
 struct Release {
+    #[serde(default)]
+    id: u64,
+    #[serde(default)]
+    url: String,
     assets: Vec<Asset>,
     name: String,
+    body: Option<String>,
+    published_at: Option<String>,
+    #[serde(default)]
+    tag_name: String,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
 }
 
-#[derive(Debug, Deserialize)]
+impl From<ReleaseInfo> for Release {
+    fn from(release_info: ReleaseInfo) -> Self {
+        Self {
+            id: release_info.id,
+            url: release_info.url,
+            assets: release_info.assets.into_iter().map(Asset::from).collect(),
+            name: release_info.name,
+            body: release_info.body,
+            published_at: release_info.published_at,
+            tag_name: release_info.tag_name,
+            prerelease: release_info.prerelease,
+            draft: release_info.draft,
+        }
+    }
+}
+
+impl Release {
+    fn to_info(&self) -> ReleaseInfo {
+        ReleaseInfo {
+            id: self.id,
+            url: self.url.clone(),
+            name: self.name.clone(),
+            tag_name: self.tag_name.clone(),
+            body: self.body.clone(),
+            published_at: self.published_at.clone(),
+            prerelease: self.prerelease,
+            draft: self.draft,
+            assets: self.assets.iter().map(Asset::to_asset_info).collect(),
+        }
+    }
+
+    fn to_summary(&self) -> ReleaseSummary {
+        ReleaseSummary {
+            name: self.name.clone(),
+            tag_name: self.tag_name.clone(),
+            released_at: self.published_at.as_deref().and_then(parse_rfc3339),
+            prerelease: self.prerelease,
+            body: self.body.clone(),
+            assets: self.assets.iter().map(Asset::to_asset_info).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
 struct Asset {
     url: String,
     browser_download_url: String,
+    name: String,
+    #[serde(default)]
+    size: u64,
+    #[serde(default)]
+    content_type: String,
+    #[serde(default)]
+    digest: Option<String>,
+}
+
+impl Asset {
+    fn to_asset_info(&self) -> AssetInfo {
+        AssetInfo {
+            name: self.name.clone(),
+            size: self.size,
+            content_type: self.content_type.clone(),
+            download_url: self.browser_download_url.clone(),
+            api_url: self.url.clone(),
+            digest: self.digest.clone(),
+        }
+    }
+}
+
+impl From<AssetInfo> for Asset {
+    fn from(asset_info: AssetInfo) -> Self {
+        Self {
+            url: asset_info.api_url,
+            browser_download_url: asset_info.download_url,
+            name: asset_info.name,
+            size: asset_info.size,
+            content_type: asset_info.content_type,
+            digest: asset_info.digest,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GeneratedNotes {
+    body: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct GithubUpdater {
     reqwest_client: Option<Client>,
+    initialize_reqwest_client: bool,
+    local_address: Option<IpAddr>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    metadata_timeout: Option<Duration>,
+    metadata_compression: bool,
+    cached_release: Option<CachedRelease>,
+    download_timeout: Option<Duration>,
+    expected_size: Option<u64>,
+    manual_redirect_handling: bool,
+    pinned_cert_fingerprints: Vec<[u8; 32]>,
+    tls_sni_override: Option<String>,
     built: bool,
+    invalid_element: Option<BuilderInvalidElement>,
     pattern: Option<String>,
     app_name: Option<String>,
     github_token: Option<String>,
     rust_target: Option<String>,
+    flexible_target_matching: bool,
     repository_infos: Option<(String, String)>,
+    release_repository_infos: Option<(String, String)>,
     download_path: Option<PathBuf>,
+    download_path_env: Option<String>,
+    additional_download_paths: Vec<PathBuf>,
+    additional_paths_failure_policy: AdditionalPathsFailurePolicy,
     file_extension: Option<String>,
     erase_previous_file: bool,
+    use_asset_filename: bool,
+    follow_symlinks: bool,
+    create_missing_dirs: bool,
+    file_owner: Option<(u32, u32)>,
+    notify_socket: Option<PathBuf>,
+    manifest_asset: Option<String>,
+    json_version_record: bool,
+    version_store: Option<Hook<dyn VersionStore>>,
+    http_client: Option<Hook<dyn HttpClient>>,
+    content_based_update_check: bool,
+    rolling_tag: Option<String>,
+    release_id: Option<u64>,
+    min_check_interval: Option<Duration>,
+    force_check: bool,
+    pending_file_extension: Option<String>,
+    chunk_buffer_size: usize,
+    download_rate_limit: Option<u64>,
+    rename_attempts: u32,
+    rename_retry_delay: Duration,
+    version_normalizer: Option<VersionNormalizer>,
+    version_extract_regex: Option<Regex>,
+    verify_executable_format: bool,
+    smoke_test: Option<SmokeTest>,
+    store_decompressed: Option<Compression>,
+    smoke_test_expected_output: Option<String>,
+    downgrade_policy: DowngradePolicy,
+    api_version: String,
+    version_placeholder_source: VersionSource,
+    asset_version_autodetect: bool,
+    asset_exclude_patterns: Vec<String>,
+    asset_match: Option<AssetMatch>,
+    asset_selector: Option<AssetSelector>,
+    url_rewriter: Option<UrlRewriter>,
+    allowed_download_hosts: Vec<String>,
+    ignore_malformed_digest_header: bool,
+    require_verification: bool,
+    allowed_content_types: Vec<String>,
+    external_downloader: Option<ExternalDownloader>,
+    #[cfg(feature = "sigstore")]
+    sigstore_config: Option<SigstoreConfig>,
+    #[cfg(feature = "provenance")]
+    provenance_config: Option<ProvenanceConfig>,
+    #[cfg(all(windows, feature = "authenticode"))]
+    authenticode_verification: bool,
+    #[cfg(all(windows, feature = "authenticode"))]
+    authenticode_subject: Option<String>,
+    release_source: ReleaseSource,
+    asset_size: Option<u64>,
+    max_retries: u32,
+    retry_backoff: Duration,
+    retry_predicate: Option<RetryPredicate>,
+    on_verification_failure: Option<VerificationFailureHook>,
+    quarantine_dir: Option<PathBuf>,
+    pre_download_confirm: Option<PreDownloadConfirm>,
+    release_transform: Option<ReleaseTransform>,
+    resolution_cache: Option<(PathBuf, Duration)>,
+    resolved_from_cache: bool,
+    resolution_cacheable: bool,
+    minimum_release_age: Option<Duration>,
+    skip_yanked_detection: bool,
+    multipart_asset: bool,
+    multipart_parts: Option<u32>,
     release_url: Option<String>,
+    release_parts: Vec<AssetPart>,
+    #[cfg(feature = "sigstore")]
+    sigstore_bundle_url: Option<String>,
+    #[cfg(feature = "provenance")]
+    provenance_attestation_url: Option<String>,
     app_version: Option<String>,
+    release_notes: Option<String>,
+    release_tag: Option<String>,
+    published_sha256: Option<String>,
+    metadata_fetch_duration: Option<Duration>,
+    timing_metrics: bool,
+    ignore_version_file_errors: bool,
+    checksum_asset: Option<(String, String)>,
+    checksum_url_template: Option<String>,
+    released_at: Option<SystemTime>,
+    release_asset_name: Option<String>,
     need_refresh: bool,
     forced_update: bool,
 }
 
+/// Checks that the file system of `directory` has at least `needed` bytes available. The check is
+/// skipped, with a warning, if the available space cannot be queried.
+fn ensure_available_space(directory: &Path, needed: u64) -> Result<(), UpdateError> {
+    match fs4::available_space(directory) {
+        Ok(available) if available < needed => Err(UpdateError::with_kind(
+            format!(
+                "Insufficient disk space in {}: {} bytes needed, {} bytes available.",
+                directory.display(),
+                needed,
+                available
+            ),
+            ErrorKind::InsufficientDiskSpace { needed, available },
+        )),
+        Ok(_) => Ok(()),
+        Err(error) => {
+            log::warn!(
+                "Unable to query the available space in {}, it is not checked: {}",
+                directory.display(),
+                error
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Copies the corrupt download `file` to `quarantine_dir` with the suffix `.corrupt`, along with a
+/// JSON note describing the `failure`, returning the path of the copy.
+async fn quarantine_download(
+    quarantine_dir: &Path,
+    file: &Path,
+    failure: &VerificationFailure,
+) -> Result<PathBuf, UpdateError> {
+    let file_name: String = file
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let timestamp: u64 = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let quarantined_file: PathBuf =
+        quarantine_dir.join(format!("{}-{}.corrupt", file_name, timestamp));
+    let note: String = serde_json::to_string_pretty(failure).map_err(|error| {
+        UpdateError::new(format!(
+            "Unable to serialize the verification failure: {}",
+            error
+        ))
+    })?;
+
+    tokio::fs::create_dir_all(quarantine_dir).await?;
+    tokio::fs::copy(file, &quarantined_file).await?;
+    let mut note_file = quarantined_file.clone().into_os_string();
+    note_file.push(".json");
+    tokio::fs::write(note_file, note).await?;
+
+    Ok(quarantined_file)
+}
+
+/// The extensions of the checksum, signature and metadata files published next to the assets.
+const SIDECAR_EXTENSIONS: [&str; 10] = [
+    ".sha256", ".sha512", ".md5", ".asc", ".sig", ".pem", ".sbom", ".json", ".jsonl", ".txt",
+];
+
+/// Returns the first asset whose name mentions the operating system and the architecture of the
+/// current platform, ignoring the checksum and signature files, or `None` if there is none. On
+/// Linux, an asset built for the C library of the system is preferred.
+fn platform_asset(assets: &[Asset]) -> Option<&Asset> {
+    let os_names: &[&str] = match std::env::consts::OS {
+        "macos" => &["darwin", "macos", "osx"],
+        "windows" => &["windows", "win64", "win32"],
+        os => &[os],
+    };
+    let arch_names: &[&str] = match std::env::consts::ARCH {
+        "x86_64" => &["x86_64", "amd64", "x64"],
+        "aarch64" => &["aarch64", "arm64"],
+        "x86" => &["i686", "i386", "x86"],
+        "arm" => &["armv7", "arm"],
+        arch => &[arch],
+    };
+    let platform_assets: Vec<&Asset> = assets
+        .iter()
+        .filter(|asset| {
+            let name: String = asset.name.to_ascii_lowercase();
+            !SIDECAR_EXTENSIONS
+                .iter()
+                .any(|extension| name.ends_with(extension))
+                && os_names.iter().any(|os_name| name.contains(os_name))
+                && arch_names.iter().any(|arch_name| name.contains(arch_name))
+        })
+        .collect();
+
+    #[cfg(target_os = "linux")]
+    if let Some(libc) = Libc::detect() {
+        if let Some(asset) = platform_assets
+            .iter()
+            .find(|asset| asset.name.contains(libc.as_str()))
+        {
+            return Some(asset);
+        }
+    }
+
+    platform_assets.first().copied()
+}
+
+/// Returns the forms of `rust_target` tried by `with_flexible_target_matching`, from the exact
+/// target to the one without vendor and ABI, without duplicates.
+fn relaxed_targets(rust_target: &str) -> Vec<String> {
+    const VENDORS: [&str; 6] = ["unknown", "pc", "apple", "sun", "fortanix", "nvidia"];
+
+    let components: Vec<&str> = rust_target.split('-').collect();
+    let (arch, vendor, rest): (&str, Option<&str>, &[&str]) = match components.as_slice() {
+        [arch, vendor, rest @ ..] if VENDORS.contains(vendor) => (arch, Some(vendor), rest),
+        [arch, rest @ ..] => (arch, None, rest),
+        [] => return vec![rust_target.to_owned()],
+    };
+    let mut targets: Vec<String> = vec![rust_target.to_owned()];
+    if vendor.is_some() && !rest.is_empty() {
+        targets.push(format!("{}-{}", arch, rest.join("-")));
+    }
+    if rest.len() > 1 {
+        targets.push(format!("{}-{}", arch, rest[0]));
+    }
+    targets.dedup();
+
+    targets
+}
+
+/// Returns the path the new version of `previous_file` is downloaded to: `previous_file` with the
+/// prefix `new_` if it exists, otherwise `previous_file` itself.
+fn staged_file_path(previous_file: &Path) -> PathBuf {
+    match previous_file.file_name() {
+        Some(previous_file_name) if previous_file.exists() => {
+            previous_file.with_file_name(format!("new_{}", previous_file_name.to_string_lossy()))
+        }
+        _ => previous_file.to_path_buf(),
+    }
+}
+
+/// Returns the name of the whole file a part of a multi-part asset is split from, and the number
+/// of the part, e.g.: `afetch` and `2` for `afetch.part2`, or `None` if `asset_name` is not the
+/// one of a part.
+fn multipart_base_name(asset_name: &str) -> Option<(&str, u32)> {
+    let (base_name, part) = asset_name.rsplit_once(".part")?;
+    if base_name.is_empty() || !part.chars().all(|character| character.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((base_name, part.parse::<u32>().ok()?))
+}
+
+/// Returns the repository of a URL of the GitHub API, e.g.: `asthowen/afetch` for
+/// `https://api.github.com/repos/Asthowen/AFetch/releases/assets/1`, in lowercase as GitHub
+/// compares them, or `None` if `url` is not the one of a repository of the GitHub API.
+fn api_repository(url: &str) -> Option<String> {
+    let url: Url = Url::parse(url).ok()?;
+    if !url
+        .host_str()
+        .is_some_and(|host| host.eq_ignore_ascii_case(GITHUB_API_HOST))
+    {
+        return None;
+    }
+    let mut segments = url.path_segments()?;
+    if segments.next() != Some("repos") {
+        return None;
+    }
+    let (owner, name) = (segments.next()?, segments.next()?);
+
+    Some(format!("{}/{}", owner, name).to_ascii_lowercase())
+}
+
+/// Reads a release from a JSON file, in the format returned by the GitHub API.
+async fn read_local_release(path: &Path) -> Result<Release, UpdateError> {
+    serde_json::from_str(&tokio::fs::read_to_string(path).await?).map_err(|error| {
+        UpdateError::new(format!(
+            "An error occurred while parsing the release file {}: {}",
+            path.display(),
+            error
+        ))
+    })
+}
+
 impl GithubUpdater {
     pub fn builder() -> Self {
         Self {
             reqwest_client: None,
+            initialize_reqwest_client: false,
+            local_address: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            metadata_timeout: None,
+            metadata_compression: false,
+            cached_release: None,
+            download_timeout: None,
+            expected_size: None,
+            manual_redirect_handling: false,
+            pinned_cert_fingerprints: Vec::new(),
+            tls_sni_override: None,
             built: false,
+            invalid_element: None,
             pattern: None,
             app_name: None,
             github_token: None,
             rust_target: None,
+            flexible_target_matching: false,
             repository_infos: None,
+            release_repository_infos: None,
             download_path: None,
+            download_path_env: None,
+            additional_download_paths: Vec::new(),
+            additional_paths_failure_policy: AdditionalPathsFailurePolicy::Keep,
             file_extension: None,
             erase_previous_file: true,
+            use_asset_filename: false,
+            follow_symlinks: true,
+            create_missing_dirs: true,
+            file_owner: None,
+            notify_socket: None,
+            manifest_asset: None,
+            json_version_record: false,
+            version_store: None,
+            http_client: None,
+            content_based_update_check: false,
+            rolling_tag: None,
+            release_id: None,
+            min_check_interval: None,
+            force_check: false,
+            pending_file_extension: None,
+            chunk_buffer_size: DEFAULT_CHUNK_BUFFER_SIZE,
+            download_rate_limit: None,
+            rename_attempts: 1,
+            rename_retry_delay: Duration::ZERO,
+            version_normalizer: None,
+            version_extract_regex: None,
+            verify_executable_format: false,
+            smoke_test: None,
+            store_decompressed: None,
+            smoke_test_expected_output: None,
+            downgrade_policy: DowngradePolicy::Allow,
+            api_version: DEFAULT_GITHUB_API_VERSION.to_owned(),
+            version_placeholder_source: VersionSource::ReleaseName,
+            asset_version_autodetect: false,
+            asset_exclude_patterns: Vec::new(),
+            asset_match: None,
+            asset_selector: None,
+            url_rewriter: None,
+            allowed_download_hosts: Vec::new(),
+            ignore_malformed_digest_header: false,
+            allowed_content_types: Vec::new(),
+            external_downloader: None,
+            require_verification: false,
+            #[cfg(feature = "sigstore")]
+            sigstore_config: None,
+            #[cfg(feature = "provenance")]
+            provenance_config: None,
+            #[cfg(all(windows, feature = "authenticode"))]
+            authenticode_verification: false,
+            #[cfg(all(windows, feature = "authenticode"))]
+            authenticode_subject: None,
+            release_source: ReleaseSource::GitHub,
+            asset_size: None,
+            max_retries: 0,
+            retry_backoff: Duration::ZERO,
+            retry_predicate: None,
+            on_verification_failure: None,
+            quarantine_dir: None,
+            pre_download_confirm: None,
+            release_transform: None,
+            resolution_cache: None,
+            resolved_from_cache: false,
+            resolution_cacheable: false,
+            minimum_release_age: None,
+            skip_yanked_detection: false,
+            multipart_asset: false,
+            multipart_parts: None,
             release_url: None,
+            release_parts: Vec::new(),
+            #[cfg(feature = "sigstore")]
+            sigstore_bundle_url: None,
+            #[cfg(feature = "provenance")]
+            provenance_attestation_url: None,
             app_version: None,
+            release_notes: None,
+            release_tag: None,
+            published_sha256: None,
+            metadata_fetch_duration: None,
+            timing_metrics: false,
+            ignore_version_file_errors: false,
+            checksum_asset: None,
+            checksum_url_template: None,
+            released_at: None,
+            release_asset_name: None,
             need_refresh: true,
             forced_update: true,
         }
@@ -96,12 +1033,16 @@ impl GithubUpdater {
     /// ```
     pub fn with_reqwest_client(mut self, reqwest_client: Client) -> Self {
         self.reqwest_client = Some(reqwest_client);
+        self.initialize_reqwest_client = false;
 
         self
     }
 
     /// Creation of a new Reqwest customer, without option activated.
     ///
+    /// The client is created by the `build` method, with the client options of the builder such as
+    /// `with_local_address`.
+    ///
     /// # Returns
     ///
     /// The modified `GithubUpdater` builder instance.
@@ -116,31 +1057,21 @@ impl GithubUpdater {
     ///     .build();
     /// ```
     pub fn with_initialized_reqwest_client(mut self) -> Self {
-        self.reqwest_client = Some(
-            Client::builder()
-                .default_headers({
-                    let mut headers = HeaderMap::new();
-                    headers.insert(
-                        reqwest::header::ACCEPT_ENCODING,
-                        HeaderValue::from_static("identity"),
-                    );
-                    headers
-                })
-                .build()
-                .unwrap(),
-        );
+        self.reqwest_client = None;
+        self.initialize_reqwest_client = true;
 
         self
     }
 
-    /// Sets the filename pattern in GitHub releases.
+    /// Sends the requests through another transport than Reqwest, e.g.: another HTTP library or
+    /// the `fetch` API of a WebAssembly host. No Reqwest client is then required, and the options
+    /// of the Reqwest client, such as `with_local_address`, do not apply.
+    ///
+    /// See `HttpClient` for the contract the transport must respect.
     ///
     /// # Arguments
     ///
-    /// * `pattern` - The file pattern, which can contain:
-    ///    * `app_name`: The name of the application.
-    ///    * `rust_target`: The Rust target, e.g.: i686-unknown-freebsd.
-    ///    * `app_version`: The version of the application.
+    /// * `http_client` - The transport sending the requests.
     ///
     /// # Returns
     ///
@@ -151,21 +1082,29 @@ impl GithubUpdater {
     /// ```rust
     /// use github_updater::GithubUpdater;
     ///
+    /// // `reqwest::Client` is the default transport, which custom transports may wrap
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_http_client(reqwest::Client::new())
     ///     .build();
     /// ```
-    pub fn with_release_file_name_pattern<S: Into<String>>(mut self, pattern: S) -> Self {
-        self.pattern = Some(pattern.into());
+    pub fn with_http_client<C: HttpClient + 'static>(mut self, http_client: C) -> Self {
+        self.http_client = Some(Hook::new(Arc::new(http_client)));
 
         self
     }
 
-    /// Sets the application name which is used to define the name of the downloaded executable.
+    /// Sets the local address from which the requests are sent, to make them egress through a
+    /// specific network interface on multi-homed hosts.
+    ///
+    /// This option only applies to the client created with `with_initialized_reqwest_client`, a
+    /// client set with `with_reqwest_client` must be configured by the caller. The address must be
+    /// assigned to one of the interfaces of the host, otherwise requests fail when connecting.
+    /// Binding a source address is supported on every platform supported by Tokio.
     ///
     /// # Arguments
     ///
-    /// * `app_name` - The name of the application.
+    /// * `local_address` - The source address, e.g.: `192.168.1.10`. Multicast addresses are
+    ///   rejected by the `build` method.
     ///
     /// # Returns
     ///
@@ -175,22 +1114,28 @@ impl GithubUpdater {
     ///
     /// ```rust
     /// use github_updater::GithubUpdater;
+    /// use std::net::{IpAddr, Ipv4Addr};
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_app_name("afetch")
+    ///     .with_initialized_reqwest_client()
+    ///     .with_local_address(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)))
     ///     .build();
     /// ```
-    pub fn with_app_name<S: Into<String>>(mut self, app_name: S) -> Self {
-        self.app_name = Some(app_name.into());
+    pub fn with_local_address(mut self, local_address: IpAddr) -> Self {
+        self.local_address = Some(local_address);
 
         self
     }
 
-    /// Sets the GitHub token which will be used to make requests to the GitHub API.
+    /// Sets the maximum number of idle connections kept open per host, to tune the reuse of the
+    /// connections when checking many repositories frequently against the same host, e.g.:
+    /// `api.github.com`. By default, Reqwest does not limit it.
+    ///
+    /// This option only applies to the client created with `with_initialized_reqwest_client`.
     ///
     /// # Arguments
     ///
-    /// * `github_token` - The GitHub token to use for authentication.
+    /// * `max_idle` - The maximum number of idle connections per host, `0` disabling the reuse.
     ///
     /// # Returns
     ///
@@ -202,20 +1147,24 @@ impl GithubUpdater {
     /// use github_updater::GithubUpdater;
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_github_token("some")
+    ///     .with_initialized_reqwest_client()
+    ///     .with_pool_max_idle_per_host(4)
     ///     .build();
     /// ```
-    pub fn with_github_token<S: Into<String>>(mut self, github_token: S) -> Self {
-        self.github_token = Some(github_token.into());
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max_idle);
 
         self
     }
 
-    /// Sets the rust target that will be searched for in GitHub releases.
+    /// Sets how long an idle connection is kept open, 90 seconds by default. A longer timeout
+    /// lets checks made at a higher interval reuse the connection instead of opening a new one.
+    ///
+    /// This option only applies to the client created with `with_initialized_reqwest_client`.
     ///
     /// # Arguments
     ///
-    /// * `rust_target` - The Rust target, e.g.: i686-unknown-freebsd.
+    /// * `idle_timeout` - The time after which an idle connection is closed.
     ///
     /// # Returns
     ///
@@ -225,23 +1174,29 @@ impl GithubUpdater {
     ///
     /// ```rust
     /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_rust_target("i686-unknown-freebsd")
+    ///     .with_initialized_reqwest_client()
+    ///     .with_pool_idle_timeout(Duration::from_secs(300))
     ///     .build();
     /// ```
-    pub fn with_rust_target<S: Into<String>>(mut self, rust_target: S) -> Self {
-        self.rust_target = Some(rust_target.into());
+    pub fn with_pool_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(idle_timeout);
 
         self
     }
 
-    /// Sets information about the GitHub repository on which the releases are located.
+    /// Sets the timeout of the request fetching the metadata of the last release, reading of the
+    /// response included, e.g.: a few seconds, as it is a small JSON document.
+    ///
+    /// The timeout applies to each attempt made according to `with_retry`. It replaces, for this
+    /// request, the timeout set on the Reqwest client with `reqwest::ClientBuilder::timeout`, if
+    /// any, while the connection timeout of the client still applies.
     ///
     /// # Arguments
     ///
-    /// * `repository_owner` - The GitHub repository owner, e.g.: `Asthowen`.
-    /// * `repository_name` - The GitHub repository name, e.g.: `AFetch`.
+    /// * `metadata_timeout` - The maximum duration of the metadata request.
     ///
     /// # Returns
     ///
@@ -251,26 +1206,30 @@ impl GithubUpdater {
     ///
     /// ```rust
     /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_repository_infos("Asthowen", "AFetch")
+    ///     .with_metadata_timeout(Duration::from_secs(5))
+    ///     .with_download_timeout(Duration::from_secs(600))
     ///     .build();
     /// ```
-    pub fn with_repository_infos<S: Into<String>>(
-        mut self,
-        repository_owner: S,
-        repository_name: S,
-    ) -> Self {
-        self.repository_infos = Some((repository_owner.into(), repository_name.into()));
+    pub fn with_metadata_timeout(mut self, metadata_timeout: Duration) -> Self {
+        self.metadata_timeout = Some(metadata_timeout);
 
         self
     }
 
-    /// Sets the file download folder path.
+    /// Allows the GitHub API to compress its JSON responses with gzip, e.g.: the release metadata,
+    /// which reduces the bandwidth used when checking for updates frequently. The other requests,
+    /// e.g.: the assets, are always sent with `Accept-Encoding: identity`, whatever the HTTP client,
+    /// so that the size of the assets matches the `Content-Length` header.
+    ///
+    /// The responses are decompressed by Reqwest: a client given with `with_reqwest_client` must
+    /// not disable it with `reqwest::ClientBuilder::no_gzip`.
     ///
     /// # Arguments
     ///
-    /// * `path` - The download folder path, e.g.: `~/Downloads/`.
+    /// * `metadata_compression` - Whether the JSON responses may be compressed, disabled by default.
     ///
     /// # Returns
     ///
@@ -280,23 +1239,28 @@ impl GithubUpdater {
     ///
     /// ```rust
     /// use github_updater::GithubUpdater;
-    /// use std::path::Path;
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_download_path(&Path::new("~/Downloads/"))
+    ///     .with_initialized_reqwest_client()
+    ///     .with_metadata_compression(true)
     ///     .build();
     /// ```
-    pub fn with_download_path<P: AsRef<Path>>(mut self, path: &P) -> Self {
-        self.download_path = Some(path.as_ref().to_owned());
+    pub fn with_metadata_compression(mut self, metadata_compression: bool) -> Self {
+        self.metadata_compression = metadata_compression;
 
         self
     }
 
-    /// Sets the extension of the downloaded file.
+    /// Sets the timeout of the request downloading the asset, reading of the whole file included,
+    /// e.g.: several minutes for a large file on a slow connection.
+    ///
+    /// The timeout applies to each attempt made according to `with_retry`. It replaces, for this
+    /// request, the timeout set on the Reqwest client with `reqwest::ClientBuilder::timeout`, if
+    /// any, while the connection timeout of the client still applies.
     ///
     /// # Arguments
     ///
-    /// * `path` - The extension, e.g.: `exe`, `so`, `dll`.
+    /// * `download_timeout` - The maximum duration of the download.
     ///
     /// # Returns
     ///
@@ -306,22 +1270,31 @@ impl GithubUpdater {
     ///
     /// ```rust
     /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .with_file_extension("so")
+    ///     .with_download_timeout(Duration::from_secs(600))
     ///     .build();
     /// ```
-    pub fn with_file_extension<S: Into<String>>(mut self, extension: S) -> Self {
-        self.file_extension = Some(extension.into());
+    pub fn with_download_timeout(mut self, download_timeout: Duration) -> Self {
+        self.download_timeout = Some(download_timeout);
 
         self
     }
 
-    /// Disables the erasure of the previous file before downloading a new one.
+    /// Follows the redirects in the crate instead of in Reqwest, e.g.: when a proxy makes the
+    /// automatic redirects misbehave on the signed URLs to which GitHub redirects the downloads of
+    /// private assets.
     ///
-    /// When this option is enabled, the original file is preserved, and the new file is saved
-    /// with the prefix `new_` added to its filename. Note that the version information
-    /// in the text file will still be updated accordingly.
+    /// Each redirect is followed with a new request, built from scratch for the `Location` URL:
+    /// the GitHub token is only attached if this URL points to the GitHub API. At most 10
+    /// redirects are followed. The client created with `with_initialized_reqwest_client` does not
+    /// follow redirects when this option is enabled, a client set with `with_reqwest_client` must
+    /// be created with `reqwest::redirect::Policy::none()` for the redirects to reach the crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `manual_redirect_handling` - `true` to follow the redirects in the crate.
     ///
     /// # Returns
     ///
@@ -333,184 +1306,5811 @@ impl GithubUpdater {
     /// use github_updater::GithubUpdater;
     ///
     /// let updater_builder = GithubUpdater::builder()
-    ///     .without_erase_previous_file()
-    ///     .build();
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_manual_redirect_handling(true)
+    ///     .build()
+    ///     .unwrap();
     /// ```
-    pub fn without_erase_previous_file(mut self) -> Self {
-        self.erase_previous_file = false;
+    pub fn with_manual_redirect_handling(mut self, manual_redirect_handling: bool) -> Self {
+        self.manual_redirect_handling = manual_redirect_handling;
 
         self
     }
 
-    pub fn build(mut self) -> Result<Self, BuilderMissingElement> {
-        if self.reqwest_client.is_none() {
-            return Err(BuilderMissingElement("reqwest_client".to_owned()));
-        }
-        if self.app_name.is_none() {
-            return Err(BuilderMissingElement("app_name".to_owned()));
-        }
-        if let Some(pattern) = &self.pattern {
-            if pattern.contains("rust_target") && self.rust_target.is_none() {
-                return Err(BuilderMissingElement("rust_target".to_owned()));
+    /// Pins the certificate of the servers: connections presenting a leaf certificate whose SHA-256
+    /// fingerprint is not pinned are rejected, even if a trusted certificate authority signed it.
+    /// Call it several times to pin several certificates, e.g.: during a certificate rotation.
+    ///
+    /// The certificates are still validated against the Mozilla root certificates. As every
+    /// request is concerned, pin the certificates of all the hosts contacted, e.g.: the GitHub API
+    /// and the host serving the assets. This option requires the client to be created with
+    /// `with_initialized_reqwest_client`, which uses the `rustls` backend of Reqwest: the `build`
+    /// method rejects it along with a client set with `with_reqwest_client`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fingerprint` - The SHA-256 fingerprint of the certificate, in hexadecimal, the bytes being optionally separated by colons, e.g.: as displayed by `openssl x509 -noout -fingerprint -sha256`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_pinned_cert_sha256(
+    ///         "8F:43:28:8A:D2:72:F3:10:3B:6F:B1:42:84:85:EA:30:14:C0:BC:FE:31:6E:EB:2A:2F:0A:C9:D2:F5:36:0B:27",
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn with_pinned_cert_sha256<S: AsRef<str>>(mut self, fingerprint: S) -> Self {
+        match parse_fingerprint(fingerprint.as_ref()) {
+            Some(fingerprint) => self.pinned_cert_fingerprints.push(fingerprint),
+            None => {
+                self.invalid_element = Some(BuilderInvalidElement(format!(
+                    "pinned_cert_sha256 {} is not a SHA-256 fingerprint",
+                    fingerprint.as_ref()
+                )))
             }
-        } else {
-            return Err(BuilderMissingElement("pattern".to_owned()));
-        }
-        if self.repository_infos.is_none() {
-            return Err(BuilderMissingElement("repository_infos".to_owned()));
         }
-        if self.download_path.is_none() {
-            return Err(BuilderMissingElement("download_path".to_owned()));
-        }
-
-        self.built = true;
 
-        Ok(self)
+        self
     }
 
-    fn generate_file_name(&self, app_name: &str) -> String {
-        let extension: String = self
-            .file_extension
-            .as_ref()
-            .map_or_else(String::default, |ext| format!(".{}", ext));
+    /// Also accepts the server certificates valid for `hostname`, e.g.: when the downloads are
+    /// routed through an internal load balancer presenting a certificate for its own name instead
+    /// of the name of the host in the URL.
+    ///
+    /// The certificates are still validated against the Mozilla root certificates, and a
+    /// certificate valid for the host of the URL is still accepted. The server name indication
+    /// sent during the handshake is not changed.
+    ///
+    /// **Security**: any server able to present a trusted certificate for `hostname` is accepted
+    /// for every host contacted, the GitHub API included, which receives the GitHub token. Only use
+    /// a hostname whose certificate is under your control, possibly along with
+    /// `with_pinned_cert_sha256`.
+    ///
+    /// This option requires the client to be created with `with_initialized_reqwest_client`: the
+    /// `build` method rejects it along with a client set with `with_reqwest_client`. It also
+    /// rejects a `hostname` which is neither a valid DNS name nor an IP address.
+    ///
+    /// # Arguments
+    ///
+    /// * `hostname` - The name the certificates may be valid for, e.g.: `lb.corp.example.com`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = |hostname: &str| {
+    ///     GithubUpdater::builder()
+    ///         .with_initialized_reqwest_client()
+    ///         .with_tls_sni_override(hostname)
+    ///         .with_app_name("afetch")
+    ///         .with_repository("Asthowen/AFetch")
+    ///         .with_release_file_name_pattern("{app_name}")
+    ///         .with_download_path(&"/opt/bin")
+    ///         .build()
+    /// };
+    ///
+    /// assert!(updater_builder("lb.corp.example.com").is_ok());
+    /// assert!(updater_builder("not a hostname").is_err());
+    /// ```
+    pub fn with_tls_sni_override<S: Into<String>>(mut self, hostname: S) -> Self {
+        let hostname: String = hostname.into();
+        if parse_server_name(&hostname).is_none() {
+            self.invalid_element = Some(BuilderInvalidElement(format!(
+                "tls_sni_override {} is not a valid hostname",
+                hostname
+            )));
+        }
+        self.tls_sni_override = Some(hostname);
+
+        self
+    }
+
+    /// Creates the client requested with `with_initialized_reqwest_client`.
+    fn build_reqwest_client(&self) -> Result<Client, BuilderInvalidElement> {
+        let mut client_builder = Client::builder().default_headers({
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                reqwest::header::ACCEPT_ENCODING,
+                HeaderValue::from_static("identity"),
+            );
+            headers
+        });
+        if let Some(local_address) = self.local_address {
+            if local_address.is_multicast() {
+                return Err(BuilderInvalidElement(format!(
+                    "local_address {} is a multicast address",
+                    local_address
+                )));
+            }
+            client_builder = client_builder.local_address(local_address);
+        }
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            client_builder = client_builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.pool_idle_timeout {
+            client_builder = client_builder.pool_idle_timeout(idle_timeout);
+        }
+        if self.follows_redirects_itself() {
+            client_builder = client_builder.redirect(reqwest::redirect::Policy::none());
+        }
+        if !self.pinned_cert_fingerprints.is_empty() || self.tls_sni_override.is_some() {
+            let tls_config = custom_tls_config(
+                self.pinned_cert_fingerprints.clone(),
+                self.tls_sni_override.as_deref().and_then(parse_server_name),
+            )
+            .map_err(|error| {
+                BuilderInvalidElement(format!("TLS could not be configured: {}", error))
+            })?;
+            client_builder = client_builder.use_preconfigured_tls(tls_config);
+        }
+
+        client_builder.build().map_err(|error| {
+            BuilderInvalidElement(format!("reqwest_client could not be created: {}", error))
+        })
+    }
+
+    /// Sets the filename pattern in GitHub releases.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The file pattern, which can contain:
+    ///    * `app_name`: The name of the application.
+    ///    * `rust_target`: The Rust target, e.g.: i686-unknown-freebsd.
+    ///    * `app_version`: The version of the application.
+    ///    * `libc`: On Linux only, the C library of the system, `gnu` or `musl`, as detected by
+    ///      `Libc::detect`, e.g.: `{app_name}-x86_64-unknown-linux-{libc}`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .build();
+    /// ```
+    pub fn with_release_file_name_pattern<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.pattern = Some(pattern.into());
+
+        self
+    }
+
+    /// Sets the application name which is used to define the name of the downloaded executable.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_app_name("afetch")
+    ///     .build();
+    /// ```
+    pub fn with_app_name<S: Into<String>>(mut self, app_name: S) -> Self {
+        self.app_name = Some(app_name.into());
+
+        self
+    }
+
+    /// Sets the GitHub token which will be used to make requests to the GitHub API.
+    ///
+    /// # Arguments
+    ///
+    /// * `github_token` - The GitHub token to use for authentication.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_github_token("some")
+    ///     .build();
+    /// ```
+    pub fn with_github_token<S: Into<String>>(mut self, github_token: S) -> Self {
+        self.github_token = Some(github_token.into());
+
+        self
+    }
+
+    /// Sets the GitHub token from the one stored by the gh CLI, for developer-facing tools.
+    ///
+    /// The token is read from the `hosts.yml` file of the gh CLI, located in `GH_CONFIG_DIR` when
+    /// set, otherwise in `%AppData%\GitHub CLI\` on Windows and in `$XDG_CONFIG_HOME/gh/`, by
+    /// default `~/.config/gh/`, on other platforms. If no token is found there, e.g.: because the
+    /// gh CLI stores it in the system keyring, the `GH_TOKEN` then `GITHUB_TOKEN` environment
+    /// variables are used.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance. If no token is found, the `build` method
+    /// returns a `BuilderInvalidElement` error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_github_token_from_gh_cli()
+    ///     .build();
+    /// ```
+    pub fn with_github_token_from_gh_cli(mut self) -> Self {
+        match gh_cli::find_github_token() {
+            Some(github_token) => self.github_token = Some(github_token),
+            None => {
+                self.invalid_element = Some(BuilderInvalidElement(
+                    "github_token was found neither in the gh CLI configuration nor in the GH_TOKEN and GITHUB_TOKEN environment variables".to_owned(),
+                ))
+            }
+        }
+
+        self
+    }
+
+    /// Sets the rust target that will be searched for in GitHub releases.
+    ///
+    /// # Arguments
+    ///
+    /// * `rust_target` - The Rust target, e.g.: i686-unknown-freebsd.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_rust_target("i686-unknown-freebsd")
+    ///     .build();
+    /// ```
+    pub fn with_rust_target<S: Into<String>>(mut self, rust_target: S) -> Self {
+        self.rust_target = Some(rust_target.into());
+
+        self
+    }
+
+    /// Also matches the assets whose name contains a shortened form of the Rust target, e.g.:
+    /// `x86_64-linux` for `x86_64-unknown-linux-gnu`, when no asset matches the exact target.
+    ///
+    /// The `{rust_target}` placeholder is replaced by the following forms, in this order, until an
+    /// asset matches:
+    /// 1. the exact target, e.g.: `x86_64-unknown-linux-gnu`;
+    /// 2. the target without its vendor, e.g.: `x86_64-linux-gnu`;
+    /// 3. the target without its vendor and its ABI, e.g.: `x86_64-linux`.
+    ///
+    /// The last form may also match an asset built for another ABI, e.g.: `x86_64-linux-musl`,
+    /// the assets are thus better excluded with `with_asset_exclude_patterns` in this case.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_flexible_target_matching()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_flexible_target_matching(mut self) -> Self {
+        self.flexible_target_matching = true;
+
+        self
+    }
+
+    /// Sets information about the GitHub repository on which the releases are located.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_owner` - The GitHub repository owner, e.g.: `Asthowen`.
+    /// * `repository_name` - The GitHub repository name, e.g.: `AFetch`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_repository_infos("Asthowen", "AFetch")
+    ///     .build();
+    /// ```
+    pub fn with_repository_infos<S: Into<String>>(
+        mut self,
+        repository_owner: S,
+        repository_name: S,
+    ) -> Self {
+        self.repository_infos = Some((repository_owner.into(), repository_name.into()));
+
+        self
+    }
+
+    /// Sets the GitHub repository on which the releases are published, when it differs from the
+    /// repository of the application, e.g.: a public mirror distributing the binaries of a
+    /// closed-source application.
+    ///
+    /// Releases and their assets are resolved from this repository, while the repository set
+    /// with `with_repository_infos` or `with_repository` stays the repository of the application.
+    /// When only the release repository is set, it is used for both.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_owner` - The owner of the release repository, e.g.: `Asthowen`.
+    /// * `repository_name` - The name of the release repository, e.g.: `AFetch-dist`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_repository_infos("Asthowen", "AFetch")
+    ///     .with_release_repository("Asthowen", "AFetch-dist")
+    ///     .build();
+    /// ```
+    pub fn with_release_repository<S: Into<String>>(
+        mut self,
+        repository_owner: S,
+        repository_name: S,
+    ) -> Self {
+        self.release_repository_infos = Some((repository_owner.into(), repository_name.into()));
+
+        self
+    }
+
+    /// Sets information about the GitHub repository from its canonical `owner/name` form.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - The GitHub repository, either as `owner/name`, e.g.: `Asthowen/AFetch`, or as
+    ///   its URL, e.g.: `https://github.com/Asthowen/AFetch`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance. If the repository cannot be split into a
+    /// non-empty owner and name, the `build` method returns a `BuilderInvalidElement` error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_repository("https://github.com/Asthowen/AFetch")
+    ///     .build();
+    /// ```
+    pub fn with_repository<S: AsRef<str>>(mut self, repository: S) -> Self {
+        match Self::parse_repository(repository.as_ref()) {
+            Some(repository_infos) => self.repository_infos = Some(repository_infos),
+            None => {
+                self.invalid_element = Some(BuilderInvalidElement(format!(
+                    "repository \"{}\" is not in the owner/name format",
+                    repository.as_ref()
+                )))
+            }
+        }
+
+        self
+    }
+
+    /// Splits a repository given as `owner/name` or as a GitHub URL into its owner and name.
+    fn parse_repository(repository: &str) -> Option<(String, String)> {
+        let repository: &str = repository.trim().trim_end_matches('/');
+        let repository: &str = repository
+            .strip_prefix("https://")
+            .or_else(|| repository.strip_prefix("http://"))
+            .unwrap_or(repository);
+        let repository: &str = repository.strip_prefix("www.").unwrap_or(repository);
+        let repository: &str = repository.strip_prefix("github.com/").unwrap_or(repository);
+        let repository: &str = repository.strip_suffix(".git").unwrap_or(repository);
+
+        let (owner, name) = repository.split_once('/')?;
+        if owner.is_empty() || name.is_empty() || name.contains('/') {
+            return None;
+        }
+
+        Some((owner.to_owned(), name.to_owned()))
+    }
+
+    /// Creates a builder configured from the latest release of a repository, for the simplest
+    /// setup: see `detect_from_repo_url`, which this method calls on a builder using the default
+    /// Reqwest client.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_url` - The URL of the repository, e.g.: `https://github.com/Asthowen/AFetch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) if the URL is not the one of a GitHub repository, if an error occurs while fetching the last release, or if none of its assets is built for the current platform.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `GithubUpdater` builder instance, to complete with the download
+    /// path before building it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut updater_builder = GithubUpdater::from_repo_url("https://github.com/Asthowen/AFetch")
+    ///     .await?
+    ///     .with_download_path(&Path::new("/usr/local/bin"))
+    ///     .build()?;
+    /// ```
+    pub async fn from_repo_url<S: AsRef<str>>(repo_url: S) -> Result<Self, UpdateError> {
+        Self::builder()
+            .with_initialized_reqwest_client()
+            .detect_from_repo_url(repo_url)
+            .await
+    }
+
+    /// Configures the builder from the latest release of a repository: the repository, the name
+    /// of the application, which is the one of the repository, and the release filename pattern,
+    /// matching the asset whose name mentions the operating system and the architecture of the
+    /// current platform, e.g.: `afetch-{app_version}-x86_64-unknown-linux-gnu.tar.gz`.
+    ///
+    /// The checksum and signature files of the asset, whose names also match the pattern, e.g.:
+    /// `afetch-{app_version}-x86_64-unknown-linux-gnu.tar.gz.sha256`, are excluded with
+    /// `with_asset_exclude_patterns`.
+    ///
+    /// The detected configuration is logged, and can be read with the `repository`, `app_name`
+    /// and `pattern` methods, or overridden with the usual builder methods. The other options of
+    /// the builder, such as the client or the GitHub token, are used to fetch the release.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_url` - The URL of the repository, e.g.: `https://github.com/Asthowen/AFetch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) if the URL is not the one of a GitHub repository, if an error occurs while fetching the last release, or if none of its assets is built for the current platform.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .detect_from_repo_url("https://github.com/Asthowen/AFetch")
+    ///     .await?
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .build()?;
+    /// ```
+    pub async fn detect_from_repo_url<S: AsRef<str>>(
+        self,
+        repo_url: S,
+    ) -> Result<Self, UpdateError> {
+        let (owner, name): (String, String) = Self::parse_repository(repo_url.as_ref())
+            .ok_or_else(|| {
+                UpdateError::new(format!(
+                    "\"{}\" is not the URL of a GitHub repository.",
+                    repo_url.as_ref()
+                ))
+            })?;
+        let repository: String = format!("{}/{}", owner, name);
+        let updater_builder: Self = self.with_repository(&repository).with_app_name(&name);
+
+        // The release is fetched by a copy of the builder, which has no pattern nor download path
+        let mut probe: Self = updater_builder
+            .clone()
+            .with_asset_match(AssetMatch::default())
+            .with_download_path(&std::env::temp_dir())
+            .build()
+            .map_err(|error| UpdateError::new(error.to_string()))?;
+        let release: Release = probe.fetch_release().await?;
+        let asset: &Asset = platform_asset(&release.assets).ok_or_else(|| {
+            UpdateError::new(format!(
+                "No asset of the latest release of {} is built for {} {}, the assets are: {}.",
+                repository,
+                std::env::consts::OS,
+                std::env::consts::ARCH,
+                release
+                    .assets
+                    .iter()
+                    .map(|asset| asset.name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ))
+        })?;
+
+        let version_source: Option<(VersionSource, &str)> = [
+            VersionSource::TagName,
+            VersionSource::TagNameNoPrefix,
+            VersionSource::ReleaseName,
+        ]
+        .into_iter()
+        .map(|version_source| {
+            (
+                version_source,
+                version_source.resolve(&release.name, &release.tag_name),
+            )
+        })
+        .find(|(_, version)| !version.is_empty() && asset.name.contains(version));
+        let (pattern, version_source): (String, VersionSource) = match version_source {
+            Some((version_source, version)) => (
+                asset.name.replacen(version, "{app_version}", 1),
+                version_source,
+            ),
+            None => (asset.name.clone(), VersionSource::default()),
+        };
+        let mut asset_exclude_patterns: Vec<String> =
+            updater_builder.asset_exclude_patterns.clone();
+        asset_exclude_patterns.extend(
+            SIDECAR_EXTENSIONS
+                .iter()
+                .filter(|extension| !asset.name.contains(*extension))
+                .map(|extension| (*extension).to_owned()),
+        );
+        log::info!(
+            "Detected the configuration of {}: application {}, asset {}, pattern {}.",
+            repository,
+            name,
+            asset.name,
+            pattern
+        );
+
+        Ok(updater_builder
+            .with_release_file_name_pattern(pattern)
+            .with_version_placeholder_source(version_source)
+            .with_asset_exclude_patterns(asset_exclude_patterns))
+    }
+
+    /// Sets the file download folder path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The download folder path, e.g.: `~/Downloads/`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::path::Path;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_download_path(&Path::new("~/Downloads/"))
+    ///     .build();
+    /// ```
+    pub fn with_download_path<P: AsRef<Path>>(mut self, path: &P) -> Self {
+        self.download_path = Some(path.as_ref().to_owned());
+
+        self
+    }
+
+    /// Reads the file download folder path from an environment variable when building, e.g.: to
+    /// let the users of a CLI tool choose the install location with `AFETCH_DOWNLOAD_DIR`.
+    ///
+    /// If the variable is set and not empty, its value takes precedence over the path set with
+    /// `with_download_path`, whatever the order of the calls. Otherwise, this path is used, and
+    /// `build` returns an error if none is set.
+    ///
+    /// The value is expanded: a leading `~` is replaced by the home directory (`HOME`, or
+    /// `USERPROFILE` on Windows), and `$NAME` or `${NAME}` by the value of the environment
+    /// variable `NAME`. `build` returns an error if one of these variables is not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `variable` - The name of the environment variable, e.g.: `AFETCH_DOWNLOAD_DIR`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::path::Path;
+    ///
+    /// std::env::set_var("AFETCH_DOWNLOAD_DIR", "${AFETCH_ROOT}/bin");
+    /// std::env::set_var("AFETCH_ROOT", "/opt/afetch");
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&Path::new("/usr/local/bin"))
+    ///     .with_download_path_from_env("AFETCH_DOWNLOAD_DIR")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(updater_builder.download_path(), Some(Path::new("/opt/afetch/bin")));
+    /// ```
+    pub fn with_download_path_from_env(mut self, variable: &str) -> Self {
+        self.download_path_env = Some(variable.to_owned());
+
+        self
+    }
+
+    /// Sets other paths in which `force_update` installs the file, e.g.: a per-user and a shared
+    /// binary directory. The file is downloaded and verified once, then copied to each path along
+    /// with a version file.
+    ///
+    /// If a copy fails, the error names the path concerned, and the paths already updated are kept
+    /// or rolled back according to `with_additional_paths_failure_policy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - The additional paths, e.g.: `/usr/local/bin`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::path::PathBuf;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_additional_download_paths(vec![PathBuf::from("/usr/local/bin")])
+    ///     .build();
+    /// ```
+    pub fn with_additional_download_paths(mut self, paths: Vec<PathBuf>) -> Self {
+        self.additional_download_paths = paths;
+
+        self
+    }
+
+    /// Sets what happens to the additional download paths already updated when the file cannot be
+    /// installed in one of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to apply, `AdditionalPathsFailurePolicy::Keep` by default.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{AdditionalPathsFailurePolicy, GithubUpdater};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_additional_paths_failure_policy(AdditionalPathsFailurePolicy::Rollback)
+    ///     .build();
+    /// ```
+    pub fn with_additional_paths_failure_policy(
+        mut self,
+        policy: AdditionalPathsFailurePolicy,
+    ) -> Self {
+        self.additional_paths_failure_policy = policy;
+
+        self
+    }
+
+    /// Sets the extension of the downloaded file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The extension, e.g.: `exe`, `so`, `dll`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_file_extension("so")
+    ///     .build();
+    /// ```
+    pub fn with_file_extension<S: Into<String>>(mut self, extension: S) -> Self {
+        self.file_extension = Some(extension.into());
+
+        self
+    }
+
+    /// Disables the erasure of the previous file before downloading a new one.
+    ///
+    /// When this option is enabled, the original file is preserved, and the new file is saved
+    /// with the prefix `new_` added to its filename. Note that the version information
+    /// in the text file will still be updated accordingly.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .without_erase_previous_file()
+    ///     .build();
+    /// ```
+    pub fn without_erase_previous_file(mut self) -> Self {
+        self.erase_previous_file = false;
+
+        self
+    }
+
+    /// Names the downloaded file after the asset of the release, e.g.:
+    /// `afetch-1.0.0-x86_64-unknown-linux-gnu`, instead of the application name followed by the
+    /// extension set with `with_file_extension`, which is then ignored.
+    ///
+    /// The name of the file is recorded in the version file with `with_json_version_record`, so
+    /// that the file of the previous release is removed once the new one is installed, unless
+    /// `without_erase_previous_file` is enabled.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_json_version_record()
+    ///     .with_use_asset_filename()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_use_asset_filename(mut self) -> Self {
+        self.use_asset_filename = true;
+
+        self
+    }
+
+    /// Sets whether symbolic links are followed, which is the default.
+    ///
+    /// When enabled, the download path is resolved by the `build` method, if it exists, and the
+    /// operations are done on the resolved directory. A file of the download path which is a
+    /// symbolic link, e.g.: `~/.local/bin/afetch` pointing to `/opt/afetch/afetch`, is replaced
+    /// by updating the file it points to, the link being left as is. When disabled, the link
+    /// itself is replaced by the new file.
+    ///
+    /// # Arguments
+    ///
+    /// * `follow_symlinks` - `false` to replace the symbolic links themselves.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_follow_symlinks(false)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+
+        self
+    }
+
+    /// Sets whether a missing download directory, or additional download directory, is created
+    /// when updating, which is the default. When disabled, updating fails if one of them does not
+    /// exist, e.g.: to avoid creating a stray directory because of a typo in the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `create_missing_dirs` - `false` to fail when a download directory does not exist.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_create_missing_dirs(false)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_create_missing_dirs(mut self, create_missing_dirs: bool) -> Self {
+        self.create_missing_dirs = create_missing_dirs;
+
+        self
+    }
+
+    /// Sets the owner of the installed file and of its version file on Unix, e.g.: a service
+    /// account when the updater runs as root. The ownership is changed once the file has its final
+    /// name, in the download path and in the additional download paths.
+    ///
+    /// Changing the owner usually requires privileges: when it fails, a warning is logged and the
+    /// update goes on. On Windows, this option has no effect besides a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the owner.
+    /// * `gid` - The group ID of the owner.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_file_owner(1001, 1001)
+    ///     .build();
+    /// ```
+    pub fn with_file_owner(mut self, uid: u32, gid: u32) -> Self {
+        self.file_owner = Some((uid, gid));
+
+        self
+    }
+
+    /// Writes an event to a Unix socket, or a Windows named pipe, at the end of each successful
+    /// update, e.g.: for a supervisor restarting the application once its new binary is installed.
+    ///
+    /// The event is an `UpdateEvent`, serialized as a line of JSON. If the socket is unavailable, a
+    /// warning is logged and the update goes on.
+    ///
+    /// # Arguments
+    ///
+    /// * `notify_socket` - The path of the socket, e.g.: `/run/afetch/update.sock` or
+    ///   `\\.\pipe\afetch-update`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_notify_socket("/run/afetch/update.sock")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_notify_socket<P: AsRef<Path>>(mut self, notify_socket: P) -> Self {
+        self.notify_socket = Some(notify_socket.as_ref().to_path_buf());
+
+        self
+    }
+
+    /// Sets the name of the manifest asset of the releases of an application distributed as a
+    /// directory of files, e.g.: `manifest.json`, to synchronize the download path with
+    /// `sync_manifest` instead of downloading a single asset.
+    ///
+    /// The manifest is a `Manifest`, listing the path and the SHA-256 of each file, each file being
+    /// attached to the release as an asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest_asset` - The name of the manifest asset.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_manifest_sync("manifest.json")
+    ///     .build();
+    /// ```
+    pub fn with_manifest_sync<S: Into<String>>(mut self, manifest_asset: S) -> Self {
+        self.manifest_asset = Some(manifest_asset.into());
+
+        self
+    }
+
+    /// Writes the version file as a JSON record (`VersionRecord`) holding, along with the version,
+    /// the release tag, the download time, the SHA-256 of the file and the URL it was downloaded
+    /// from, instead of the version only.
+    ///
+    /// Version files in either format are read, so this option can be enabled on existing
+    /// installations.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_json_version_record()
+    ///     .build();
+    /// ```
+    pub fn with_json_version_record(mut self) -> Self {
+        self.json_version_record = true;
+
+        self
+    }
+
+    /// Keeps the version record of the installed file in a store instead of the version file of
+    /// the download path, e.g.: when the installation directory is wiped while the state of the
+    /// user persists. Built-in stores are `KeyringVersionStore` and, on Windows with the
+    /// `registry` feature, `RegistryVersionStore`.
+    ///
+    /// The store is used to check for updates and to record the installed version. The version
+    /// files of the additional download paths and of the staged updates are still written.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_store` - The store of the version records.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use async_trait::async_trait;
+    /// use github_updater::errors::update_error::UpdateError;
+    /// use github_updater::{GithubUpdater, VersionStore};
+    /// use std::collections::HashMap;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Clone, Default)]
+    /// struct MemoryVersionStore(Arc<Mutex<HashMap<String, String>>>);
+    ///
+    /// #[async_trait]
+    /// impl VersionStore for MemoryVersionStore {
+    ///     async fn load(&self, app_name: &str) -> Result<Option<String>, UpdateError> {
+    ///         Ok(self.0.lock().unwrap().get(app_name).cloned())
+    ///     }
+    ///
+    ///     async fn store(&self, app_name: &str, record: &str) -> Result<(), UpdateError> {
+    ///         self.0.lock().unwrap().insert(app_name.to_owned(), record.to_owned());
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_version_store(MemoryVersionStore::default())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_version_store<S: VersionStore + 'static>(mut self, version_store: S) -> Self {
+        self.version_store = Some(Hook::new(Arc::new(version_store)));
+
+        self
+    }
+
+    /// Also compares the SHA-256 of the installed file with the one published for the release
+    /// asset when checking for an update, so that an asset re-uploaded without a version bump is
+    /// downloaded again.
+    ///
+    /// The published SHA-256 is the `digest` returned by the GitHub API for the asset, otherwise
+    /// the one listed in a checksum file attached to the release: `<asset>.sha256`, `SHA256SUMS`
+    /// or `checksums.txt`. Reading a checksum file costs one more request each time the versions
+    /// are identical. If no SHA-256 is published, only the versions are compared.
+    ///
+    /// The SHA-256 of the installed file is read from the version file, so this option requires
+    /// `with_json_version_record`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_json_version_record()
+    ///     .with_content_based_update_check()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_content_based_update_check(mut self) -> Self {
+        self.content_based_update_check = true;
+
+        self
+    }
+
+    /// Verifies the downloaded file against a checksum file hosted outside of the release, e.g.:
+    /// `https://downloads.example.com/{tag}/SHA256SUMS`, in the format written by `sha256sum`.
+    ///
+    /// The checksum file is fetched once the asset is downloaded, and the download fails if the
+    /// SHA-256 of the asset is not the one listed. The other verifications, such as the digest
+    /// announced by the server, are still done. This checksum file is also the one read by
+    /// `with_content_based_update_check`, instead of those attached to the release.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The URL of the checksum file, which can contain:
+    ///    * `tag`: The Git tag of the release, e.g.: `v1.0.0`.
+    ///    * `asset_name`: The name of the matched asset.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_checksum_url_template("https://downloads.example.com/{tag}/SHA256SUMS")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_checksum_url_template<S: Into<String>>(mut self, template: S) -> Self {
+        self.checksum_url_template = Some(template.into());
+
+        self
+    }
+
+    /// Follows a release whose tag never changes while its assets are replaced, e.g.: a `nightly`
+    /// release rebuilt from the default branch, instead of the latest release.
+    ///
+    /// The release is fetched by its tag, and an identical version no longer means that the
+    /// installed file is up to date: as with `with_content_based_update_check`, which this option
+    /// implies, the SHA-256 of the installed file is compared with the one published for the
+    /// asset, but the update is also considered available when any of them is unknown. Publishing
+    /// the `digest` of the assets, or a checksum file, avoids downloading the release each time.
+    ///
+    /// The SHA-256 of the installed file is read from the version file, so this option requires
+    /// `with_json_version_record`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rolling_tag` - The tag of the release, e.g.: `nightly`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_json_version_record()
+    ///     .with_rolling_tag("nightly")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_rolling_tag<S: Into<String>>(mut self, rolling_tag: S) -> Self {
+        self.rolling_tag = Some(rolling_tag.into());
+        self.content_based_update_check = true;
+
+        self
+    }
+
+    /// Fetches the release with the given ID instead of the latest one, e.g.: the ID given by the
+    /// payload of a `release` webhook, which avoids resolving the release again from its tag.
+    ///
+    /// The asset is chosen in this release as in the latest one, and fetching it fails if no
+    /// release of the repository has this ID. This option cannot be combined with
+    /// `with_rolling_tag`.
+    ///
+    /// # Arguments
+    ///
+    /// * `release_id` - The ID of the release.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_release_id(123456789);
+    /// assert!(updater_builder.clone().build().is_ok());
+    /// assert!(updater_builder.with_rolling_tag("nightly").build().is_err());
+    /// ```
+    pub fn with_release_id(mut self, release_id: u64) -> Self {
+        self.release_id = Some(release_id);
+
+        self
+    }
+
+    /// Sets the minimum interval between two checks for an update, e.g.: one day for an
+    /// application checking at each launch, so that frequent launches do not exhaust the rate
+    /// limit of the user.
+    ///
+    /// When the installed version was found up to date less than `min_check_interval` ago,
+    /// `check_for_update` and `update_if_needed` return without any request, reporting the latest
+    /// version found then and setting `check_skipped`. The time of the last check is stored in the
+    /// `last-check-<app name>.txt` file of the download path. A check finding an available update
+    /// is not recorded, so that the update is still reported by the next check.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_check_interval` - The minimum duration between two checks.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_min_check_interval(Duration::from_secs(24 * 60 * 60))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_min_check_interval(mut self, min_check_interval: Duration) -> Self {
+        self.min_check_interval = Some(min_check_interval);
+
+        self
+    }
+
+    /// Checks for an update even if the previous check is more recent than the interval set with
+    /// `with_min_check_interval`, e.g.: when the user explicitly asks for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `force_check` - Whether to ignore the minimum check interval.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_min_check_interval(Duration::from_secs(24 * 60 * 60))
+    ///     .with_force_check(std::env::args().any(|arg| arg == "--check-update"))
+    ///     .build();
+    /// ```
+    pub fn with_force_check(mut self, force_check: bool) -> Self {
+        self.force_check = force_check;
+
+        self
+    }
+
+    /// Downloads the file under a neutral extension and gives it its real name only once it has
+    /// been verified.
+    ///
+    /// Some antivirus software scan, and lock, any new file named like an executable, which can
+    /// make the final rename fail. Downloading to e.g. `app.exe.download` avoids these scans while
+    /// the file is written. Combine it with `with_rename_retry` to tolerate the lock taken when
+    /// the file gets its final name.
+    ///
+    /// # Arguments
+    ///
+    /// * `extension` - The extension appended to the file name during the download, e.g.: `download`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_pending_file_extension("download")
+    ///     .build();
+    /// ```
+    pub fn with_pending_file_extension<S: Into<String>>(mut self, extension: S) -> Self {
+        self.pending_file_extension = Some(extension.into());
+
+        self
+    }
+
+    /// Sets the size of the chunks in which files are downloaded: at most this many bytes are
+    /// hashed per iteration, and writes are buffered up to this size.
+    ///
+    /// Small chunks suit devices with little memory, large chunks reduce the number of system
+    /// calls when downloading big files on servers. The default is `DEFAULT_CHUNK_BUFFER_SIZE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_buffer_size` - The size of the chunks, in bytes. `0` is rejected by the `build` method.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_chunk_buffer_size(1024 * 1024)
+    ///     .build();
+    /// ```
+    pub fn with_chunk_buffer_size(mut self, chunk_buffer_size: usize) -> Self {
+        if chunk_buffer_size == 0 {
+            self.invalid_element = Some(BuilderInvalidElement(
+                "chunk_buffer_size must be greater than 0".to_owned(),
+            ));
+        }
+        self.chunk_buffer_size = chunk_buffer_size;
+
+        self
+    }
+
+    /// Limits the rate at which the assets are downloaded, e.g.: for an updater running in the
+    /// background on a metered connection. The download sleeps between the chunks whenever it is
+    /// ahead of the rate, without busy-waiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes_per_second` - The maximum rate, in bytes per second. `0` is rejected by the `build` method.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_download_rate_limit(512 * 1024)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_download_rate_limit(mut self, bytes_per_second: u64) -> Self {
+        if bytes_per_second == 0 {
+            self.invalid_element = Some(BuilderInvalidElement(
+                "download_rate_limit must be greater than 0".to_owned(),
+            ));
+        }
+        self.download_rate_limit = Some(bytes_per_second);
+
+        self
+    }
+
+    /// Retries the renames done at the end of an update when they fail, e.g.: because an antivirus
+    /// temporarily locks the downloaded file.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of rename attempts, `0` is treated as `1`.
+    /// * `delay` - The delay to wait between two attempts.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_rename_retry(5, Duration::from_millis(500))
+    ///     .build();
+    /// ```
+    pub fn with_rename_retry(mut self, attempts: u32, delay: Duration) -> Self {
+        self.rename_attempts = attempts.max(1);
+        self.rename_retry_delay = delay;
+
+        self
+    }
+
+    /// Sets a function used to bring every version handled by the updater to a canonical form,
+    /// e.g.: to drop the build metadata of `1.2.0+build.45`.
+    ///
+    /// The normalizer is applied:
+    ///    * to the version read from the version file of the installed application;
+    ///    * to the version of the release fetched from GitHub, which is then used for the comparison
+    ///      with the installed one, written to the version file and returned in `DownloadInfos`.
+    ///
+    /// The `{app_version}` placeholder of the file name pattern still receives the release name
+    /// as published on GitHub, since it must match the asset names.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_normalizer` - The function returning the canonical form of a version.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_version_normalizer(|version| {
+    ///         version.split('+').next().unwrap_or(version).to_owned()
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn with_version_normalizer<F>(mut self, version_normalizer: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.version_normalizer = Some(Hook::new(Arc::new(version_normalizer)));
+
+        self
+    }
+
+    /// Sets the regular expression extracting the version from a release name holding more than
+    /// the version, e.g.: `MyApp 2.0 (Stable 1.4.2)`. The version is the first capture group,
+    /// searched in the release name, then in the tag name.
+    ///
+    /// The extracted version is used everywhere the release name would be: version comparisons,
+    /// version file and `{app_version}` placeholder. Fetching a release from which no version can
+    /// be extracted fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_extract_regex` - The regular expression, with a capture group around the version.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{GithubUpdater, Regex};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_version_extract_regex(Regex::new(r"Stable (\d+\.\d+\.\d+)").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_version_extract_regex(mut self, version_extract_regex: Regex) -> Self {
+        self.version_extract_regex = Some(version_extract_regex);
+
+        self
+    }
+
+    /// Checks that the downloaded file is an executable for the configured Rust target: ELF for
+    /// Linux and BSDs, Mach-O for Apple platforms and PE for Windows.
+    ///
+    /// This catches pattern mistakes that a checksum cannot, e.g.: a Linux binary downloaded for a
+    /// Windows target. Files that are not executables, such as archives, are not checked. When no
+    /// Rust target is set, the current platform is used.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_rust_target("x86_64-pc-windows-msvc")
+    ///     .with_verify_executable_format()
+    ///     .build();
+    /// ```
+    pub fn with_verify_executable_format(mut self) -> Self {
+        self.verify_executable_format = true;
+
+        self
+    }
+
+    /// Runs the downloaded file with harmless arguments, e.g.: `--version`, before it replaces the
+    /// installed one, and aborts the update if it does not exit with the expected code. This
+    /// catches files which are valid executables but cannot run, e.g.: built for another
+    /// architecture or linked against a missing library.
+    ///
+    /// The test is skipped for files which are not executables of the current platform, e.g.:
+    /// archives. On Unix, the file is made executable by its owner to be run. The process is
+    /// killed, and the test fails, if it runs for more than 30 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments given to the new file.
+    /// * `expected_exit` - The exit code the new file must return.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_smoke_test(vec!["--version".to_owned()], 0)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_smoke_test(mut self, args: Vec<String>, expected_exit: i32) -> Self {
+        self.smoke_test = Some(SmokeTest {
+            args,
+            expected_exit,
+            expected_output: None,
+        });
+
+        self
+    }
+
+    /// Also requires the standard output of the smoke test to contain a text, e.g.: the name of the
+    /// application printed by `--version`, see `with_smoke_test`.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_output` - The text the standard output of the new file must contain.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_smoke_test(vec!["--version".to_owned()], 0)
+    ///     .with_smoke_test_expected_output("afetch");
+    /// ```
+    pub fn with_smoke_test_expected_output<S: Into<String>>(mut self, expected_output: S) -> Self {
+        self.smoke_test_expected_output = Some(expected_output.into());
+
+        self
+    }
+
+    /// Decompresses the downloaded asset before storing it, for a release publishing a single
+    /// compressed file, e.g.: `afetch-x86_64-unknown-linux-gnu.gz`, with gzip, Zstandard or xz,
+    /// see `Compression`.
+    ///
+    /// The integrity checks and the signatures apply to the asset as published, the executable
+    /// format and the smoke test to the decompressed file. The SHA-256 of the version record is
+    /// the one of the asset, so that it can be compared with the published one.
+    /// `download_to_writer` writes the asset as it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `compression` - The compression of the asset.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{Compression, GithubUpdater};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{rust_target}.gz")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_store_decompressed(Compression::Gzip)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_store_decompressed(mut self, compression: Compression) -> Self {
+        self.store_decompressed = Some(compression);
+
+        self
+    }
+
+    /// Sets what `update_if_needed` does when the latest release is older than the installed
+    /// version. By default, older releases are installed (`DowngradePolicy::Allow`).
+    ///
+    /// # Arguments
+    ///
+    /// * `downgrade_policy` - The policy to apply to downgrades.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{DowngradePolicy, GithubUpdater};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_downgrade_policy(DowngradePolicy::Refuse)
+    ///     .build();
+    /// ```
+    pub fn with_downgrade_policy(mut self, downgrade_policy: DowngradePolicy) -> Self {
+        self.downgrade_policy = downgrade_policy;
+
+        self
+    }
+
+    /// Sets the version of the GitHub REST API requested through the `X-GitHub-Api-Version`
+    /// header. By default, `DEFAULT_GITHUB_API_VERSION` is requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_version` - The version of the API, e.g.: `2022-11-28`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_api_version("2022-11-28")
+    ///     .build();
+    /// ```
+    pub fn with_api_version<S: Into<String>>(mut self, api_version: S) -> Self {
+        self.api_version = api_version.into();
+
+        self
+    }
+
+    /// Sets what fills the `{app_version}` placeholder of the file name pattern, e.g.: the tag
+    /// name when the assets are named after the tag while the release name differs. The version
+    /// written to the version file is still the release name.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_source` - The source of the placeholder, `VersionSource::ReleaseName` by default.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{GithubUpdater, VersionSource};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_version_placeholder_source(VersionSource::TagNameNoPrefix)
+    ///     .build();
+    /// ```
+    pub fn with_version_placeholder_source(mut self, version_source: VersionSource) -> Self {
+        self.version_placeholder_source = version_source;
+
+        self
+    }
+
+    /// Matches the `{app_version}` placeholder of the pattern with any version instead of
+    /// replacing it, e.g.: to match `afetch-v1.2.0-linux` for a release named `1.2.0`.
+    ///
+    /// Among the assets matching the pattern, those whose version, without its `v` prefix, equals
+    /// the tag of the release (its version if it has no tag) are selected. If there is none, e.g.:
+    /// the tag is `stable`, those with the highest version are. Both versions are normalized with
+    /// the function set with `with_version_normalizer`, if any, before being compared.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_asset_version_autodetect()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_asset_version_autodetect(mut self) -> Self {
+        self.asset_version_autodetect = true;
+
+        self
+    }
+
+    /// Sets patterns excluding assets from the matching, e.g.: `.asc` or `.sha256` for the
+    /// signature and checksum files sharing their name with the file. An asset whose name contains
+    /// one of the patterns is ignored, before the release filename pattern is matched.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_exclude_patterns` - The patterns to exclude, e.g.: `.asc`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_asset_exclude_patterns(vec![".asc".to_owned(), ".sha256".to_owned()])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_asset_exclude_patterns(mut self, asset_exclude_patterns: Vec<String>) -> Self {
+        self.asset_exclude_patterns = asset_exclude_patterns;
+
+        self
+    }
+
+    /// Selects the assets by substrings their name must and must not contain, e.g.: `linux` and
+    /// `musl` but neither `debug` nor `sha256`, see `AssetMatch`.
+    ///
+    /// The assets which do not match are ignored, before the release filename pattern is matched.
+    /// The pattern is optional with this option: without it, the first asset matching is
+    /// downloaded, or the one chosen by the function set with `with_asset_selector`.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_match` - The substrings required and forbidden in the name of the asset.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{AssetMatch, GithubUpdater};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_asset_match(AssetMatch {
+    ///         must_contain: vec!["linux".to_owned(), "musl".to_owned()],
+    ///         must_not_contain: vec!["debug".to_owned(), "sha256".to_owned()],
+    ///     })
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_asset_match(mut self, asset_match: AssetMatch) -> Self {
+        self.asset_match = Some(asset_match);
+
+        self
+    }
+
+    /// Sets a function choosing the asset to download when several assets of the release match
+    /// the file name pattern, e.g.: to let the user pick a variant in a CLI.
+    ///
+    /// The function receives the matching assets, in the order of the release, and returns the
+    /// index of the one to download. Without selector, the first matching asset is downloaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_selector` - The function returning the index of the asset to download.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_asset_selector(|assets| {
+    ///         assets
+    ///             .iter()
+    ///             .position(|asset| asset.name.contains("musl"))
+    ///             .unwrap_or(0)
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn with_asset_selector<F>(mut self, asset_selector: F) -> Self
+    where
+        F: Fn(&[AssetInfo]) -> usize + Send + Sync + 'static,
+    {
+        self.asset_selector = Some(Hook::new(Arc::new(asset_selector)));
+
+        self
+    }
+
+    /// Sets a function rewriting the URLs of the assets before downloading them, e.g.: to route
+    /// the downloads through the caching proxy of a company while the release is still resolved
+    /// from GitHub.
+    ///
+    /// The function receives the URL of each asset to download, the file, its checksum file and
+    /// its signatures, and returns the URL to request instead. The GitHub token is only sent if
+    /// the rewritten URL still points to the GitHub API.
+    ///
+    /// # Arguments
+    ///
+    /// * `url_rewriter` - The function returning the URL to request.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_url_rewriter(|url| {
+    ///         url.replace("https://github.com/", "https://proxy.example.com/github/")
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_url_rewriter<F>(mut self, url_rewriter: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.url_rewriter = Some(Hook::new(Arc::new(url_rewriter)));
+
+        self
+    }
+
+    /// Restricts the downloads to a list of hosts, e.g.: `api.github.com`, from which GitHub serves
+    /// the assets, `objects.githubusercontent.com` and `release-assets.githubusercontent.com`, to
+    /// which it redirects, or an internal mirror. A download to another host fails with a
+    /// `DisallowedHost` error, so that a tampered release cannot make the updater download a file
+    /// from an unexpected server.
+    ///
+    /// The host of each asset URL, once rewritten by the function of `with_url_rewriter`, is
+    /// checked before it is requested, and so is the host of each redirect: the redirects are then
+    /// followed by the crate, as with `with_manual_redirect_handling`, so a client set with
+    /// `with_reqwest_client` must be created with `reqwest::redirect::Policy::none()`. Otherwise,
+    /// the host the redirects lead to is only checked before anything is read. The assets of a
+    /// local release source are not checked. The hosts are compared case-insensitively, and all
+    /// hosts are allowed if the list is empty, which is the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed_download_hosts` - The hosts the assets can be downloaded from.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_allowed_download_hosts(vec![
+    ///         "api.github.com".to_owned(),
+    ///         "objects.githubusercontent.com".to_owned(),
+    ///         "release-assets.githubusercontent.com".to_owned(),
+    ///     ])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_allowed_download_hosts(mut self, allowed_download_hosts: Vec<String>) -> Self {
+        self.allowed_download_hosts = allowed_download_hosts;
+
+        self
+    }
+
+    /// Sets the content types the server may serve the asset with, e.g.: to refuse a release note
+    /// matched by a loose pattern. The `Content-Type` header of the download response is compared,
+    /// without its parameters and case-insensitively, with each of them before writing anything.
+    /// A response without this header is refused.
+    ///
+    /// The assets of a local release (`ReleaseSource::LocalJson`) are not checked, as they have no
+    /// content type.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed_content_types` - The allowed content types, e.g.: `application/octet-stream`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_allowed_content_types(vec![
+    ///         "application/octet-stream".to_owned(),
+    ///         "application/x-executable".to_owned(),
+    ///     ])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_allowed_content_types(mut self, allowed_content_types: Vec<String>) -> Self {
+        self.allowed_content_types = allowed_content_types;
+
+        self
+    }
+
+    /// Sets the size the downloaded asset must have, e.g.: the one listed in a CI manifest, which
+    /// guards against truncated downloads independently of the headers of the server.
+    ///
+    /// The size is used in place of the `Content-Length` header when the server does not send it,
+    /// and the download fails if the header, or the size of the asset in the release, announces
+    /// another size, or if the downloaded file does not have this size.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_size` - The size of the asset, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_expected_size(4_194_304)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_expected_size(mut self, expected_size: u64) -> Self {
+        self.expected_size = Some(expected_size);
+
+        self
+    }
+
+    /// Measures the time spent in each step of the updates: fetch of the metadata, download,
+    /// verification and file operations, returned in `DownloadInfos::timings`, e.g.: to find out
+    /// whether a slow update is due to the network or to the disk.
+    ///
+    /// The durations are measured with the clock of Tokio, so they follow its paused time in tests.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_timing_metrics()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_timing_metrics(mut self) -> Self {
+        self.timing_metrics = true;
+
+        self
+    }
+
+    /// Completes the update even if the version of the installed file cannot be recorded, e.g.:
+    /// when a security policy forbids creating the version file next to the binary. A warning is
+    /// logged instead of returning an error once the file is installed.
+    ///
+    /// Without version record, the installed version is unknown: the next checks report an update
+    /// and `update_if_needed` downloads the release again.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_ignore_version_file_errors()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_ignore_version_file_errors(mut self) -> Self {
+        self.ignore_version_file_errors = true;
+
+        self
+    }
+
+    /// Downloads the assets with an external program, e.g.: `aria2c` for segmented downloads,
+    /// instead of the crate. The file is downloaded into a temporary directory, then verified and
+    /// installed as usual, version file included.
+    ///
+    /// The crate first requests the headers of the asset with a `HEAD` request, to follow the
+    /// redirect of the GitHub API to the storage host, which is given to the program, and to check
+    /// the host, the content type and the checksum headers as for any download. The request
+    /// headers are passed to the program through a configuration file readable by the current user
+    /// only, never on its command line, and the GitHub token is only passed if the asset is served
+    /// by the API host without any redirect. The file is verified against the checksum headers and
+    /// the size announced, if any, and against the checksum published for the asset. An error is
+    /// returned if the program fails or does not create the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `external_downloader` - The program and its arguments, e.g.: `ExternalDownloader::aria2c()`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{ExternalDownloader, GithubUpdater};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}-{app_version}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_external_downloader(ExternalDownloader::aria2c())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_external_downloader(mut self, external_downloader: ExternalDownloader) -> Self {
+        self.external_downloader = Some(external_downloader);
+
+        self
+    }
+
+    /// Ignores a malformed checksum header, e.g.: a `content-md5` header or a `content-digest`
+    /// member which is not valid base64 or is truncated, instead of failing the download.
+    ///
+    /// By default, such a header makes the download fail with a dedicated error, distinct from the
+    /// one reported when the checksum does not match. With this option, a warning is logged and
+    /// only the file size is verified.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_ignore_malformed_digest_header()
+    ///     .build();
+    /// ```
+    pub fn with_ignore_malformed_digest_header(mut self) -> Self {
+        self.ignore_malformed_digest_header = true;
+
+        self
+    }
+
+    /// Requires the integrity of every download to be verified: a download is refused before any
+    /// data is written when none of the verification mechanisms applies to it, i.e. a checksum
+    /// header of the server (`content-digest`, `repr-digest` or `content-md5`), the SHA-256
+    /// published by the GitHub API or in a checksum asset of the release, the checksum file of
+    /// `with_checksum_url_template`, or a signature or provenance verification.
+    ///
+    /// The `build` method fails if no verification mechanism is configured: a local release
+    /// source, whose checksums come from the same directory as its assets, requires one of
+    /// `with_checksum_url_template`, `with_sigstore_verification`, `with_provenance_verification`
+    /// or `with_authenticode_verification`, while the GitHub release source publishes the
+    /// SHA-256 of its assets.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_require_verification()
+    ///     .build();
+    /// ```
+    pub fn with_require_verification(mut self) -> Self {
+        self.require_verification = true;
+
+        self
+    }
+
+    /// Verifies the Sigstore signature of the downloaded file, against the bundle attached to the
+    /// release next to the asset, named after it with the `.sigstore` or `.sigstore.json`
+    /// extension.
+    ///
+    /// The verification is done with the `cosign` executable once the file is downloaded: if it
+    /// fails, or if no bundle is attached, the file is removed and an
+    /// `ErrorKind::SignatureVerificationFailed` error is returned. This option requires the
+    /// `sigstore` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `sigstore_config` - The identity and the issuer expected in the signing certificate.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{GithubUpdater, SigstoreConfig};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_sigstore_verification(SigstoreConfig {
+    ///         identity: "https://github.com/Asthowen/AFetch/.github/workflows/release.yml@refs/heads/main".to_owned(),
+    ///         issuer: "https://token.actions.githubusercontent.com".to_owned(),
+    ///         cosign_path: None,
+    ///     })
+    ///     .build();
+    /// ```
+    #[cfg(feature = "sigstore")]
+    pub fn with_sigstore_verification(mut self, sigstore_config: SigstoreConfig) -> Self {
+        self.sigstore_config = Some(sigstore_config);
+
+        self
+    }
+
+    /// Verifies the SLSA provenance of the downloaded file, against the attestation attached to
+    /// the release: the one named after the asset with the `.intoto.jsonl` extension, otherwise
+    /// the first asset with this extension, e.g.: `multiple.intoto.jsonl`.
+    ///
+    /// The supported format is the one produced by the SLSA GitHub generator: a JSON Lines file of
+    /// DSSE envelopes, signed with Sigstore, each wrapping an in-toto statement with a SLSA
+    /// provenance predicate (v0.2 or v1.0). The attestations API of GitHub is not supported.
+    ///
+    /// The verification is done with the `slsa-verifier` executable once the file is downloaded:
+    /// the digest of the file must be a subject of the attestation, which must have been produced
+    /// by the expected builder, from the expected repository and, when known, from the tag of the
+    /// release. If it fails, or if no attestation can be downloaded, the file is removed and an
+    /// `ErrorKind::ProvenanceVerificationFailed` error is returned. This option requires the
+    /// `provenance` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `provenance_config` - The builder and the source repository expected in the attestation.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{GithubUpdater, ProvenanceConfig};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_provenance_verification(ProvenanceConfig {
+    ///         builder_id: "https://github.com/slsa-framework/slsa-github-generator/.github/workflows/generator_generic_slsa3.yml".to_owned(),
+    ///         source_uri: None,
+    ///         slsa_verifier_path: None,
+    ///     })
+    ///     .build();
+    /// ```
+    #[cfg(feature = "provenance")]
+    pub fn with_provenance_verification(mut self, provenance_config: ProvenanceConfig) -> Self {
+        self.provenance_config = Some(provenance_config);
+
+        self
+    }
+
+    /// Verifies the Authenticode signature of the downloaded file, e.g.: a `.exe`, once it is
+    /// downloaded. If it fails, the file is removed and an error is returned.
+    ///
+    /// The signature is verified with the `WinVerifyTrust` function of the WinTrust API, using the
+    /// `WINTRUST_ACTION_GENERIC_VERIFY_V2` policy: the file must be signed, unaltered, and its
+    /// certificate chain must lead to a trusted root without any revoked certificate. When a
+    /// subject is expected, it is compared with the simple display name of the signing
+    /// certificate, as returned by `CertGetNameStringW` (usually its common name).
+    ///
+    /// This option is only available on Windows and requires the `authenticode` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_subject` - The name of the expected publisher, e.g.: `Asthowen`, or `None` to
+    ///   accept any trusted publisher.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_authenticode_verification(Some("Asthowen"))
+    ///     .build();
+    /// ```
+    #[cfg(all(windows, feature = "authenticode"))]
+    pub fn with_authenticode_verification(mut self, expected_subject: Option<&str>) -> Self {
+        self.authenticode_verification = true;
+        self.authenticode_subject = expected_subject.map(str::to_owned);
+
+        self
+    }
+
+    /// Sets where the release metadata and assets are read from. By default, they are fetched from
+    /// GitHub (`ReleaseSource::GitHub`).
+    ///
+    /// With `ReleaseSource::LocalJson`, the whole update works without network access: the release
+    /// is parsed from the JSON file, and the matched asset is copied from the directory of this
+    /// file and verified against the size announced in the release.
+    ///
+    /// # Arguments
+    ///
+    /// * `release_source` - The source of the release.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{GithubUpdater, ReleaseSource};
+    /// use std::path::PathBuf;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_release_source(ReleaseSource::LocalJson(PathBuf::from(
+    ///         "/mnt/mirror/afetch/release.json",
+    ///     )))
+    ///     .build();
+    /// ```
+    pub fn with_release_source(mut self, release_source: ReleaseSource) -> Self {
+        self.release_source = release_source;
+
+        self
+    }
+
+    /// Retries the failed requests, with an exponential backoff: the first retry waits `backoff`,
+    /// and each following one waits twice as long as the previous one.
+    ///
+    /// Which failures are retried is decided by the predicate set with `with_retry_predicate`,
+    /// by default server errors (5xx), rate limiting (429), secondary rate limits and requests
+    /// without response. The retries of requests rejected by the secondary rate limits wait at
+    /// least the delay requested by GitHub, see `SecondaryRateLimited`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_retries` - The maximum number of retries of a request, `0` disabling retries.
+    /// * `backoff` - The delay before the first retry.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_retry(3, Duration::from_secs(1))
+    ///     .build();
+    /// ```
+    pub fn with_retry(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = backoff;
+
+        self
+    }
+
+    /// Sets the function deciding whether a request attempt must be retried, replacing the default
+    /// policy (`RetryContext::is_retryable_by_default`).
+    ///
+    /// The predicate is only consulted while retries remain, as configured with `with_retry`, and
+    /// the backoff is applied before each retry it accepts.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_predicate` - The function returning `true` if the attempt must be retried.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_retry(3, Duration::from_secs(1))
+    ///     .with_retry_predicate(|context| {
+    ///         context.is_retryable_by_default() || context.status == Some(403)
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn with_retry_predicate<F>(mut self, retry_predicate: F) -> Self
+    where
+        F: Fn(&RetryContext) -> bool + Send + Sync + 'static,
+    {
+        self.retry_predicate = Some(Hook::new(Arc::new(retry_predicate)));
+
+        self
+    }
+
+    /// Sets a function called when a download fails an integrity check, e.g.: to log the details
+    /// of a recurrent corruption caused by a broken mirror.
+    ///
+    /// The function is called before the downloaded file is removed, the error being returned
+    /// afterwards as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_verification_failure` - The function receiving the details of the failure.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_on_verification_failure(|failure| {
+    ///         eprintln!(
+    ///             "{} is corrupted: {:?} expected, {:?} received",
+    ///             failure.asset_url, failure.expected_digest, failure.actual_digest
+    ///         );
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn with_on_verification_failure<F>(mut self, on_verification_failure: F) -> Self
+    where
+        F: Fn(&VerificationFailure) + Send + Sync + 'static,
+    {
+        self.on_verification_failure = Some(Hook::new(Arc::new(on_verification_failure)));
+
+        self
+    }
+
+    /// Keeps the downloads failing an integrity check in a quarantine directory instead of only
+    /// removing them, e.g.: to inspect the files served by a broken mirror.
+    ///
+    /// The file is copied to `quarantine_dir` with the suffix `.corrupt`, e.g.:
+    /// `afetch-1700000000.corrupt`, along with a note `afetch-1700000000.corrupt.json` holding the
+    /// details of the failure (`VerificationFailure`), before being removed from the download path
+    /// as usual. A failure to quarantine the file is logged, the error of the verification being
+    /// returned anyway.
+    ///
+    /// # Arguments
+    ///
+    /// * `quarantine_dir` - The directory keeping the corrupt downloads, created if needed.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_quarantine_corrupt_downloads(&std::env::temp_dir().join("quarantine"))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_quarantine_corrupt_downloads<P: AsRef<Path>>(mut self, quarantine_dir: P) -> Self {
+        self.quarantine_dir = Some(quarantine_dir.as_ref().to_path_buf());
+
+        self
+    }
+
+    /// Sets a function deciding whether to download the resolved release, e.g.: to ask the user
+    /// "Update to 2.0 available, 50 MB, proceed?" in an interactive application.
+    ///
+    /// The function is called by `force_update`, and thus by `update_if_needed` when an update is
+    /// needed, once the release has been resolved and before anything is downloaded. Returning
+    /// `false` is not an error: nothing is downloaded and `DownloadInfos::update_declined` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `pre_download_confirm` - The function returning whether to download the update.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_pre_download_confirm(|info| {
+    ///         println!("Update to {} available, {:?} bytes, proceed?", info.new_version, info.size);
+    ///         false
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_pre_download_confirm<F>(mut self, pre_download_confirm: F) -> Self
+    where
+        F: Fn(&PreDownloadInfo) -> bool + Send + Sync + 'static,
+    {
+        self.pre_download_confirm = Some(Hook::new(Arc::new(pre_download_confirm)));
+
+        self
+    }
+
+    /// Sets a minimum age for a release to be installed by `update_if_needed`, so that a release
+    /// pulled shortly after its publication, e.g.: because it is broken, never reaches the users.
+    ///
+    /// A release published more recently is skipped and `DownloadInfos::release_too_recent` is
+    /// set, until it gets old enough. A release whose publication date is unknown is not held back.
+    /// `force_update` ignores this setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum_release_age` - The time elapsed since the publication of a release before it can be installed.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_minimum_release_age(Duration::from_secs(2 * 24 * 60 * 60))
+    ///     .build();
+    /// ```
+    pub fn with_minimum_release_age(mut self, minimum_release_age: Duration) -> Self {
+        self.minimum_release_age = Some(minimum_release_age);
+
+        self
+    }
+
+    /// Checks again the latest release once resolved, falling back to the previous stable release
+    /// if it has been pulled in the meantime, i.e. deleted or turned into a draft or a
+    /// pre-release, e.g.: for an updater checking frequently, along with
+    /// `with_minimum_release_age`.
+    ///
+    /// This costs one more API request each time the latest release is fetched, and one more to
+    /// list the releases when it has been pulled. It has no effect with `with_rolling_tag`,
+    /// `with_release_id` or a local release source.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_skip_yanked_detection()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_skip_yanked_detection(mut self) -> Self {
+        self.skip_yanked_detection = true;
+
+        self
+    }
+
+    /// Downloads an asset split into several parts, e.g.: `afetch.part1`, `afetch.part2`, to stay
+    /// under the size limit of a single asset. The parts are concatenated in order into the
+    /// installed file.
+    ///
+    /// Each part is verified like a single asset, and against the SHA-256 published by GitHub for
+    /// it, if any. The whole file is then verified against its checksum, which must be published
+    /// in a `{base_name}.sha256`, `SHA256SUMS` or `checksums.txt` asset of the release, or at the
+    /// URL set with `with_checksum_url_template`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_pattern` - The release filename pattern of the whole file, which replaces the one
+    ///   set with `with_release_file_name_pattern`, e.g.: `{app_name}-{app_version}-linux`.
+    /// * `parts` - The number of parts, or `None` to download the parts numbered from 1 until the
+    ///   first one missing from the release.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_multipart_asset("{app_name}-{rust_target}", None)
+    ///     .with_rust_target("x86_64-unknown-linux-gnu")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_multipart_asset<S: Into<String>>(
+        mut self,
+        base_pattern: S,
+        parts: Option<u32>,
+    ) -> Self {
+        self.pattern = Some(base_pattern.into());
+        self.multipart_asset = true;
+        self.multipart_parts = parts;
+
+        self
+    }
+
+    /// Sets a function modifying the release once parsed, e.g.: to filter or reorder its assets,
+    /// add an asset or correct its name, for a release whose layout the other options do not
+    /// support.
+    ///
+    /// The function runs in `fetch_last_release`, before the version is extracted and the asset
+    /// is matched against the release filename pattern, so the verifications of the download
+    /// apply to the modified release. An added asset without `api_url` is downloaded from its
+    /// `download_url`.
+    ///
+    /// # Arguments
+    ///
+    /// * `release_transform` - The function modifying the release.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_release_transform(|release| {
+    ///         release.name = release.name.trim_start_matches("AFetch ").to_owned();
+    ///         release.assets.retain(|asset| !asset.name.ends_with("-debug"));
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_release_transform<F>(mut self, release_transform: F) -> Self
+    where
+        F: Fn(&mut ReleaseInfo) + Send + Sync + 'static,
+    {
+        self.release_transform = Some(Hook::new(Arc::new(release_transform)));
+
+        self
+    }
+
+    /// Persists the release resolved by `force_update` to a file, so that the next `force_update`
+    /// of another process, e.g.: the application restarted after a crash, downloads the same asset
+    /// without fetching the release from the API. The asset is then verified against the SHA-256
+    /// of the previous download.
+    ///
+    /// The cached release is used for the same repository, requested release and pattern, until
+    /// `ttl` has elapsed since it was resolved. It is removed when a fetch of the release, e.g.:
+    /// by `check_for_update`, finds another tag or version. The cache is not used with
+    /// multi-part assets, nor with the Sigstore and provenance verifications, which need the
+    /// other assets of the release.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the cache file, e.g.: `/var/cache/afetch/release.json`.
+    /// * `ttl` - How long a resolved release is reused.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GithubUpdater` builder instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::time::Duration;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_app_name("afetch")
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_release_file_name_pattern("{app_name}")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .with_resolution_cache(
+    ///         std::env::temp_dir().join("afetch-resolved-release.json"),
+    ///         Duration::from_secs(60),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_resolution_cache<P: AsRef<Path>>(mut self, path: P, ttl: Duration) -> Self {
+        self.resolution_cache = Some((path.as_ref().to_path_buf(), ttl));
+
+        self
+    }
+
+    /// Checks the options and builds the updater, creating the Reqwest client if requested with
+    /// `with_initialized_reqwest_client`.
+    ///
+    /// A built updater can be changed with the `with_*` methods and built again, e.g.: to update
+    /// several applications from one configuration. The release fetched for the previous
+    /// configuration is forgotten, so the next operation fetches the release of the new one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if a required element is missing (`BuilderMissingElement` error) or if an
+    /// element is invalid (`BuilderInvalidElement` error).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the built `GithubUpdater`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let template = GithubUpdater::builder()
+    ///     .with_initialized_reqwest_client()
+    ///     .with_release_file_name_pattern("{app_name}-{rust_target}")
+    ///     .with_rust_target("x86_64-unknown-linux-musl")
+    ///     .with_download_path(&"/opt/bin");
+    ///
+    /// for (repository, app_name) in [("Asthowen/AFetch", "afetch"), ("Asthowen/Other", "other")] {
+    ///     let updater_builder = template
+    ///         .clone()
+    ///         .with_repository(repository)
+    ///         .with_app_name(app_name)
+    ///         .build()
+    ///         .unwrap();
+    ///     assert_eq!(updater_builder.app_name(), Some(app_name));
+    /// }
+    ///
+    /// // A built updater can also be retargeted
+    /// let updater_builder = template
+    ///     .with_repository("Asthowen/AFetch")
+    ///     .with_app_name("afetch")
+    ///     .build()
+    ///     .unwrap()
+    ///     .with_repository("Asthowen/Other")
+    ///     .with_app_name("other")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(updater_builder.repository(), Some(("Asthowen", "Other")));
+    /// ```
+    pub fn build(mut self) -> Result<Self, BuilderError> {
+        if let Some(invalid_element) = self.invalid_element.take() {
+            return Err(invalid_element.into());
+        }
+        self.built = false;
+        self.clear_fetched_release();
+        if self.initialize_reqwest_client {
+            self.reqwest_client = Some(self.build_reqwest_client()?);
+        } else if !self.pinned_cert_fingerprints.is_empty() {
+            return Err(BuilderInvalidElement(
+                "pinned_cert_sha256 requires a client created with with_initialized_reqwest_client"
+                    .to_owned(),
+            )
+            .into());
+        } else if self.tls_sni_override.is_some() {
+            return Err(BuilderInvalidElement(
+                "tls_sni_override requires a client created with with_initialized_reqwest_client"
+                    .to_owned(),
+            )
+            .into());
+        }
+        if self.reqwest_client.is_none() && self.http_client.is_none() {
+            return Err(BuilderMissingElement("reqwest_client".to_owned()).into());
+        }
+        if self.app_name.is_none() {
+            return Err(BuilderMissingElement("app_name".to_owned()).into());
+        }
+        if let Some(pattern) = &self.pattern {
+            if pattern.contains("rust_target") && self.rust_target.is_none() {
+                return Err(BuilderMissingElement("rust_target".to_owned()).into());
+            }
+        } else if self.asset_match.is_none() {
+            return Err(BuilderMissingElement("pattern".to_owned()).into());
+        }
+        if self.repository_infos.is_none()
+            && self.release_repository_infos.is_none()
+            && self.release_source == ReleaseSource::GitHub
+        {
+            return Err(BuilderMissingElement("repository_infos".to_owned()).into());
+        }
+        if let Some(variable) = &self.download_path_env {
+            match std::env::var(variable) {
+                Ok(value) if !value.is_empty() => {
+                    self.download_path = Some(expand_path(&value).map_err(|error| {
+                        BuilderInvalidElement(format!(
+                            "{} environment variable: {}",
+                            variable, error
+                        ))
+                    })?);
+                }
+                _ if self.download_path.is_none() => {
+                    return Err(BuilderMissingElement(format!(
+                        "download_path (or the {} environment variable)",
+                        variable
+                    ))
+                    .into());
+                }
+                _ => {}
+            }
+        }
+        match &self.download_path {
+            Some(download_path) if self.follow_symlinks => {
+                if let Ok(canonical_path) = download_path.canonicalize() {
+                    self.download_path = Some(canonical_path);
+                }
+            }
+            Some(_) => {}
+            None => return Err(BuilderMissingElement("download_path".to_owned()).into()),
+        }
+        match (&mut self.smoke_test, self.smoke_test_expected_output.take()) {
+            (Some(smoke_test), expected_output @ Some(_)) => {
+                smoke_test.expected_output = expected_output
+            }
+            (None, Some(_)) => {
+                return Err(BuilderInvalidElement(
+                    "smoke_test_expected_output requires with_smoke_test".to_owned(),
+                )
+                .into())
+            }
+            _ => {}
+        }
+        if self.release_id.is_some() && self.rolling_tag.is_some() {
+            return Err(
+                BuilderInvalidElement("release_id conflicts with rolling_tag".to_owned()).into(),
+            );
+        }
+        if self.content_based_update_check && !self.json_version_record {
+            return Err(BuilderInvalidElement(
+                if self.rolling_tag.is_some() {
+                    "rolling_tag requires with_json_version_record"
+                } else {
+                    "content_based_update_check requires with_json_version_record"
+                }
+                .to_owned(),
+            )
+            .into());
+        }
+        if self.require_verification && !self.verification_configured() {
+            return Err(BuilderInvalidElement(
+                "require_verification needs a verification mechanism, e.g.: with_checksum_url_template"
+                    .to_owned(),
+            )
+            .into());
+        }
+
+        self.built = true;
+
+        Ok(self)
+    }
+
+    /// Returns whether a verification mechanism is configured, see `with_require_verification`.
+    fn verification_configured(&self) -> bool {
+        self.signature_verification_configured()
+            || self.checksum_url_template.is_some()
+            || self.release_source == ReleaseSource::GitHub
+    }
+
+    /// Returns whether a signature or provenance verification is configured.
+    fn signature_verification_configured(&self) -> bool {
+        #[cfg(feature = "sigstore")]
+        if self.sigstore_config.is_some() {
+            return true;
+        }
+        #[cfg(feature = "provenance")]
+        if self.provenance_config.is_some() {
+            return true;
+        }
+        #[cfg(all(windows, feature = "authenticode"))]
+        if self.authenticode_verification {
+            return true;
+        }
+
+        false
+    }
+
+    /// Returns the application name set with `with_app_name`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder().with_app_name("afetch");
+    /// assert_eq!(updater_builder.app_name(), Some("afetch"));
+    /// ```
+    pub fn app_name(&self) -> Option<&str> {
+        self.app_name.as_deref()
+    }
+
+    /// Returns the owner and the name of the GitHub repository set with `with_repository_infos` or
+    /// `with_repository`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder().with_repository("Asthowen/AFetch");
+    /// assert_eq!(updater_builder.repository(), Some(("Asthowen", "AFetch")));
+    /// ```
+    pub fn repository(&self) -> Option<(&str, &str)> {
+        self.repository_infos
+            .as_ref()
+            .map(|(owner, name)| (owner.as_str(), name.as_str()))
+    }
+
+    /// Returns the release filename pattern set with `with_release_file_name_pattern`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder().with_release_file_name_pattern("{app_name}-{rust_target}");
+    /// assert_eq!(updater_builder.pattern(), Some("{app_name}-{rust_target}"));
+    /// ```
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Returns the Rust target set with `with_rust_target`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder().with_rust_target("i686-unknown-freebsd");
+    /// assert_eq!(updater_builder.rust_target(), Some("i686-unknown-freebsd"));
+    /// ```
+    pub fn rust_target(&self) -> Option<&str> {
+        self.rust_target.as_deref()
+    }
+
+    /// Returns the download folder path set with `with_download_path`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::path::Path;
+    ///
+    /// let updater_builder = GithubUpdater::builder().with_download_path(&Path::new("~/Downloads/"));
+    /// assert_eq!(updater_builder.download_path(), Some(Path::new("~/Downloads/")));
+    /// ```
+    pub fn download_path(&self) -> Option<&Path> {
+        self.download_path.as_deref()
+    }
+
+    /// Returns the extension of the downloaded file set with `with_file_extension`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder().with_file_extension("so");
+    /// assert_eq!(updater_builder.file_extension(), Some("so"));
+    /// ```
+    pub fn file_extension(&self) -> Option<&str> {
+        self.file_extension.as_deref()
+    }
+
+    /// Returns the path of the file produced by `force_update`, computed from the download path,
+    /// the application name and the file extension, without downloading anything.
+    ///
+    /// Returns `None` if the application name or the download path is not set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_app_name("afetch")
+    ///     .with_download_path(&Path::new("/opt/afetch"))
+    ///     .with_file_extension("exe");
+    /// assert_eq!(
+    ///     updater_builder.target_file_path(),
+    ///     Some(PathBuf::from("/opt/afetch/afetch.exe"))
+    /// );
+    /// ```
+    pub fn target_file_path(&self) -> Option<PathBuf> {
+        let app_name: &String = self.app_name.as_ref()?;
+        let path: &PathBuf = self.download_path.as_ref()?;
+
+        Some(path.join(self.generate_file_name(app_name)))
+    }
+
+    /// Returns the path `force_update` would download the new file to, e.g.: for a restart helper
+    /// moving the staged file into place once the application has exited, with
+    /// `without_erase_previous_file`.
+    ///
+    /// This is the file of `target_file_path` with the prefix `new_`, next to the file it points
+    /// to if it is a symbolic link which is followed, or `target_file_path` itself if no file
+    /// exists there yet. Without `without_erase_previous_file`, the new file is then renamed over
+    /// the previous one by `force_update`.
+    ///
+    /// Returns `None` if the application name or the download path is not set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    /// use std::path::PathBuf;
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_app_name("afetch")
+    ///     .with_download_path(&std::env::temp_dir())
+    ///     .without_erase_previous_file();
+    /// let pending_file: Option<PathBuf> = updater_builder.pending_file_path();
+    /// ```
+    pub fn pending_file_path(&self) -> Option<PathBuf> {
+        let mut previous_file: PathBuf = self.target_file_path()?;
+        if self.follow_symlinks && previous_file.is_symlink() && previous_file.exists() {
+            previous_file = std::fs::canonicalize(&previous_file).ok()?;
+        }
+
+        Some(staged_file_path(&previous_file))
+    }
+
+    /// Returns whether the `build` method has successfully been called on this instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::GithubUpdater;
+    ///
+    /// let updater_builder = GithubUpdater::builder();
+    /// assert!(!updater_builder.is_built());
+    /// ```
+    pub fn is_built(&self) -> bool {
+        self.built
+    }
+
+    fn generate_file_name(&self, app_name: &str) -> String {
+        if self.use_asset_filename {
+            if let Some(release_asset_name) = &self.release_asset_name {
+                return release_asset_name.clone();
+            }
+        }
+        let extension: String = self
+            .file_extension
+            .as_ref()
+            .map_or_else(String::default, |ext| format!(".{}", ext));
         format!("{}{}", app_name, extension)
     }
 
-    async fn get_current_version(
+    /// Applies the normalizer set with `with_version_normalizer`, if any, to `version`.
+    fn normalize_version(&self, version: &str) -> String {
+        match &self.version_normalizer {
+            Some(version_normalizer) => version_normalizer(version),
+            None => version.to_owned(),
+        }
+    }
+
+    /// Returns the version of a release, extracted with the regular expression set with
+    /// `with_version_extract_regex`, if any, otherwise its name.
+    fn extract_version(&self, release_name: &str, tag_name: &str) -> Result<String, UpdateError> {
+        let Some(version_extract_regex) = &self.version_extract_regex else {
+            return Ok(release_name.to_owned());
+        };
+
+        [release_name, tag_name]
+            .iter()
+            .find_map(|value| Some(version_extract_regex.captures(value)?.get(1)?.as_str()))
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                UpdateError::new(format!(
+                    "No version could be extracted from the release \"{}\" (tag \"{}\").",
+                    release_name, tag_name
+                ))
+            })
+    }
+
+    fn version_file_path(&self, app_name: &str, path: &Path) -> PathBuf {
+        path.join(format!("binary-version-{}.txt", app_name))
+    }
+
+    async fn get_current_version(
+        &self,
+        app_name: &str,
+        path: &Path,
+    ) -> Result<Option<String>, UpdateError> {
+        Ok(self
+            .read_version_record(app_name, path)
+            .await?
+            .map(|record| self.normalize_version(&record.version)))
+    }
+
+    async fn read_version_record(
+        &self,
+        app_name: &str,
+        path: &Path,
+    ) -> Result<Option<VersionRecord>, UpdateError> {
+        if let Some(version_store) = &self.version_store {
+            return Ok(version_store
+                .load(app_name)
+                .await?
+                .map(|content| VersionRecord::parse(&content)));
+        }
+
+        let path_version_file: PathBuf = self.version_file_path(app_name, path);
+        if path_version_file.exists() {
+            let content: String = tokio::fs::read_to_string(&path_version_file).await?;
+            Ok(Some(VersionRecord::parse(&content)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn last_check_file_path(&self, app_name: &str, path: &Path) -> PathBuf {
+        path.join(format!("last-check-{}.txt", app_name))
+    }
+
+    /// Returns the latest version found by the previous check, if it is more recent than the
+    /// interval set with `with_min_check_interval` and not forced. A missing or unreadable record
+    /// is treated as no previous check.
+    async fn recently_checked_version(&self) -> Option<String> {
+        let min_check_interval: Duration = self.min_check_interval.filter(|_| !self.force_check)?;
+        let app_name: &String = self.app_name.as_ref()?;
+        let path: &PathBuf = self.download_path.as_ref()?;
+
+        let content: String = tokio::fs::read_to_string(self.last_check_file_path(app_name, path))
+            .await
+            .ok()?;
+        let (checked_at, latest_version) = content.split_once('\n')?;
+        let checked_at: SystemTime =
+            SystemTime::UNIX_EPOCH + Duration::from_secs(checked_at.trim().parse().ok()?);
+        let elapsed: Duration = SystemTime::now().duration_since(checked_at).ok()?;
+
+        (elapsed < min_check_interval).then(|| latest_version.trim().to_owned())
+    }
+
+    /// Records the time of a check which found the installed version up to date, if
+    /// `with_min_check_interval` is set and the download path exists.
+    async fn record_check(&self) -> Result<(), UpdateError> {
+        let (Some(app_name), Some(path), Some(latest_version)) =
+            (&self.app_name, &self.download_path, &self.app_version)
+        else {
+            return Ok(());
+        };
+        if self.min_check_interval.is_none() || !path.is_dir() {
+            return Ok(());
+        }
+
+        let checked_at: u64 = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let last_check_file: PathBuf = self.last_check_file_path(app_name, path);
+        tokio::fs::write(
+            &last_check_file,
+            format!("{}\n{}\n", checked_at, latest_version),
+        )
+        .await?;
+        self.apply_file_owner(&last_check_file);
+
+        Ok(())
+    }
+
+    /// Records the version of the installed file, in the version store if any, otherwise in the
+    /// version file of `path`. Failures are only logged with `with_ignore_version_file_errors`.
+    async fn write_version_record(
+        &self,
+        app_name: &str,
+        path: &Path,
+        content: &str,
+    ) -> Result<(), UpdateError> {
+        let result: Result<(), UpdateError> = match &self.version_store {
+            Some(version_store) => version_store.store(app_name, content).await,
+            None => self.write_version_file(app_name, path, content).await,
+        };
+        match result {
+            Err(error) if self.ignore_version_file_errors => {
+                log::warn!(
+                    "Unable to record the version of {}, the installed version will be unknown: {}",
+                    app_name,
+                    error
+                );
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
+    /// Writes `content` to the version file of `path`.
+    async fn write_version_file(
+        &self,
+        app_name: &str,
+        path: &Path,
+        content: &str,
+    ) -> Result<(), UpdateError> {
+        let version_file_path: PathBuf = self.version_file_path(app_name, path);
+        let mut file: File = File::create(&version_file_path).await?;
+        file.write_all(content.as_bytes()).await?;
+        // The record must be complete once the update returns, it is read back to compare the
+        // next download
+        file.sync_all().await?;
+        self.apply_file_owner(&version_file_path);
+
+        Ok(())
+    }
+
+    /// Returns the content of the version file to write for the fetched release.
+    fn version_file_content(&self, version: &str, sha256: &str) -> String {
+        VersionRecord {
+            version: version.to_owned(),
+            tag: self.release_tag.clone(),
+            downloaded_at: Some(SystemTime::now()),
+            sha256: Some(sha256.to_owned()),
+            asset_url: self.release_url.clone(),
+            file_name: self
+                .app_name
+                .as_deref()
+                .map(|app_name| self.generate_file_name(app_name)),
+        }
+        .to_file_content(self.json_version_record)
+    }
+
+    /// Reads the version file of the installed file, whether it contains a JSON record or only
+    /// the version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while reading the version file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the version record (`VersionRecord`), or `None` if the file has not
+    /// been installed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if let Some(record) = updater_builder.installed_version_record().await? {
+    ///     println!("{} downloaded from {:?}", record.version, record.asset_url);
+    /// }
+    /// ```
+    pub async fn installed_version_record(&self) -> Result<Option<VersionRecord>, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+
+        self.read_version_record(app_name, path).await
+    }
+
+    /// Checks whether a file, e.g.: a binary copied by deployment tooling, is the asset of the
+    /// latest release matched by the updater, without downloading it.
+    ///
+    /// The file is compared with the SHA-256 published for the asset, by the GitHub API or in a
+    /// checksum file (see `with_content_based_update_check`), otherwise with the digest announced
+    /// by the server in the headers of a `HEAD` request for the asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path of the file to verify.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release, if no checksum is published for the asset, or if an error occurs while reading the file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the file matches the checksum of the asset.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let matches: bool = updater_builder
+    ///     .verify_file_against_release(Path::new("/usr/local/bin/afetch"))
+    ///     .await?;
+    /// ```
+    pub async fn verify_file_against_release(&mut self, file: &Path) -> Result<bool, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        self.ensure_release_fetched().await?;
+        if let Some(published_sha256) = self.fetch_published_sha256().await? {
+            return Ok(self
+                .file_sha256(file)
+                .await?
+                .eq_ignore_ascii_case(&published_sha256));
+        }
+
+        let release_url: &String = self.release_url.as_ref().ok_or(UpdateError::new(
+            "An error occurred while retrieving the release URL.".to_owned(),
+        ))?;
+        let release_url: &str = &self.rewrite_asset_url(release_url);
+        self.check_download_host(release_url)?;
+        let expected_digest: Option<ExpectedDigest> = match Url::parse(release_url) {
+            // The digest announced for a part is not the one of the whole file
+            _ if !self.release_parts.is_empty() => None,
+            Ok(url) if url.scheme() == "file" => None,
+            _ => {
+                let response: Response = self
+                    .send_request_with_body(
+                        Method::HEAD,
+                        release_url,
+                        "application/octet-stream",
+                        None,
+                        self.metadata_timeout,
+                        HeaderMap::new(),
+                    )
+                    .await?
+                    .error_for_status()?;
+                ExpectedDigest::from_headers(response.headers())
+                    .filter(ExpectedDigest::is_well_formed)
+            }
+        };
+        let expected_digest: ExpectedDigest = expected_digest.ok_or_else(|| {
+            UpdateError::new("No checksum is published for the asset of the release.".to_owned())
+        })?;
+
+        Ok(self
+            .hash_file(file, expected_digest.algorithm)
+            .await?
+            .finalize_base64()
+            == expected_digest.value)
+    }
+
+    /// Sends a GET request to `url` with the headers shared by every call of the crate.
+    ///
+    /// Requests to the GitHub API also carry the `X-GitHub-Api-Version` header.
+    ///
+    /// The GitHub token is only attached when `url` points to the GitHub API host. Assets are
+    /// served from another host (GitHub redirects asset downloads to its storage), which must not
+    /// receive the token: reqwest drops the `Authorization` header itself when a redirect leaves
+    /// the original host, and the check below prevents it from being sent to a foreign host in
+    /// the first place.
+    ///
+    /// Failed attempts are retried according to `with_retry` and `with_retry_predicate`.
+    async fn send_request(&self, url: &str, accept: &str) -> Result<Response, UpdateError> {
+        self.send_request_with_timeout(url, accept, None).await
+    }
+
+    /// Sends a GET request like `send_request`, which fails if it has not completed within
+    /// `timeout`, if set.
+    async fn send_request_with_timeout(
+        &self,
+        url: &str,
+        accept: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Response, UpdateError> {
+        self.send_request_with_body(Method::GET, url, accept, None, timeout, HeaderMap::new())
+            .await
+    }
+
+    /// Sends a request like `send_request_with_timeout`, with any method, an optional JSON body
+    /// and additional `headers`.
+    ///
+    /// Redirects are followed here when `with_manual_redirect_handling` is enabled, or when the
+    /// downloads are restricted with `with_allowed_download_hosts`.
+    async fn send_request_with_body(
+        &self,
+        method: Method,
+        url: &str,
+        accept: &str,
+        body: Option<&serde_json::Value>,
+        timeout: Option<Duration>,
+        headers: HeaderMap,
+    ) -> Result<Response, UpdateError> {
+        let mut response: Response = self
+            .send_request_with_retry(method.clone(), url, accept, body, timeout, &headers)
+            .await?;
+        if !self.follows_redirects_itself() {
+            return Ok(response);
+        }
+
+        let (mut method, mut body) = (method, body);
+        for _ in 0..MAX_REDIRECTS {
+            let location: &HeaderValue = match response.headers().get(reqwest::header::LOCATION) {
+                Some(location) if response.status().is_redirection() => location,
+                _ => return Ok(response),
+            };
+            let location: Url = response.url().join(location.to_str()?).map_err(|error| {
+                UpdateError::new(format!("Invalid redirect location: {}", error))
+            })?;
+            // The downloads must not reach a host that is not allowed, even through a redirect
+            if accept != "application/vnd.github+json" {
+                self.check_download_host(location.as_str())?;
+            }
+            // Only 307 and 308 redirects preserve the method and the body of the request
+            if !matches!(response.status().as_u16(), 307 | 308) {
+                method = Method::GET;
+                body = None;
+            }
+
+            response = self
+                .send_request_with_retry(
+                    method.clone(),
+                    location.as_str(),
+                    accept,
+                    body,
+                    timeout,
+                    &headers,
+                )
+                .await?;
+        }
+
+        if response.status().is_redirection() {
+            return Err(UpdateError::new(format!(
+                "Too many redirects, the last one being to {}.",
+                response.url()
+            )));
+        }
+
+        Ok(response)
+    }
+
+    /// Sends a single request, retried according to `with_retry` and `with_retry_predicate`.
+    async fn send_request_with_retry(
+        &self,
+        method: Method,
+        url: &str,
+        accept: &str,
+        body: Option<&serde_json::Value>,
+        timeout: Option<Duration>,
+        headers: &HeaderMap,
+    ) -> Result<Response, UpdateError> {
+        let parsed_url: Url = Url::parse(url)
+            .map_err(|error| UpdateError::new(format!("Invalid URL \"{}\": {}", url, error)))?;
+
+        let mut attempt: u32 = 1;
+        loop {
+            let mut request = reqwest::Request::new(method.clone(), parsed_url.clone());
+            let request_headers: &mut HeaderMap = request.headers_mut();
+            request_headers.insert(USER_AGENT, HeaderValue::from_static("GitHub-Updater"));
+            request_headers.insert(ACCEPT, HeaderValue::from_str(accept)?);
+            for (name, value) in headers {
+                request_headers.insert(name, value.clone());
+            }
+            // Only the JSON responses may be compressed, the size of the assets must match their
+            // `Content-Length`, whatever the defaults of the HTTP client
+            if accept != "application/vnd.github+json" {
+                request_headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("identity"));
+            } else if self.metadata_compression {
+                request_headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+            }
+            if parsed_url.host_str() == Some(GITHUB_API_HOST) {
+                request_headers.insert(
+                    "X-GitHub-Api-Version",
+                    HeaderValue::from_str(&self.api_version)?,
+                );
+                if let Some(token) = &self.github_token {
+                    request_headers.insert(
+                        AUTHORIZATION,
+                        HeaderValue::from_str(&format!("token {}", token))?,
+                    );
+                }
+            }
+            if let Some(body) = body {
+                request_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                *request.body_mut() = Some(
+                    serde_json::to_vec(body)
+                        .map_err(|error| {
+                            UpdateError::new(format!(
+                                "Unable to serialize the request body: {}",
+                                error
+                            ))
+                        })?
+                        .into(),
+                );
+            }
+            *request.timeout_mut() = timeout;
+
+            let sent: Result<Response, (RetryErrorKind, UpdateError)> = match &self.http_client {
+                Some(http_client) => http_client
+                    .send(HttpRequest::from(request))
+                    .await
+                    .map(Response::from)
+                    .map_err(|error| (RetryErrorKind::Other, error)),
+                None => {
+                    let client: &Client =
+                        self.reqwest_client.as_ref().ok_or(BuilderNotInitialized)?;
+                    client
+                        .execute(request)
+                        .await
+                        .map_err(|error| (RetryErrorKind::from(&error), error.into()))
+                }
+            };
+            let (result, secondary_rate_limit): (
+                Result<Response, (RetryErrorKind, UpdateError)>,
+                Option<SecondaryRateLimited>,
+            ) = match sent {
+                Ok(response) => {
+                    let api_response: bool = response.url().host_str() == Some(GITHUB_API_HOST);
+                    let (response, secondary_rate_limit) =
+                        detect_secondary_rate_limit(response, api_response).await?;
+                    (Ok(response), secondary_rate_limit)
+                }
+                Err(error) => (Err(error), None),
+            };
+            let give_up =
+                |result: Result<Response, (RetryErrorKind, UpdateError)>,
+                 secondary_rate_limit: Option<SecondaryRateLimited>| {
+                    match secondary_rate_limit {
+                        Some(secondary_rate_limit) => Err(secondary_rate_limit.into()),
+                        None => result.map_err(|(_, error)| error),
+                    }
+                };
+            if attempt > self.max_retries {
+                return give_up(result, secondary_rate_limit);
+            }
+            let retry_context = RetryContext {
+                attempt,
+                status: result
+                    .as_ref()
+                    .ok()
+                    .map(|response| response.status().as_u16()),
+                error_kind: result.as_ref().err().map(|(error_kind, _)| *error_kind),
+                secondary_rate_limited: secondary_rate_limit.is_some(),
+            };
+            let should_retry: bool = match &self.retry_predicate {
+                Some(retry_predicate) => retry_predicate(&retry_context),
+                None => retry_context.is_retryable_by_default(),
+            };
+            if !should_retry {
+                return give_up(result, secondary_rate_limit);
+            }
+
+            let mut delay: Duration = self
+                .retry_backoff
+                .saturating_mul(1 << (attempt - 1).min(16));
+            if let Some(secondary_rate_limit) = secondary_rate_limit {
+                delay = delay.max(
+                    secondary_rate_limit
+                        .retry_after
+                        .unwrap_or(SECONDARY_RATE_LIMIT_DELAY),
+                );
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Returns the API URL of the releases of the repository from which they are fetched.
+    fn releases_api_url(&self) -> Result<String, UpdateError> {
+        let repository_infos: &(String, String) = self
+            .release_repository_infos
+            .as_ref()
+            .or(self.repository_infos.as_ref())
+            .ok_or(BuilderNotInitialized)?;
+
+        Ok(format!(
+            "https://api.github.com/repos/{}/{}/releases",
+            repository_infos.0, repository_infos.1
+        ))
+    }
+
+    /// Fetches a page of releases, returning them along with whether a next page exists.
+    async fn fetch_releases_page(&self, page: u32) -> Result<(Vec<Release>, bool), UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        if let ReleaseSource::LocalJson(path) = &self.release_source {
+            return Ok((vec![read_local_release(path).await?], false));
+        }
+        let url: String = format!(
+            "{}?per_page={}&page={}",
+            self.releases_api_url()?,
+            RELEASES_PER_PAGE,
+            page
+        );
+        let response: Response = self
+            .send_request(&url, "application/vnd.github+json")
+            .await?
+            .error_for_status()?;
+        let has_next_page: bool = response
+            .headers()
+            .get(reqwest::header::LINK)
+            .and_then(|link| link.to_str().ok())
+            .is_some_and(|link| link.contains("rel=\"next\""));
+
+        Ok((response.json::<Vec<Release>>().await?, has_next_page))
+    }
+
+    /// Retrieves the quota of GitHub API requests left, e.g.: to decide whether a batch of checks
+    /// can be made now or must wait for the reset. This request does not count against the quota.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while making the API request, or if an error occurs while parsing the response JSON.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the rate limit status (`RateLimit`) of the REST and search APIs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let rate_limit = updater_builder.rate_limit_status().await?;
+    /// if rate_limit.core.remaining < 10 {
+    ///     println!("Waiting until {:?}", rate_limit.core.reset_at);
+    /// }
+    /// ```
+    pub async fn rate_limit_status(&self) -> Result<RateLimit, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let response: RateLimitResponse = self
+            .send_request(
+                &format!("https://{}/rate_limit", GITHUB_API_HOST),
+                "application/vnd.github+json",
+            )
+            .await?
+            .error_for_status()?
+            .json::<RateLimitResponse>()
+            .await?;
+
+        Ok(response.into())
+    }
+
+    /// Checks that the repository of the releases exists and is accessible, e.g.: to confirm at
+    /// startup that an embedded token has access to it, and that its visibility is the expected
+    /// one. A warning is logged if the repository is archived, since it will not receive updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if the repository does not exist or is not accessible, if an error occurs while making the API request, or if an error occurs while parsing the response JSON.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the information of the repository (`RepoInfo`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let repo_info = updater_builder.verify_repository().await?;
+    /// if repo_info.private {
+    ///     panic!("The releases must come from the public repository.");
+    /// }
+    /// ```
+    pub async fn verify_repository(&self) -> Result<RepoInfo, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let (owner, repository): &(String, String) = self
+            .release_repository_infos
+            .as_ref()
+            .or(self.repository_infos.as_ref())
+            .ok_or(BuilderNotInitialized)?;
+        let response: Response = self
+            .send_request(
+                &format!("https://{}/repos/{}/{}", GITHUB_API_HOST, owner, repository),
+                "application/vnd.github+json",
+            )
+            .await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(UpdateError::new(format!(
+                "The repository {}/{} does not exist or is not accessible with the token.",
+                owner, repository
+            )));
+        }
+        let repo_info: RepoInfo = response.error_for_status()?.json::<RepoInfo>().await?;
+        if repo_info.archived {
+            log::warn!(
+                "The repository {}/{} is archived, it will not receive updates.",
+                owner,
+                repository
+            );
+        }
+
+        Ok(repo_info)
+    }
+
+    /// Generates with GitHub the release notes covering the changes between two tags, e.g.: to show
+    /// a single changelog when several versions are skipped, instead of concatenating the
+    /// description of each release.
+    ///
+    /// This endpoint requires a GitHub token with write access to the contents of the repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous_tag` - The tag of the installed release, e.g.: `v1.0.0`.
+    /// * `new_tag` - The tag of the new release, e.g.: `v1.2.0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while making the API request, or if an error occurs while parsing the response JSON.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the generated notes, in Markdown.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let notes = updater_builder
+    ///     .generated_notes_between("v1.0.0", "v1.2.0")
+    ///     .await?;
+    /// ```
+    pub async fn generated_notes_between(
+        &self,
+        previous_tag: &str,
+        new_tag: &str,
+    ) -> Result<String, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let url: String = format!("{}/generate-notes", self.releases_api_url()?);
+        let body: serde_json::Value = serde_json::json!({
+            "tag_name": new_tag,
+            "previous_tag_name": previous_tag,
+        });
+        let generated_notes: GeneratedNotes = self
+            .send_request_with_body(
+                Method::POST,
+                &url,
+                "application/vnd.github+json",
+                Some(&body),
+                None,
+                HeaderMap::new(),
+            )
+            .await?
+            .error_for_status()?
+            .json::<GeneratedNotes>()
+            .await?;
+
+        Ok(generated_notes.body)
+    }
+
+    /// Lists the releases of the repository, from the most recent one, fetching the pages of the
+    /// GitHub API lazily: a page is only requested once the releases of the previous one have been
+    /// consumed, so the caller can stop early without fetching the whole history.
+    ///
+    /// Draft releases are only listed if the GitHub token allows to see them. With
+    /// `ReleaseSource::LocalJson`, the release of the file is the only one yielded.
+    ///
+    /// # Errors
+    ///
+    /// Yields an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But yield (`UpdateError` error) if an error occurs while making an API request or while parsing the response JSON, after which the stream ends.
+    ///
+    /// # Returns
+    ///
+    /// A stream of the release summaries (`ReleaseSummary`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut releases = std::pin::pin!(updater_builder.releases_stream());
+    /// while let Some(release) = releases.try_next().await? {
+    ///     if release.name == "1.0.0" {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    pub fn releases_stream(&self) -> impl Stream<Item = Result<ReleaseSummary, UpdateError>> + '_ {
+        stream::try_unfold(Some(1), move |page: Option<u32>| async move {
+            let Some(page) = page else {
+                return Ok::<_, UpdateError>(None);
+            };
+            let (releases, has_next_page) = self.fetch_releases_page(page).await?;
+            let summaries: Vec<Result<ReleaseSummary, UpdateError>> = releases
+                .iter()
+                .map(|release| Ok(release.to_summary()))
+                .collect();
+
+            Ok(Some((
+                stream::iter(summaries),
+                has_next_page.then_some(page + 1),
+            )))
+        })
+        .try_flatten()
+    }
+
+    /// Retrieve the latest version of the release from GitHub.
+    ///
+    /// The next fetches of the same release are conditional requests, sending the `ETag` or the
+    /// `Last-Modified` header of the previous response, whichever the server provided: a release
+    /// which has not been modified is not downloaded again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while making the API request, if an error occurs while parsing the response JSON, if an error occurs while retrieving the release URL, if no URL matching the pattern is found, or if the selected asset does not belong to the fetched release, e.g.: its API URL is the one of another repository.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the fetch is successful.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// updater_builder.fetch_last_release().await;
+    /// ```
+    pub async fn fetch_last_release(&mut self) -> Result<(), UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let started_at: Instant = Instant::now();
+        let mut response: Release = self.fetch_release().await?;
+        if let Some(release_transform) = &self.release_transform {
+            let mut release_info: ReleaseInfo = response.to_info();
+            release_transform(&mut release_info);
+            response = Release::from(release_info);
+        }
+        let release_version: String = self.extract_version(&response.name, &response.tag_name)?;
+        let selected_asset: &Asset = self
+            .select_asset(&response, &release_version, self.rust_target.as_deref())?
+            .ok_or_else(|| {
+                UpdateError::new("No URL matching the pattern entered was found.".to_owned())
+            })?;
+        self.app_version = Some(self.normalize_version(&release_version));
+        self.release_notes = response.body.clone();
+        self.release_tag = Some(response.tag_name.clone()).filter(|tag_name| !tag_name.is_empty());
+        self.released_at = response.published_at.as_deref().and_then(parse_rfc3339);
+
+        self.check_asset_in_release(&response, selected_asset)?;
+        self.release_url = Some(self.asset_download_url(selected_asset)?);
+        self.asset_size = Some(selected_asset.size).filter(|size| *size > 0);
+        self.release_asset_name = Some(selected_asset.name.clone());
+        self.published_sha256 = selected_asset
+            .digest
+            .as_deref()
+            .and_then(|digest| digest.strip_prefix("sha256:"))
+            .map(str::to_ascii_lowercase);
+        self.release_parts = Vec::new();
+        if self.multipart_asset {
+            self.fetch_multipart_asset(&response, selected_asset)?;
+        }
+        let asset_name: &str = self
+            .release_asset_name
+            .as_deref()
+            .unwrap_or(&selected_asset.name);
+        let sha256_file_name: String = format!("{}.sha256", asset_name);
+        self.checksum_asset = [sha256_file_name.as_str(), "SHA256SUMS", "checksums.txt"]
+            .iter()
+            .find_map(|name| response.assets.iter().find(|asset| asset.name == *name))
+            .map(|checksum_asset| {
+                Ok::<_, UpdateError>((
+                    self.asset_download_url(checksum_asset)?,
+                    asset_name.to_owned(),
+                ))
+            })
+            .transpose()?;
+        if let Some(checksum_url_template) = &self.checksum_url_template {
+            let checksum_url: String = checksum_url_template
+                .replace("{tag}", &response.tag_name)
+                .replace("{asset_name}", asset_name);
+            self.checksum_asset = Some((checksum_url, asset_name.to_owned()));
+        }
+        #[cfg(feature = "sigstore")]
+        {
+            self.sigstore_bundle_url = sigstore::BUNDLE_EXTENSIONS
+                .iter()
+                .find_map(|extension| {
+                    let bundle_name: String = format!("{}.{}", selected_asset.name, extension);
+                    response
+                        .assets
+                        .iter()
+                        .find(|asset| asset.name == bundle_name)
+                })
+                .map(|bundle| self.asset_download_url(bundle))
+                .transpose()?;
+        }
+        #[cfg(feature = "provenance")]
+        {
+            let attestation_name: String = format!(
+                "{}.{}",
+                selected_asset.name,
+                provenance::ATTESTATION_EXTENSION
+            );
+            self.provenance_attestation_url = response
+                .assets
+                .iter()
+                .find(|asset| asset.name == attestation_name)
+                .or_else(|| {
+                    response.assets.iter().find(|asset| {
+                        asset
+                            .name
+                            .ends_with(&format!(".{}", provenance::ATTESTATION_EXTENSION))
+                    })
+                })
+                .map(|attestation| self.asset_download_url(attestation))
+                .transpose()?;
+        }
+        self.metadata_fetch_duration = Some(started_at.elapsed());
+        self.resolution_cacheable = self.release_parts.is_empty();
+        self.invalidate_resolution_cache().await;
+
+        Ok(())
+    }
+
+    /// Returns the path of the cache file set with `with_resolution_cache` and its TTL, or `None`
+    /// if there is none or if the verifications need the other assets of the release.
+    fn resolution_cache(&self) -> Option<(&Path, Duration)> {
+        #[cfg(feature = "sigstore")]
+        if self.sigstore_config.is_some() {
+            return None;
+        }
+        #[cfg(feature = "provenance")]
+        if self.provenance_config.is_some() {
+            return None;
+        }
+
+        self.resolution_cache
+            .as_ref()
+            .map(|(path, ttl)| (path.as_path(), *ttl))
+    }
+
+    /// Returns the key identifying, in the resolution cache, the release to resolve: the source
+    /// of the releases, the requested release, the pattern and the Rust target.
+    fn resolution_cache_key(&self) -> String {
+        let release_source: String = match &self.release_source {
+            ReleaseSource::GitHub => self
+                .release_repository_infos
+                .as_ref()
+                .or(self.repository_infos.as_ref())
+                .map(|(owner, name)| format!("{}/{}", owner, name))
+                .unwrap_or_default(),
+            ReleaseSource::LocalJson(path) => path.display().to_string(),
+        };
+        let release: String = match (&self.rolling_tag, self.release_id) {
+            (Some(rolling_tag), _) => format!("tag {}", rolling_tag),
+            (None, Some(release_id)) => format!("id {}", release_id),
+            (None, None) => "latest".to_owned(),
+        };
+
+        format!(
+            "{} {} {} {}",
+            release_source,
+            release,
+            self.pattern.as_deref().unwrap_or_default(),
+            self.rust_target.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Restores the release resolved by a previous process from the resolution cache, if it is
+    /// still valid, so that it is not fetched.
+    async fn load_resolution_cache(&mut self) {
+        let Some((path, ttl)) = self.resolution_cache() else {
+            return;
+        };
+        let Some(resolved_release) = ResolvedRelease::load(path).await else {
+            return;
+        };
+        if !resolved_release.is_valid(&self.resolution_cache_key(), ttl) {
+            return;
+        }
+
+        self.clear_fetched_release();
+        self.release_url = Some(resolved_release.asset_url);
+        self.app_version = Some(resolved_release.version);
+        self.release_tag = resolved_release.tag;
+        self.release_asset_name = resolved_release.asset_name;
+        self.asset_size = resolved_release.size;
+        self.published_sha256 = Some(resolved_release.sha256);
+        self.resolved_from_cache = true;
+        self.need_refresh = false;
+    }
+
+    /// Removes the resolution cache if the fetched release is not the cached one, e.g.: a new
+    /// release has been published.
+    async fn invalidate_resolution_cache(&self) {
+        let Some((path, _)) = self.resolution_cache() else {
+            return;
+        };
+        let Some(resolved_release) = ResolvedRelease::load(path).await else {
+            return;
+        };
+        if resolved_release.key == self.resolution_cache_key()
+            && resolved_release.tag == self.release_tag
+            && Some(&resolved_release.version) == self.app_version.as_ref()
+        {
+            return;
+        }
+
+        if let Err(error) = tokio::fs::remove_file(path).await {
+            log::warn!(
+                "Unable to remove the resolution cache {}: {}",
+                path.display(),
+                error
+            );
+        }
+    }
+
+    /// Writes the fetched release to the resolution cache, along with the SHA-256 of its
+    /// downloaded asset.
+    async fn store_resolution_cache(&self, sha256: &str) {
+        let Some((path, _)) = self.resolution_cache() else {
+            return;
+        };
+        let (true, Some(asset_url), Some(version)) = (
+            self.resolution_cacheable,
+            &self.release_url,
+            &self.app_version,
+        ) else {
+            return;
+        };
+
+        let resolved_release = ResolvedRelease {
+            key: self.resolution_cache_key(),
+            tag: self.release_tag.clone(),
+            version: version.clone(),
+            asset_name: self.release_asset_name.clone(),
+            asset_url: asset_url.clone(),
+            size: self.asset_size,
+            sha256: sha256.to_owned(),
+            resolved_at: SystemTime::now(),
+        };
+        if let Err(error) = resolved_release.store(path).await {
+            log::warn!(
+                "Unable to write the resolution cache {}: {}",
+                path.display(),
+                error
+            );
+        }
+    }
+
+    /// Resolves the parts of the multi-part asset `selected_part` is one of, among the assets of
+    /// the fetched `release`, see `with_multipart_asset`. The asset to download becomes the whole
+    /// file, whose SHA-256 is the one published in a checksum file.
+    fn fetch_multipart_asset(
+        &mut self,
+        release: &Release,
+        selected_part: &Asset,
+    ) -> Result<(), UpdateError> {
+        let (base_name, _): (&str, u32) =
+            multipart_base_name(&selected_part.name).ok_or_else(|| {
+                UpdateError::new(format!(
+                    "The asset {} is not a part of a multi-part asset.",
+                    selected_part.name
+                ))
+            })?;
+
+        let mut parts: Vec<AssetPart> = Vec::new();
+        for number in 1.. {
+            if self.multipart_parts.is_some_and(|count| number > count) {
+                break;
+            }
+            let part_name: String = format!("{}.part{}", base_name, number);
+            let Some(part) = release.assets.iter().find(|asset| asset.name == part_name) else {
+                if self.multipart_parts.is_some() {
+                    return Err(UpdateError::new(format!(
+                        "The part {} of the asset {} is missing from the release.",
+                        number, base_name
+                    )));
+                }
+                break;
+            };
+            self.check_asset_in_release(release, part)?;
+            parts.push(AssetPart {
+                name: part.name.clone(),
+                url: self.asset_download_url(part)?,
+                size: part.size,
+                sha256: part
+                    .digest
+                    .as_deref()
+                    .and_then(|digest| digest.strip_prefix("sha256:"))
+                    .map(str::to_ascii_lowercase),
+            });
+        }
+        if parts.is_empty() {
+            return Err(UpdateError::new(format!(
+                "The first part of the asset {} is missing from the release.",
+                base_name
+            )));
+        }
+
+        self.asset_size = Some(parts.iter().map(|part| part.size).sum::<u64>())
+            .filter(|_| parts.iter().all(|part| part.size > 0));
+        self.release_asset_name = Some(base_name.to_owned());
+        self.published_sha256 = None;
+        self.release_parts = parts;
+
+        Ok(())
+    }
+
+    /// Fetches the release to update from, i.e. the latest one or the one of the rolling tag, from
+    /// the configured release source.
+    async fn fetch_release(&mut self) -> Result<Release, UpdateError> {
+        match &self.release_source {
+            ReleaseSource::GitHub => {
+                let (url, not_found): (String, String) = match (&self.rolling_tag, self.release_id)
+                {
+                    (Some(rolling_tag), _) => (
+                        format!("{}/tags/{}", self.releases_api_url()?, rolling_tag),
+                        format!("No release has the tag {}.", rolling_tag),
+                    ),
+                    (None, Some(release_id)) => (
+                        format!("{}/{}", self.releases_api_url()?, release_id),
+                        format!("No release has the ID {}.", release_id),
+                    ),
+                    (None, None) => (
+                        format!("{}/latest", self.releases_api_url()?),
+                        "The repository has no release.".to_owned(),
+                    ),
+                };
+
+                let release: Release = self
+                    .fetch_release_metadata(url)
+                    .await?
+                    .ok_or(UpdateError::new(not_found))?;
+                if self.skip_yanked_detection
+                    && self.rolling_tag.is_none()
+                    && self.release_id.is_none()
+                {
+                    return self.confirm_latest_release(release).await;
+                }
+
+                Ok(release)
+            }
+            ReleaseSource::LocalJson(path) => read_local_release(path).await,
+        }
+    }
+
+    /// Fetches again the `latest` release by its ID, returning the previous stable release instead
+    /// if it has been deleted or turned into a draft or a pre-release since it was resolved, see
+    /// `with_skip_yanked_detection`.
+    async fn confirm_latest_release(&self, latest: Release) -> Result<Release, UpdateError> {
+        // A release without ID cannot be fetched again
+        if latest.id == 0 {
+            return Ok(latest);
+        }
+
+        let response: Response = self
+            .send_request(
+                &format!("{}/{}", self.releases_api_url()?, latest.id),
+                "application/vnd.github+json",
+            )
+            .await?;
+        if response.status() != reqwest::StatusCode::NOT_FOUND {
+            let release: Release = response.error_for_status()?.json::<Release>().await?;
+            if !release.draft && !release.prerelease {
+                return Ok(release);
+            }
+        }
+
+        log::warn!(
+            "The latest release {} has been pulled, falling back to the previous stable release.",
+            latest.name
+        );
+        let (releases, _): (Vec<Release>, bool) = self.fetch_releases_page(1).await?;
+        releases
+            .into_iter()
+            .find(|release| release.id != latest.id && !release.draft && !release.prerelease)
+            .ok_or_else(|| {
+                UpdateError::new("The repository has no release but the pulled one.".to_owned())
+            })
+    }
+
+    /// Returns the asset of `release` matching the file name pattern for `rust_target`, chosen
+    /// with the asset selector when several assets match, or `None` if no asset matches.
+    fn select_asset<'a>(
+        &self,
+        release: &'a Release,
+        release_version: &str,
+        rust_target: Option<&str>,
+    ) -> Result<Option<&'a Asset>, UpdateError> {
+        // Without pattern, the assets are only selected by `with_asset_match`
+        let mut pattern: String = match (&self.pattern, &self.asset_match) {
+            (Some(pattern), _) => pattern.clone(),
+            (None, Some(_)) => String::new(),
+            (None, None) => return Err(BuilderNotInitialized.into()),
+        };
+        if !self.asset_version_autodetect {
+            pattern = pattern.replace(
+                "{app_version}",
+                self.version_placeholder_source
+                    .resolve(release_version, &release.tag_name),
+            );
+        }
+        if let Some(app_name) = &self.app_name {
+            pattern = pattern.replace("{app_name}", app_name);
+        }
+        #[cfg(target_os = "linux")]
+        if pattern.contains("{libc}") {
+            let libc: Libc = Libc::detect().ok_or_else(|| {
+                UpdateError::new("Unable to detect the C library of the system.".to_owned())
+            })?;
+            pattern = pattern.replace("{libc}", libc.as_str());
+        }
+        let patterns: Vec<String> = match rust_target {
+            Some(rust_target) if self.flexible_target_matching => relaxed_targets(rust_target)
+                .iter()
+                .map(|target| pattern.replace("{rust_target}", target))
+                .collect(),
+            Some(rust_target) => vec![pattern.replace("{rust_target}", rust_target)],
+            None => vec![pattern],
+        };
+
+        let candidate_assets: Vec<&Asset> = release
+            .assets
+            .iter()
+            .filter(|asset| {
+                !self
+                    .asset_exclude_patterns
+                    .iter()
+                    .any(|exclude_pattern| asset.name.contains(exclude_pattern.as_str()))
+                    && self
+                        .asset_match
+                        .as_ref()
+                        .is_none_or(|asset_match| asset_match.matches(&asset.name))
+                    && (!self.multipart_asset || multipart_base_name(&asset.name).is_some())
+            })
+            .collect();
+        let mut matching_assets: Vec<&Asset> = Vec::new();
+        for pattern in &patterns {
+            matching_assets = if pattern.contains("{app_version}") {
+                let reference_version: &str = if release.tag_name.is_empty() {
+                    release_version
+                } else {
+                    &release.tag_name
+                };
+                self.match_versioned_assets(&candidate_assets, pattern, reference_version)?
+            } else {
+                candidate_assets
+                    .iter()
+                    .copied()
+                    .filter(|asset| asset.browser_download_url.contains(pattern.as_str()))
+                    .collect()
+            };
+            if !matching_assets.is_empty() {
+                break;
+            }
+        }
+
+        Ok(match (matching_assets.len(), &self.asset_selector) {
+            (0, _) => None,
+            (1, _) | (_, None) => Some(matching_assets[0]),
+            (_, Some(asset_selector)) => {
+                let asset_infos: Vec<AssetInfo> = matching_assets
+                    .iter()
+                    .map(|asset| asset.to_asset_info())
+                    .collect();
+                let index: usize = asset_selector(&asset_infos);
+                Some(matching_assets.get(index).copied().ok_or_else(|| {
+                    UpdateError::new(format!(
+                        "The asset selector returned the index {} but only {} assets match the pattern.",
+                        index,
+                        matching_assets.len()
+                    ))
+                })?)
+            }
+        })
+    }
+
+    /// Fetches the release metadata at `url`, revalidating the previous response with its `ETag`
+    /// or `Last-Modified` header, if any: when the server answers that it has not been modified,
+    /// the cached release is used without downloading it again. `None` is returned if there is no
+    /// release at `url`.
+    async fn fetch_release_metadata(
+        &mut self,
+        url: String,
+    ) -> Result<Option<Release>, UpdateError> {
+        let cached_release: Option<&CachedRelease> = self
+            .cached_release
+            .as_ref()
+            .filter(|cached_release| cached_release.url == url);
+        let mut headers: HeaderMap = HeaderMap::new();
+        if let Some(cached_release) = cached_release {
+            if let Some(etag) = &cached_release.etag {
+                headers.insert(reqwest::header::IF_NONE_MATCH, etag.clone());
+            }
+            if let Some(last_modified) = &cached_release.last_modified {
+                headers.insert(reqwest::header::IF_MODIFIED_SINCE, last_modified.clone());
+            }
+        }
+
+        let response: Response = self
+            .send_request_with_body(
+                Method::GET,
+                &url,
+                "application/vnd.github+json",
+                None,
+                self.metadata_timeout,
+                headers,
+            )
+            .await?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(cached_release) = cached_release {
+                return Ok(Some(cached_release.release.clone()));
+            }
+        }
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let etag: Option<HeaderValue> = response.headers().get(reqwest::header::ETAG).cloned();
+        let last_modified: Option<HeaderValue> = response
+            .headers()
+            .get(reqwest::header::LAST_MODIFIED)
+            .cloned();
+        let release: Release = response.json::<Release>().await?;
+        self.cached_release = (etag.is_some() || last_modified.is_some()).then(|| CachedRelease {
+            url,
+            etag,
+            last_modified,
+            release: release.clone(),
+        });
+
+        Ok(Some(release))
+    }
+
+    /// Resolves, from a single fetch of the release, the URL of the asset matching the file name
+    /// pattern for each Rust target, e.g.: to build a manifest of the installers of every platform
+    /// without one API request per target. The state of the updater is left unchanged.
+    ///
+    /// The `{rust_target}` placeholder of the pattern is replaced by each target, as configured
+    /// with `with_flexible_target_matching`. The URL is the one from which `force_update` would
+    /// download the asset, i.e. its API URL for a GitHub release.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets` - The Rust targets, e.g.: `x86_64-unknown-linux-gnu`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the release, or if an error occurs while parsing the response JSON.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the URL of the asset of each target, or `None` for the targets
+    /// without any matching asset.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let asset_urls = updater_builder
+    ///     .resolve_assets_for_targets(&["x86_64-unknown-linux-gnu", "aarch64-apple-darwin"])
+    ///     .await?;
+    /// ```
+    pub async fn resolve_assets_for_targets(
+        &mut self,
+        targets: &[&str],
+    ) -> Result<HashMap<String, Option<String>>, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let release: Release = self.fetch_release().await?;
+        let release_version: String = self.extract_version(&release.name, &release.tag_name)?;
+        let mut asset_urls: HashMap<String, Option<String>> = HashMap::with_capacity(targets.len());
+        for target in targets {
+            let asset_url: Option<String> = self
+                .select_asset(&release, &release_version, Some(target))?
+                .map(|asset| self.asset_download_url(asset))
+                .transpose()?;
+            asset_urls.insert((*target).to_owned(), asset_url);
+        }
+
+        Ok(asset_urls)
+    }
+
+    /// Forgets the fetched release, which is fetched again by the next operation.
+    fn clear_fetched_release(&mut self) {
+        self.release_url = None;
+        self.release_parts = Vec::new();
+        self.resolved_from_cache = false;
+        self.resolution_cacheable = false;
+        self.release_asset_name = None;
+        #[cfg(feature = "sigstore")]
+        {
+            self.sigstore_bundle_url = None;
+        }
+        #[cfg(feature = "provenance")]
+        {
+            self.provenance_attestation_url = None;
+        }
+        self.app_version = None;
+        self.asset_size = None;
+        self.release_notes = None;
+        self.release_tag = None;
+        self.released_at = None;
+        self.published_sha256 = None;
+        self.checksum_asset = None;
+        self.metadata_fetch_duration = None;
+        self.need_refresh = true;
+    }
+
+    /// Fetches the latest release if it has not been fetched since the last download, otherwise
+    /// checks that the URL of the asset to download is known, so that a download never starts
+    /// from a partially fetched state.
+    async fn ensure_release_fetched(&mut self) -> Result<(), UpdateError> {
+        if self.need_refresh {
+            return self.fetch_last_release().await;
+        }
+        if self.release_url.is_none() || self.app_version.is_none() {
+            return Err(UpdateError::with_kind(
+                "The release has not been fetched, call fetch_last_release first.".to_owned(),
+                ErrorKind::NotFetched,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the assets matching `pattern`, in which `{app_version}` matches any version, whose
+    /// version is `reference_version`, otherwise the highest one, see
+    /// `with_asset_version_autodetect`.
+    fn match_versioned_assets<'a>(
+        &self,
+        assets: &[&'a Asset],
+        pattern: &str,
+        reference_version: &str,
+    ) -> Result<Vec<&'a Asset>, UpdateError> {
+        let pattern_regex: Regex = Regex::new(
+            &pattern
+                .split("{app_version}")
+                .map(regex::escape)
+                .collect::<Vec<String>>()
+                .join(ASSET_VERSION_REGEX),
+        )
+        .map_err(|error| {
+            UpdateError::new(format!("Invalid release file name pattern: {}", error))
+        })?;
+        let versioned_assets: Vec<(&Asset, String)> = assets
+            .iter()
+            .filter_map(|asset| {
+                let version: &str = pattern_regex
+                    .captures(&asset.browser_download_url)?
+                    .get(1)?
+                    .as_str();
+                Some((*asset, self.normalize_version(version)))
+            })
+            .collect();
+
+        let reference_version: String = self.normalize_version(
+            reference_version
+                .strip_prefix(['v', 'V'])
+                .unwrap_or(reference_version),
+        );
+        let is_same_version = |version: &str, other_version: &str| {
+            version == other_version
+                || compare_versions(version, other_version) == Some(Ordering::Equal)
+        };
+        let mut matching_assets: Vec<&Asset> = versioned_assets
+            .iter()
+            .filter(|(_, version)| is_same_version(version, &reference_version))
+            .map(|(asset, _)| *asset)
+            .collect();
+        if matching_assets.is_empty() {
+            let highest_version: Option<&String> = versioned_assets
+                .iter()
+                .map(|(_, version)| version)
+                .max_by(|version, other_version| {
+                    compare_versions(version, other_version).unwrap_or(Ordering::Equal)
+                });
+            if let Some(highest_version) = highest_version {
+                matching_assets = versioned_assets
+                    .iter()
+                    .filter(|(_, version)| is_same_version(version, highest_version))
+                    .map(|(asset, _)| *asset)
+                    .collect();
+            }
+        }
+
+        Ok(matching_assets)
+    }
+
+    /// Checks that the API URL of `asset`, if any, is the one of an asset of the repository of
+    /// `release`, so that a selection bug cannot make the update download an asset of another
+    /// repository. The repositories are compared case-insensitively, and with the URL of the
+    /// release returned by GitHub rather than the configured repository, which may have been
+    /// renamed or transferred since.
+    fn check_asset_in_release(&self, release: &Release, asset: &Asset) -> Result<(), UpdateError> {
+        if asset.url.is_empty() {
+            return Ok(());
+        }
+
+        let asset_repository: Option<String> = api_repository(&asset.url);
+        let belongs_to_release: bool = match (&asset_repository, api_repository(&release.url)) {
+            (None, _) => false,
+            (Some(asset_repository), Some(release_repository)) => {
+                *asset_repository == release_repository
+            }
+            (Some(_), None) => true,
+        };
+        if !belongs_to_release {
+            return Err(UpdateError::new(format!(
+                "The selected asset {} does not belong to the release {}.",
+                asset.name,
+                self.release_tag
+                    .as_deref()
+                    .or(self.app_version.as_deref())
+                    .unwrap_or_default()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the URL from which an asset of the fetched release is downloaded.
+    fn asset_download_url(&self, asset: &Asset) -> Result<String, UpdateError> {
+        match &self.release_source {
+            ReleaseSource::GitHub if asset.url.is_empty() => Ok(asset.browser_download_url.clone()),
+            ReleaseSource::GitHub => Ok(asset.url.clone()),
+            ReleaseSource::LocalJson(path) => {
+                let asset_path: PathBuf = path
+                    .parent()
+                    .unwrap_or_else(|| Path::new(""))
+                    .join(&asset.name);
+                let asset_path: PathBuf = std::path::absolute(&asset_path)?;
+                Ok(Url::from_file_path(&asset_path)
+                    .map_err(|_| {
+                        UpdateError::new(format!(
+                            "Invalid local asset path {}.",
+                            asset_path.display()
+                        ))
+                    })?
+                    .to_string())
+            }
+        }
+    }
+
+    /// Checks, without downloading anything, whether a newer version is available on GitHub.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release or while reading the version file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the update status (`UpdateStatus`) if the check is successful.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let update_status = updater_builder.check_for_update().await?;
+    /// ```
+    pub async fn check_for_update(&mut self) -> Result<UpdateStatus, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        if let Some(latest_version) = self.recently_checked_version().await {
+            return Ok(UpdateStatus {
+                current_version: self.get_current_version(app_name, path).await?,
+                latest_version,
+                update_available: false,
+                check_skipped: true,
+            });
+        }
+
+        self.fetch_last_release().await?;
+        self.need_refresh = false;
+
+        let update_available: bool = self.check_if_update_is_needed().await?
+            && !(self.downgrade_policy == DowngradePolicy::Refuse && self.is_downgrade().await?)
+            && !self.is_release_too_recent();
+        if !update_available {
+            self.record_check().await?;
+        }
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+
+        Ok(UpdateStatus {
+            current_version: self.get_current_version(app_name, path).await?,
+            latest_version: self.app_version.clone().unwrap_or_default(),
+            update_available,
+            check_skipped: false,
+        })
+    }
+
+    /// Fetches the last release and returns how far its version is from the installed one, e.g.:
+    /// to apply patch updates automatically but ask before installing a major one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release or while reading the version file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the update severity (`UpdateSeverity`), `UpdateSeverity::Major` if
+    /// nothing is installed yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match updater_builder.update_severity().await? {
+    ///     UpdateSeverity::Patch => updater_builder.force_update().await?,
+    ///     UpdateSeverity::None => return Ok(()),
+    ///     _ => ask_before_updating(),
+    /// };
+    /// ```
+    pub async fn update_severity(&mut self) -> Result<UpdateSeverity, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        self.fetch_last_release().await?;
+        self.need_refresh = false;
+
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        let latest_version: &String = self.app_version.as_ref().ok_or(BuilderNotInitialized)?;
+
+        Ok(match self.get_current_version(app_name, path).await? {
+            Some(current_version) => UpdateSeverity::between(&current_version, latest_version),
+            None => UpdateSeverity::Major,
+        })
+    }
+
+    /// Returns whether the fetched release was published more recently than the minimum age.
+    fn is_release_too_recent(&self) -> bool {
+        match (self.minimum_release_age, self.released_at) {
+            (Some(minimum_release_age), Some(released_at)) => SystemTime::now()
+                .duration_since(released_at)
+                .map_or(true, |age| age < minimum_release_age),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the fetched release is older than the installed version.
+    async fn is_downgrade(&self) -> Result<bool, UpdateError> {
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        let latest_version: &String = self.app_version.as_ref().ok_or(BuilderNotInitialized)?;
+
+        Ok(match self.get_current_version(app_name, path).await? {
+            Some(current_version) => {
+                compare_versions(latest_version, &current_version) == Some(Ordering::Less)
+            }
+            None => false,
+        })
+    }
+
+    /// Checks if an update is needed for the GitHub release.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(true)` if an update is needed.
+    /// - `Ok(false)` if no update is needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let update_is_needed = updater_builder.check_if_update_is_needed().await;
+    /// ```
+    async fn check_if_update_is_needed(&mut self) -> Result<bool, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let current_version: &String = self.app_version.as_ref().ok_or(BuilderNotInitialized)?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        if !path.join(self.generate_file_name(app_name)).exists() {
+            return Ok(true);
+        }
+        let Some(previous_version) = self.read_version_record(app_name, path).await? else {
+            return Ok(true);
+        };
+
+        if self.normalize_version(previous_version.version.trim()) != *current_version {
+            return Ok(true);
+        }
+        if !self.content_based_update_check {
+            return Ok(false);
+        }
+
+        Ok(
+            match (
+                previous_version.sha256,
+                self.fetch_published_sha256().await?,
+            ) {
+                (Some(installed_sha256), Some(published_sha256)) => {
+                    !installed_sha256.eq_ignore_ascii_case(&published_sha256)
+                }
+                // The assets of a rolling tag may have changed since they were installed
+                _ => self.rolling_tag.is_some(),
+            },
+        )
+    }
+
+    /// Returns the SHA-256 published for the matched release asset, from the GitHub API or from a
+    /// checksum file attached to the release, if any.
+    async fn fetch_published_sha256(&self) -> Result<Option<String>, UpdateError> {
+        if let Some(published_sha256) = &self.published_sha256 {
+            return Ok(Some(published_sha256.clone()));
+        }
+        let Some((checksum_url, asset_name)) = &self.checksum_asset else {
+            return Ok(None);
+        };
+
+        let content: Vec<u8> = self.read_attached_asset(checksum_url).await?;
+
+        Ok(parse_checksum_file(
+            &String::from_utf8_lossy(&content),
+            asset_name,
+        ))
+    }
+
+    /// Opens the matched release asset, or its `part` for a multi-part asset, returning its content
+    /// along with the digest and the size it must have.
+    async fn open_asset_body(
         &self,
-        app_name: &str,
-        path: &Path,
-    ) -> Result<Option<String>, UpdateError> {
-        let path_version_file: PathBuf = path.join(format!("binary-version-{}.txt", app_name));
-        if path_version_file.exists() {
-            Ok(Some(tokio::fs::read_to_string(&path_version_file).await?))
+        part: Option<&AssetPart>,
+    ) -> Result<(AssetBody, Option<ExpectedDigest>, usize), UpdateError> {
+        let release_url: &String = match part {
+            Some(part) => &part.url,
+            None => self.release_url.as_ref().ok_or(UpdateError::new(
+                "An error occurred while retrieving the release URL.".to_owned(),
+            ))?,
+        };
+        let release_url: &str = &self.rewrite_asset_url(release_url);
+        self.check_download_host(release_url)?;
+
+        // Assets of a local release are read from the file system, without any header to verify
+        // them against but the size announced in the release.
+        if let Ok(url) = Url::parse(release_url) {
+            if url.scheme() == "file" {
+                let path: PathBuf = url.to_file_path().map_err(|_| {
+                    UpdateError::new(format!("Invalid local asset URL \"{}\".", release_url))
+                })?;
+                self.ensure_verifiable(None)?;
+                let file: File = File::open(&path).await?;
+                let content_length: u64 = match part.map_or(self.asset_size, |part| {
+                    Some(part.size).filter(|size| *size > 0)
+                }) {
+                    Some(asset_size) => asset_size,
+                    None => file.metadata().await?.len(),
+                };
+
+                return Ok((
+                    AssetBody::new(AssetSource::File(file), self.chunk_buffer_size),
+                    None,
+                    self.expected_part_length(part, Some(content_length))?,
+                ));
+            }
+        }
+
+        // With an external downloader, only the headers of the asset are requested, to follow the
+        // redirects to its final URL and to check them before the program runs.
+        let method: Method = match self.external_downloader {
+            Some(_) => Method::HEAD,
+            None => Method::GET,
+        };
+        let response = self
+            .send_request_with_body(
+                method,
+                release_url,
+                "application/octet-stream",
+                None,
+                self.download_timeout,
+                HeaderMap::new(),
+            )
+            .await?;
+        self.check_download_host(response.url().as_str())?;
+        if !response.status().is_success() {
+            return Err(UpdateError::new(format!(
+                "An error occurred while downloading the file, HTTP code: {}",
+                response.status()
+            )));
+        }
+        if !self.allowed_content_types.is_empty() {
+            let content_type: &str = match response.headers().get(reqwest::header::CONTENT_TYPE) {
+                Some(content_type) => content_type.to_str()?,
+                None => "",
+            };
+            let media_type: &str = content_type.split(';').next().unwrap_or_default().trim();
+            if !self
+                .allowed_content_types
+                .iter()
+                .any(|allowed_content_type| allowed_content_type.eq_ignore_ascii_case(media_type))
+            {
+                return Err(UpdateError::new(format!(
+                    "The asset is served with the content type \"{}\", which is not allowed.",
+                    content_type
+                )));
+            }
+        }
+
+        let mut expected_digest: Option<ExpectedDigest> =
+            ExpectedDigest::from_headers(response.headers());
+        if let Some(digest) = expected_digest
+            .as_ref()
+            .filter(|digest| !digest.is_well_formed())
+        {
+            if !self.ignore_malformed_digest_header {
+                return Err(UpdateError::new(format!(
+                    "Server provided an invalid {} header.",
+                    digest.header
+                )));
+            }
+            log::warn!(
+                "Server provided an invalid {} header, the {} checksum is not verified.",
+                digest.header,
+                digest.algorithm
+            );
+            expected_digest = None;
+        }
+        self.ensure_verifiable(expected_digest.as_ref())?;
+        let content_length: Option<u64> = match response.headers().get("content-length") {
+            Some(content_length) => Some(content_length.to_str()?.parse::<u64>()?),
+            None => None,
+        };
+        if let Some(external_downloader) = &self.external_downloader {
+            return self
+                .download_externally(
+                    external_downloader,
+                    part,
+                    response.url(),
+                    expected_digest,
+                    content_length,
+                )
+                .await;
+        }
+        let content_length: usize = self.expected_part_length(part, content_length)?;
+
+        Ok((
+            AssetBody::new(AssetSource::Http(response), self.chunk_buffer_size),
+            expected_digest,
+            content_length,
+        ))
+    }
+
+    /// Downloads the asset, or its `part`, with the program set with `with_external_downloader`,
+    /// from its final `url`, to verify it against the `expected_digest` and the `content_length`
+    /// announced by the server, if any.
+    async fn download_externally(
+        &self,
+        external_downloader: &ExternalDownloader,
+        part: Option<&AssetPart>,
+        url: &Url,
+        expected_digest: Option<ExpectedDigest>,
+        content_length: Option<u64>,
+    ) -> Result<(AssetBody, Option<ExpectedDigest>, usize), UpdateError> {
+        let mut headers: Vec<(&str, String)> = vec![
+            ("User-Agent", "GitHub-Updater".to_owned()),
+            ("Accept", "application/octet-stream".to_owned()),
+        ];
+        if url.host_str() == Some(GITHUB_API_HOST) {
+            headers.push(("X-GitHub-Api-Version", self.api_version.clone()));
+            if let Some(token) = &self.github_token {
+                headers.push(("Authorization", format!("token {}", token)));
+            }
+        }
+
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        let staging_dir: PathBuf = create_staging_dir(app_name).await?;
+        let guard = PartialDownloadGuard::directory(staging_dir.clone());
+        let output: PathBuf = staging_dir.join(self.generate_file_name(app_name));
+        external_downloader
+            .download(url.as_str(), &headers, &output)
+            .await?;
+
+        let file: File = File::open(&output).await?;
+        let content_length: u64 = match content_length.or(part.map_or(self.asset_size, |part| {
+            Some(part.size).filter(|size| *size > 0)
+        })) {
+            Some(content_length) => content_length,
+            None => file.metadata().await?.len(),
+        };
+
+        Ok((
+            AssetBody::new(
+                AssetSource::DownloadedFile(file, guard),
+                self.chunk_buffer_size,
+            ),
+            expected_digest,
+            self.expected_part_length(part, Some(content_length))?,
+        ))
+    }
+
+    /// Returns the size the asset must have, from the `announced_length` of the server or the
+    /// release, checked against the size set with `with_expected_size`.
+    fn expected_content_length(&self, announced_length: Option<u64>) -> Result<usize, UpdateError> {
+        let content_length: u64 = match (announced_length, self.expected_size) {
+            (Some(announced_length), Some(expected_size)) if announced_length != expected_size => {
+                return Err(UpdateError::new(format!(
+                    "File corrupted: the asset is announced with {} bytes instead of the {} expected.",
+                    announced_length, expected_size
+                )));
+            }
+            (Some(content_length), _) | (None, Some(content_length)) => content_length,
+            (None, None) => {
+                return Err(UpdateError::new(
+                    "The content-length header is absent.".to_owned(),
+                ))
+            }
+        };
+
+        Ok(content_length as usize)
+    }
+
+    /// Checks, with `with_require_verification`, that the asset can be verified, against the
+    /// `expected_digest` announced by the server or a mechanism of the release or of the
+    /// configuration, before any data is downloaded.
+    fn ensure_verifiable(
+        &self,
+        expected_digest: Option<&ExpectedDigest>,
+    ) -> Result<(), UpdateError> {
+        if !self.require_verification
+            || expected_digest.is_some()
+            || self.published_sha256.is_some()
+            || self.checksum_asset.is_some()
+            || self.signature_verification_configured()
+        {
+            return Ok(());
+        }
+
+        Err(UpdateError::new(
+            "The integrity of the file cannot be verified, no checksum is published for it."
+                .to_owned(),
+        ))
+    }
+
+    /// Returns the size `part` of a multi-part asset must have, from the `announced_length` of the
+    /// server or the release, or the size of the whole asset if `part` is `None`.
+    fn expected_part_length(
+        &self,
+        part: Option<&AssetPart>,
+        announced_length: Option<u64>,
+    ) -> Result<usize, UpdateError> {
+        let Some(part) = part else {
+            return self.expected_content_length(announced_length);
+        };
+
+        let content_length: u64 = match (announced_length, part.size) {
+            (Some(announced_length), size) if size > 0 && announced_length != size => {
+                return Err(UpdateError::new(format!(
+                    "File corrupted: the part {} is announced with {} bytes instead of the {} expected.",
+                    part.name, announced_length, size
+                )));
+            }
+            (Some(content_length), _) => content_length,
+            (None, size) if size > 0 => size,
+            (None, _) => {
+                return Err(UpdateError::new(
+                    "The content-length header is absent.".to_owned(),
+                ))
+            }
+        };
+
+        Ok(content_length as usize)
+    }
+
+    /// Downloads the matched release asset into `writer`, verifying its integrity along the way.
+    ///
+    /// The body is streamed chunk by chunk: each chunk is hashed and written before the next one is
+    /// read, so the whole file is never held in memory. The SHA-256 recorded in the version file is
+    /// computed from the same chunks, so the file is never read back from disk either.
+    /// Verification happens once the stream is complete, which means `writer` may already have
+    /// received the data when an error is returned.
+    ///
+    /// When `writer` is the file `destination`, the download is refused before any data is written
+    /// if the file system of its directory does not have enough space for the asset, with an
+    /// `ErrorKind::InsufficientDiskSpace` error, and the file is quarantined if it fails an
+    /// integrity check, see `with_quarantine_corrupt_downloads`.
+    async fn download_release_to<W: AsyncWrite + Unpin>(
+        &self,
+        writer: W,
+        destination: Option<&Path>,
+    ) -> Result<DownloadedAsset, UpdateError> {
+        let mut writer: BufWriter<W> = BufWriter::with_capacity(self.chunk_buffer_size, writer);
+        let parts: Vec<Option<&AssetPart>> = if self.release_parts.is_empty() {
+            vec![None]
         } else {
-            Ok(None)
+            self.release_parts.iter().map(Some).collect()
+        };
+
+        let mut sha256_hasher: Hasher = Hasher::new(DigestAlgorithm::Sha256);
+        let mut expected_length: usize = 0;
+        let mut downloaded_length: usize = 0;
+        let mut magic_bytes: Vec<u8> = Vec::with_capacity(MAGIC_BYTES_LENGTH);
+        let mut throttle: Option<Throttle> = self.download_rate_limit.map(Throttle::new);
+        for part in parts {
+            let (mut body, expected_digest, content_length) = self.open_asset_body(part).await?;
+            if let Some(destination_dir) = destination.and_then(Path::parent) {
+                ensure_available_space(destination_dir, content_length as u64)?;
+            }
+
+            let mut hasher: Option<Hasher> = expected_digest
+                .as_ref()
+                .map(|digest| Hasher::new(digest.algorithm));
+            let mut part_sha256: Option<(&String, Hasher)> = part
+                .and_then(|part| part.sha256.as_ref())
+                .map(|sha256| (sha256, Hasher::new(DigestAlgorithm::Sha256)));
+            let mut part_length: usize = 0;
+            while let Some(chunk) = body.next_chunk().await? {
+                if let Some(throttle) = throttle.as_mut() {
+                    throttle.consume(chunk.len()).await;
+                }
+                if let Some(hasher) = hasher.as_mut() {
+                    hasher.update(&chunk);
+                }
+                if let Some((_, part_sha256_hasher)) = part_sha256.as_mut() {
+                    part_sha256_hasher.update(&chunk);
+                }
+                sha256_hasher.update(&chunk);
+                if magic_bytes.len() < MAGIC_BYTES_LENGTH {
+                    let missing_length: usize = MAGIC_BYTES_LENGTH - magic_bytes.len();
+                    magic_bytes.extend(chunk.iter().take(missing_length));
+                }
+                writer.write_all(&chunk).await?;
+                part_length += chunk.len();
+            }
+            writer.flush().await?;
+
+            // Verify file integrity with the digest announced by the server and content-size
+            if let (Some(expected_digest), Some(hasher)) = (expected_digest, hasher) {
+                let actual_digest: String = hasher.finalize_base64();
+                if expected_digest.value != actual_digest {
+                    self.report_verification_failure(
+                        VerificationFailure {
+                            algorithm: Some(expected_digest.algorithm.to_string()),
+                            expected_digest: Some(expected_digest.value),
+                            actual_digest: Some(actual_digest),
+                            expected_length: content_length as u64,
+                            actual_length: part_length as u64,
+                            ..Default::default()
+                        },
+                        destination,
+                    )
+                    .await;
+                    return Err(UpdateError::new(format!(
+                        "File corrupted: {} checksum does not match.",
+                        expected_digest.algorithm
+                    )));
+                }
+            }
+            if content_length != part_length {
+                self.report_verification_failure(
+                    VerificationFailure {
+                        expected_length: content_length as u64,
+                        actual_length: part_length as u64,
+                        ..Default::default()
+                    },
+                    destination,
+                )
+                .await;
+                return Err(UpdateError::new(
+                    "File corrupted: Incorrect file size detected.".to_owned(),
+                ));
+            }
+            if let (Some(part), Some((expected_sha256, part_sha256_hasher))) = (part, part_sha256) {
+                let actual_sha256: String = part_sha256_hasher.finalize_hex();
+                if !expected_sha256.eq_ignore_ascii_case(&actual_sha256) {
+                    self.report_verification_failure(
+                        VerificationFailure {
+                            algorithm: Some(DigestAlgorithm::Sha256.to_string()),
+                            expected_digest: Some(expected_sha256.clone()),
+                            actual_digest: Some(actual_sha256),
+                            expected_length: content_length as u64,
+                            actual_length: part_length as u64,
+                            ..Default::default()
+                        },
+                        destination,
+                    )
+                    .await;
+                    return Err(UpdateError::new(format!(
+                        "File corrupted: SHA-256 of the part {} does not match.",
+                        part.name
+                    )));
+                }
+            }
+            expected_length += content_length;
+            downloaded_length += part_length;
+        }
+
+        // The size and the checksum of a multi-part asset are verified once it is assembled
+        let multipart: bool = !self.release_parts.is_empty();
+        if let (true, Some(expected_size)) = (multipart, self.expected_size) {
+            if downloaded_length as u64 != expected_size {
+                self.report_verification_failure(
+                    VerificationFailure {
+                        expected_length: expected_size,
+                        actual_length: downloaded_length as u64,
+                        ..Default::default()
+                    },
+                    destination,
+                )
+                .await;
+                return Err(UpdateError::new(
+                    "File corrupted: Incorrect file size detected.".to_owned(),
+                ));
+            }
+        }
+        let sha256: String = sha256_hasher.finalize_hex();
+        // The SHA-256 published for the asset, by the GitHub API or in a checksum asset, and the
+        // one of the checksum file of `with_checksum_url_template`, if any
+        let mut expected_sha256s: Vec<String> = Vec::new();
+        match &self.checksum_asset {
+            Some((checksum_url, asset_name)) if self.checksum_url_template.is_some() => {
+                let content: Vec<u8> = self.read_attached_asset(checksum_url).await?;
+                expected_sha256s.push(
+                    parse_checksum_file(&String::from_utf8_lossy(&content), asset_name)
+                        .ok_or_else(|| {
+                            UpdateError::new(format!(
+                                "The checksum file {} does not list {}.",
+                                checksum_url, asset_name
+                            ))
+                        })?,
+                );
+                expected_sha256s.extend(self.published_sha256.clone());
+            }
+            _ => match self.fetch_published_sha256().await? {
+                Some(published_sha256) => expected_sha256s.push(published_sha256),
+                None if multipart => {
+                    return Err(UpdateError::new(format!(
+                        "No checksum of the multi-part asset {} is published.",
+                        self.release_asset_name.as_deref().unwrap_or_default()
+                    )));
+                }
+                None => {}
+            },
+        }
+        for expected_sha256 in expected_sha256s {
+            if !expected_sha256.eq_ignore_ascii_case(&sha256) {
+                self.report_verification_failure(
+                    VerificationFailure {
+                        algorithm: Some(DigestAlgorithm::Sha256.to_string()),
+                        expected_digest: Some(expected_sha256),
+                        actual_digest: Some(sha256),
+                        expected_length: expected_length as u64,
+                        actual_length: downloaded_length as u64,
+                        ..Default::default()
+                    },
+                    destination,
+                )
+                .await;
+                return Err(UpdateError::new(
+                    "File corrupted: SHA-256 does not match the published checksum.".to_owned(),
+                ));
+            }
+        }
+        // The format of a compressed asset is verified once decompressed
+        if self.verify_executable_format && self.store_decompressed.is_none() {
+            self.verify_executable_format(&magic_bytes)?;
+        }
+
+        Ok(DownloadedAsset { sha256 })
+    }
+
+    /// Verifies the Sigstore signature of the downloaded `file`, if configured with
+    /// `with_sigstore_verification`.
+    #[cfg(feature = "sigstore")]
+    async fn verify_sigstore_signature(&self, file: &Path) -> Result<(), UpdateError> {
+        let Some(sigstore_config) = &self.sigstore_config else {
+            return Ok(());
+        };
+        let bundle_url: &String = self.sigstore_bundle_url.as_ref().ok_or_else(|| {
+            UpdateError::with_kind(
+                "Signature verification failed: no Sigstore bundle is attached to the asset."
+                    .to_owned(),
+                ErrorKind::SignatureVerificationFailed,
+            )
+        })?;
+
+        let bundle: Vec<u8> = self
+            .read_attached_asset(bundle_url)
+            .await
+            .map_err(|error| match error.kind() {
+                ErrorKind::Other => UpdateError::with_kind(
+                    format!(
+                        "Signature verification failed: unable to download the Sigstore bundle: {}",
+                        error
+                    ),
+                    ErrorKind::SignatureVerificationFailed,
+                ),
+                _ => error,
+            })?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        let staging_dir: PathBuf = create_staging_dir(app_name).await?;
+        let _guard = PartialDownloadGuard::directory(staging_dir.clone());
+        let bundle_path: PathBuf = staging_dir.join("bundle.sigstore.json");
+        tokio::fs::write(&bundle_path, bundle).await?;
+
+        sigstore_config.verify(file, &bundle_path).await
+    }
+
+    /// Runs the verifications which need the downloaded `file` to be complete, i.e. those of the
+    /// signatures and of the provenance, then decompresses it and runs the smoke test, if
+    /// configured.
+    async fn verify_downloaded_file(&self, file: &Path) -> Result<(), UpdateError> {
+        #[cfg(feature = "sigstore")]
+        self.verify_sigstore_signature(file).await?;
+        #[cfg(feature = "provenance")]
+        self.verify_provenance(file).await?;
+        if let Some(compression) = &self.store_decompressed {
+            compression.decompress_file(file).await?;
+            if self.verify_executable_format {
+                let mut magic_bytes: Vec<u8> = Vec::with_capacity(MAGIC_BYTES_LENGTH);
+                File::open(file)
+                    .await?
+                    .take(MAGIC_BYTES_LENGTH as u64)
+                    .read_to_end(&mut magic_bytes)
+                    .await?;
+                self.verify_executable_format(&magic_bytes)?;
+            }
+        }
+        #[cfg(all(windows, feature = "authenticode"))]
+        if self.authenticode_verification {
+            authenticode::verify(file, self.authenticode_subject.as_deref()).await?;
+        }
+        if let Some(smoke_test) = &self.smoke_test {
+            smoke_test.run(file).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the SLSA provenance of the downloaded `file`, if configured with
+    /// `with_provenance_verification`.
+    #[cfg(feature = "provenance")]
+    async fn verify_provenance(&self, file: &Path) -> Result<(), UpdateError> {
+        let Some(provenance_config) = &self.provenance_config else {
+            return Ok(());
+        };
+        let attestation_url: &String =
+            self.provenance_attestation_url.as_ref().ok_or_else(|| {
+                UpdateError::with_kind(
+                    "Provenance verification failed: no attestation is attached to the release."
+                        .to_owned(),
+                    ErrorKind::ProvenanceVerificationFailed,
+                )
+            })?;
+        let repository_infos: &(String, String) = self
+            .repository_infos
+            .as_ref()
+            .or(self.release_repository_infos.as_ref())
+            .ok_or(BuilderNotInitialized)?;
+
+        let attestation: Vec<u8> =
+            self.read_attached_asset(attestation_url)
+                .await
+                .map_err(|error| match error.kind() {
+                    ErrorKind::Other => UpdateError::with_kind(
+                        format!(
+                        "Provenance verification failed: unable to download the attestation: {}",
+                        error
+                    ),
+                        ErrorKind::ProvenanceVerificationFailed,
+                    ),
+                    _ => error,
+                })?;
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        let staging_dir: PathBuf = create_staging_dir(app_name).await?;
+        let _guard = PartialDownloadGuard::directory(staging_dir.clone());
+        let attestation_path: PathBuf = staging_dir.join("provenance.intoto.jsonl");
+        tokio::fs::write(&attestation_path, attestation).await?;
+
+        provenance_config
+            .verify(
+                file,
+                &attestation_path,
+                &format!("github.com/{}/{}", repository_infos.0, repository_infos.1),
+                self.release_tag.as_deref(),
+            )
+            .await
+    }
+
+    /// Returns the URL from which the asset at `url` is downloaded, rewritten by the function set
+    /// with `with_url_rewriter`, if any.
+    fn rewrite_asset_url(&self, url: &str) -> String {
+        match &self.url_rewriter {
+            Some(url_rewriter) => url_rewriter(url),
+            None => url.to_owned(),
+        }
+    }
+
+    /// Whether the redirects are followed by the crate rather than by the HTTP client, see
+    /// `with_manual_redirect_handling` and `with_allowed_download_hosts`.
+    fn follows_redirects_itself(&self) -> bool {
+        self.manual_redirect_handling || !self.allowed_download_hosts.is_empty()
+    }
+
+    /// Checks that the host of the download `url` is allowed by `with_allowed_download_hosts`.
+    fn check_download_host(&self, url: &str) -> Result<(), UpdateError> {
+        if self.allowed_download_hosts.is_empty() {
+            return Ok(());
+        }
+        let url: Url = Url::parse(url)
+            .map_err(|error| UpdateError::new(format!("Invalid URL \"{}\": {}", url, error)))?;
+        if url.scheme() == "file" && matches!(self.release_source, ReleaseSource::LocalJson(_)) {
+            return Ok(());
+        }
+
+        let host: &str = url.host_str().unwrap_or_default();
+        if self
+            .allowed_download_hosts
+            .iter()
+            .any(|allowed_host| allowed_host.eq_ignore_ascii_case(host))
+        {
+            Ok(())
+        } else {
+            Err(DisallowedHost {
+                host: host.to_owned(),
+            }
+            .into())
+        }
+    }
+
+    /// Reads a small asset attached to the release, e.g.: a signature bundle, from GitHub or from
+    /// the local file system.
+    async fn read_attached_asset(&self, url: &str) -> Result<Vec<u8>, UpdateError> {
+        let url: &str = &self.rewrite_asset_url(url);
+        self.check_download_host(url)?;
+        Ok(match Url::parse(url) {
+            Ok(parsed_url) if parsed_url.scheme() == "file" => {
+                let path: PathBuf = parsed_url.to_file_path().map_err(|_| {
+                    UpdateError::new(format!("Invalid local asset URL \"{}\".", url))
+                })?;
+                tokio::fs::read(path).await?
+            }
+            _ => {
+                let response: Response = self.send_request(url, "application/octet-stream").await?;
+                self.check_download_host(response.url().as_str())?;
+                response.error_for_status()?.bytes().await?.to_vec()
+            }
+        })
+    }
+
+    /// Calls the function set with `with_on_verification_failure`, if any, filling the URL of the
+    /// asset in `failure`.
+    async fn report_verification_failure(
+        &self,
+        mut failure: VerificationFailure,
+        destination: Option<&Path>,
+    ) {
+        failure.asset_url = self.release_url.clone().unwrap_or_default();
+        if let Some(on_verification_failure) = &self.on_verification_failure {
+            on_verification_failure(&failure);
+        }
+        if let (Some(quarantine_dir), Some(destination)) = (&self.quarantine_dir, destination) {
+            if let Err(error) = quarantine_download(quarantine_dir, destination, &failure).await {
+                log::warn!(
+                    "Unable to quarantine the corrupt download {}: {}",
+                    destination.display(),
+                    error
+                );
+            }
+        }
+    }
+
+    /// Checks that the executable format of the downloaded file, recognized from its first bytes,
+    /// is the one of the configured Rust target, or of the current platform if no target is set.
+    fn verify_executable_format(&self, magic_bytes: &[u8]) -> Result<(), UpdateError> {
+        let target: &str = self.rust_target.as_deref().unwrap_or(std::env::consts::OS);
+        let expected_format: Option<ExecutableFormat> =
+            ExecutableFormat::expected_for_target(target);
+        let format: Option<ExecutableFormat> = ExecutableFormat::detect(magic_bytes);
+
+        match (expected_format, format) {
+            (Some(expected_format), Some(format)) if expected_format != format => {
+                Err(UpdateError::new(format!(
+                    "Invalid executable: expected a {} file for the {} target but got a {} file.",
+                    expected_format, target, format
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Download the latest GitHub release into a caller-provided writer.
+    ///
+    /// Unlike `force_update`, nothing is written to the download path: no version file is created
+    /// and no file is renamed, the verified bytes are only streamed into `writer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination of the downloaded asset, e.g.: a file, a pipe or an encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release, if an error occurs while downloading the file, or if the integrity verification fails. In the latter case, the data has already been written to `writer`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the download information (`DownloadInfos`) if the download is successful.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let download_infos = updater_builder.download_to_writer(tokio::io::sink()).await?;
+    /// ```
+    pub async fn download_to_writer<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: W,
+    ) -> Result<DownloadInfos, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        #[cfg(feature = "sigstore")]
+        if self.sigstore_config.is_some() {
+            return Err(UpdateError::new(
+                "The Sigstore signature can only be verified when downloading to a file."
+                    .to_owned(),
+            ));
+        }
+        #[cfg(feature = "provenance")]
+        if self.provenance_config.is_some() {
+            return Err(UpdateError::new(
+                "The provenance can only be verified when downloading to a file.".to_owned(),
+            ));
+        }
+        #[cfg(all(windows, feature = "authenticode"))]
+        if self.authenticode_verification {
+            return Err(UpdateError::new(
+                "The Authenticode signature can only be verified when downloading to a file."
+                    .to_owned(),
+            ));
+        }
+
+        self.ensure_release_fetched().await?;
+
+        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let previous_version: Option<String> = self.get_current_version(app_name, path).await?;
+        let new_version: String = self
+            .app_version
+            .as_ref()
+            .ok_or_else(|| UpdateError::new("No version of the application found.".to_owned()))?
+            .to_owned();
+
+        let downloaded_asset: DownloadedAsset = self.download_release_to(writer, None).await?;
+
+        Ok(DownloadInfos {
+            previous_version,
+            latest_available: Some(new_version.clone()),
+            new_version,
+            has_been_updated: true,
+            forced_update: true,
+            downgrade_refused: false,
+            downloaded_sha256: Some(downloaded_asset.sha256),
+            released_at: self.released_at,
+            release_too_recent: false,
+            timings: None,
+            update_declined: false,
+            check_skipped: false,
+            changed: true,
+        })
+    }
+
+    /// Download the latest GitHub release and write it to the standard output, e.g.: to pipe it
+    /// into `tar xz` from a script.
+    ///
+    /// The asset is first downloaded into a temporary directory, where it is fully verified,
+    /// signatures and provenance included, before anything is written: corrupted data is never
+    /// emitted. As with `download_to_writer`, no version file is created and no file is renamed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release, if an error occurs while downloading the file, if the verification fails, or if an error occurs while writing to the standard output.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the download information (`DownloadInfos`) if the download is successful.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// updater_builder.download_to_stdout().await?;
+    /// ```
+    pub async fn download_to_stdout(&mut self) -> Result<DownloadInfos, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
         }
+
+        self.ensure_release_fetched().await?;
+
+        let app_name: String = self.app_name.clone().ok_or(BuilderNotInitialized)?;
+        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+        let previous_version: Option<String> = self.get_current_version(&app_name, path).await?;
+        let new_version: String = self
+            .app_version
+            .clone()
+            .ok_or_else(|| UpdateError::new("No version of the application found.".to_owned()))?;
+
+        let staging_dir: PathBuf = create_staging_dir(&app_name).await?;
+        let _guard = PartialDownloadGuard::directory(staging_dir.clone());
+        let staged_file: PathBuf = staging_dir.join(self.generate_file_name(&app_name));
+        let mut file: File = File::create(&staged_file).await?;
+        let downloaded_asset: DownloadedAsset = self
+            .download_release_to(&mut file, Some(&staged_file))
+            .await?;
+        drop(file);
+        self.verify_downloaded_file(&staged_file).await?;
+
+        let mut stdout = tokio::io::stdout();
+        tokio::io::copy(&mut File::open(&staged_file).await?, &mut stdout).await?;
+        stdout.flush().await?;
+
+        Ok(DownloadInfos {
+            previous_version,
+            latest_available: Some(new_version.clone()),
+            new_version,
+            has_been_updated: true,
+            forced_update: true,
+            downgrade_refused: false,
+            downloaded_sha256: Some(downloaded_asset.sha256),
+            released_at: self.released_at,
+            release_too_recent: false,
+            timings: None,
+            update_declined: false,
+            check_skipped: false,
+            changed: true,
+        })
     }
 
-    /// Retrieve the latest version of the release from GitHub.
+    /// Download and verify the latest GitHub release into a new temporary directory, without
+    /// touching the download path nor the version file.
+    ///
+    /// This allows privilege-separated installers to do the final placement themselves, or to
+    /// call `StagedUpdate::commit` to install it like `force_update` would. As with `force_update`,
+    /// the temporary directory is removed on a best-effort basis if the future is dropped before
+    /// its completion.
     ///
     /// # Errors
     ///
     /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
     ///
-    /// But return (`UpdateError` error) if an error occurs while making the API request, if an error occurs while parsing the response JSON, if an error occurs while retrieving the release URL, or if no URL matching the pattern is found.
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release, if an error occurs during file operations, or if an error occurs while downloading the file.
     ///
     /// # Returns
     ///
-    /// `Ok(())` if the fetch is successful.
+    /// A `Result` containing the staged update (`StagedUpdate`) if the download is successful.
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// updater_builder.fetch_last_release().await;
+    /// let staged_update = updater_builder.stage_download().await?;
+    /// println!("{} downloaded to {}", staged_update.version, staged_update.path.display());
     /// ```
-    pub async fn fetch_last_release(&mut self) -> Result<(), UpdateError> {
+    pub async fn stage_download(&mut self) -> Result<StagedUpdate, UpdateError> {
         if !self.built {
             return Err(BuilderNotInitialized.into());
         }
 
-        let repository_infos: &(String, String) = self
-            .repository_infos
-            .as_ref()
-            .ok_or(BuilderNotInitialized)?;
-        let url: String = format!(
-            "https://api.github.com/repos/{}/{}/releases/latest",
-            repository_infos.0, repository_infos.1
-        );
-
-        let mut build_request = self
-            .reqwest_client
-            .as_ref()
-            .ok_or(BuilderNotInitialized)?
-            .get(&url)
-            .header("User-Agent", "GitHub-Updater")
-            .header("Accept", "application/vnd.github.v3+json");
-        if let Some(token) = &self.github_token {
-            build_request = build_request.header("Authorization", format!("token {}", token));
-        }
-
-        let response = build_request.send().await?.json::<Release>().await?;
-        let asset_urls: Vec<String> = response
-            .assets
-            .iter()
-            .map(|asset| asset.browser_download_url.to_owned())
-            .collect();
-
-        let mut pattern: String = self
-            .pattern
-            .as_ref()
-            .ok_or(BuilderNotInitialized)?
-            .replace("{app_version}", &response.name);
-        if let Some(app_name) = &self.app_name {
-            pattern = pattern.replace("{app_name}", app_name);
-        }
-        if let Some(rust_target) = &self.rust_target {
-            pattern = pattern.replace("{rust_target}", rust_target);
-        }
-        self.app_version = Some(response.name);
+        self.ensure_release_fetched().await?;
 
-        let matching_value: Option<&String> =
-            asset_urls.iter().find(|&value| value.contains(&pattern));
-        if let Some(value) = matching_value {
-            let api_url: String = response
-                .assets
-                .iter()
-                .find(|asset| &asset.browser_download_url == value)
-                .map(|asset| &asset.url)
-                .ok_or_else(|| {
-                    UpdateError("An error occurred while retrieving the release URL.".to_owned())
-                })?
-                .clone();
+        let app_name: String = self.app_name.clone().ok_or(BuilderNotInitialized)?;
+        let path: PathBuf = self.download_path.clone().ok_or(BuilderNotInitialized)?;
+        let version: String = self
+            .app_version
+            .clone()
+            .ok_or_else(|| UpdateError::new("No version of the application found.".to_owned()))?;
+        let file_name: String = self.generate_file_name(&app_name);
+        self.check_directory_exists(&path)?;
 
-            self.release_url = Some(api_url);
-        } else {
-            return Err(UpdateError(
-                "No URL matching the pattern entered was found.".to_owned(),
-            ));
+        let staging_dir: PathBuf = create_staging_dir(&app_name).await?;
+        let staged_file: PathBuf = staging_dir.join(&file_name);
+        let mut guard = PartialDownloadGuard::directory(staging_dir.clone());
+        let mut file: File = File::create(&staged_file).await?;
+        let downloaded_asset: DownloadedAsset = match self
+            .download_release_to(&mut file, Some(&staged_file))
+            .await
+        {
+            Ok(downloaded_asset) => downloaded_asset,
+            Err(error) => {
+                drop(file);
+                guard.disarm();
+                tokio::fs::remove_dir_all(&staging_dir).await?;
+                return Err(error);
+            }
+        };
+        drop(file);
+        if let Err(error) = self.verify_downloaded_file(&staged_file).await {
+            guard.disarm();
+            tokio::fs::remove_dir_all(&staging_dir).await?;
+            return Err(error);
         }
+        guard.disarm();
+        let version_file_content: String =
+            self.version_file_content(&version, &downloaded_asset.sha256);
 
-        Ok(())
+        Ok(StagedUpdate::new(
+            staged_file,
+            version,
+            self.release_notes.clone(),
+            path.join(file_name),
+            self.version_file_path(&app_name, &path),
+            version_file_content,
+        ))
     }
 
-    /// Checks if an update is needed for the GitHub release.
+    /// Download and verify the latest GitHub release, deferring its installation to the commit of
+    /// the returned transaction.
+    ///
+    /// Transactions of several updaters can be merged to install all their files together, or none
+    /// of them if one installation fails.
     ///
     /// # Errors
     ///
     /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
     ///
+    /// But return (`UpdateError` error) if an error occurs while fetching the last release, if an error occurs during file operations, or if an error occurs while downloading the file.
+    ///
     /// # Returns
     ///
-    /// - `Ok(true)` if an update is needed.
-    /// - `Ok(false)` if no update is needed.
+    /// A `Result` containing the transaction (`TransactionalUpdate`) if the download is successful.
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// let update_is_needed = updater_builder.check_if_update_is_needed().await;
+    /// let transaction = updater_builder.prepare().await?;
+    /// if user_confirmed {
+    ///     transaction.commit().await?;
+    /// } else {
+    ///     transaction.abort().await?;
+    /// }
     /// ```
-    async fn check_if_update_is_needed(&mut self) -> Result<bool, UpdateError> {
-        if !self.built {
-            return Err(BuilderNotInitialized.into());
+    pub async fn prepare(&mut self) -> Result<TransactionalUpdate, UpdateError> {
+        let mut transaction = TransactionalUpdate::new();
+        transaction.push(self.stage_download().await?);
+
+        Ok(transaction)
+    }
+
+    /// Returns an error if `directory` does not exist and `with_create_missing_dirs` is disabled.
+    fn check_directory_exists(&self, directory: &Path) -> Result<(), UpdateError> {
+        if self.create_missing_dirs || directory.is_dir() {
+            return Ok(());
         }
 
-        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
-        let current_version: &String = self.app_version.as_ref().ok_or(BuilderNotInitialized)?;
-        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
-        let path_version_file: PathBuf = path.join(format!("binary-version-{}.txt", app_name));
+        Err(UpdateError::new(format!(
+            "The download directory {} does not exist.",
+            directory.display()
+        )))
+    }
 
-        if !path_version_file.exists() || !path.join(self.generate_file_name(app_name)).exists() {
-            return Ok(true);
+    /// Copies the installed file to each additional download path and writes their version files.
+    async fn install_in_additional_paths(
+        &self,
+        installed_file: &Path,
+        app_name: &str,
+        version_file_content: &str,
+    ) -> Result<(), UpdateError> {
+        let file_name: String = self.generate_file_name(app_name);
+        let mut backups: Vec<Backup> = Vec::with_capacity(self.additional_download_paths.len());
+        for path in &self.additional_download_paths {
+            let target_path: PathBuf =
+                if !self.erase_previous_file && path.join(&file_name).exists() {
+                    path.join(format!("new_{}", file_name))
+                } else {
+                    path.join(&file_name)
+                };
+            let result: Result<Backup, UpdateError> = match self.check_directory_exists(path) {
+                Ok(()) => {
+                    install_file(
+                        installed_file,
+                        &target_path,
+                        &self.version_file_path(app_name, path),
+                        version_file_content,
+                        true,
+                    )
+                    .await
+                }
+                Err(error) => Err(error),
+            };
+
+            match result {
+                Ok(backup) => {
+                    self.apply_file_owner(&target_path);
+                    self.apply_file_owner(&self.version_file_path(app_name, path));
+                    backups.push(backup);
+                }
+                Err(error) => {
+                    if self.additional_paths_failure_policy
+                        == AdditionalPathsFailurePolicy::Rollback
+                    {
+                        for backup in backups.iter().rev() {
+                            backup.restore().await;
+                        }
+                    } else {
+                        for backup in &backups {
+                            backup.remove().await?;
+                        }
+                    }
+                    return Err(UpdateError::new(format!(
+                        "An error occurred while installing the file in {}: {}",
+                        path.display(),
+                        error
+                    )));
+                }
+            }
+        }
+
+        for backup in &backups {
+            backup.remove().await?;
         }
 
-        let previous_version: String = tokio::fs::read_to_string(&path_version_file).await?;
+        Ok(())
+    }
+
+    /// Changes the owner of `path` to the one set with `with_file_owner`, if any. Failures are
+    /// logged since the file is already installed.
+    fn apply_file_owner(&self, path: &Path) {
+        let Some((uid, gid)) = self.file_owner else {
+            return;
+        };
+
+        #[cfg(unix)]
+        if let Err(error) = std::os::unix::fs::chown(path, Some(uid), Some(gid)) {
+            log::warn!(
+                "Unable to change the owner of {} to {}:{}: {}",
+                path.display(),
+                uid,
+                gid,
+                error
+            );
+        }
+        #[cfg(not(unix))]
+        log::warn!(
+            "Unable to change the owner of {} to {}:{}: unsupported on this platform",
+            path.display(),
+            uid,
+            gid
+        );
+    }
 
-        Ok(previous_version.trim() != current_version)
+    /// Renames `from` to `to`, retrying according to the `with_rename_retry` configuration.
+    async fn rename_with_retry(&self, from: &Path, to: &Path) -> Result<(), UpdateError> {
+        let mut attempt: u32 = 1;
+        loop {
+            match tokio::fs::rename(from, to).await {
+                Ok(()) => return Ok(()),
+                Err(_) if attempt < self.rename_attempts => {
+                    attempt += 1;
+                    tokio::time::sleep(self.rename_retry_delay).await;
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
     }
 
     /// Force download the latest GitHub release.
     ///
+    /// If the returned future is dropped before its completion, e.g.: by a timeout, the partially
+    /// downloaded file is removed on a best-effort basis: the removal is synchronous, as `Drop`
+    /// cannot be async, and its errors are ignored.
+    ///
     /// # Errors
     ///
     /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
@@ -520,6 +7120,7 @@ impl GithubUpdater {
     /// # Returns
     ///
     /// A `Result` containing the download information (`DownloadInfos`) if the update is successful.
+    /// Its `changed` field tells whether the installed file differs from the previous one.
     ///
     /// # Example
     ///
@@ -532,117 +7133,265 @@ impl GithubUpdater {
         }
 
         if self.need_refresh {
-            self.fetch_last_release().await?;
+            self.load_resolution_cache().await;
         }
-
-        let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
-        let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
-        let file_name = self.generate_file_name(app_name);
-        let previous_file: PathBuf = path.join(&file_name);
-        let new_file: PathBuf = if previous_file.exists() {
-            path.join(format!("new_{}", file_name))
-        } else {
-            previous_file.clone()
+        self.ensure_release_fetched().await?;
+        let mut timings = UpdateTimings {
+            metadata: self.metadata_fetch_duration.unwrap_or_default(),
+            ..Default::default()
         };
-        let release_url: &String = self.release_url.as_ref().ok_or(UpdateError(
-            "An error occurred while retrieving the release URL.".to_owned(),
-        ))?;
-        let previous_version: Option<String> = self.get_current_version(app_name, path).await?;
+        let steps_started_at: Instant = Instant::now();
+
+        let app_name: String = self.app_name.clone().ok_or(BuilderNotInitialized)?;
+        let path: PathBuf = self.download_path.clone().ok_or(BuilderNotInitialized)?;
+        let file_name = self.generate_file_name(&app_name);
+        let mut previous_file: PathBuf = path.join(&file_name);
+        if self.follow_symlinks && previous_file.is_symlink() && previous_file.exists() {
+            previous_file = tokio::fs::canonicalize(&previous_file).await?;
+        }
+        let new_file: PathBuf = staged_file_path(&previous_file);
+        let previous_record: Option<VersionRecord> =
+            self.read_version_record(&app_name, &path).await?;
+        let previous_version: Option<String> = previous_record
+            .as_ref()
+            .map(|record| self.normalize_version(&record.version));
+        // The SHA-256 recorded for the previous file spares hashing it, unless it is the one of a
+        // compressed asset or of a file with another name, or the file was modified since
+        let recorded_sha256: Option<String> = previous_record
+            .as_ref()
+            .filter(|record| {
+                self.store_decompressed.is_none()
+                    && record
+                        .file_name
+                        .as_ref()
+                        .is_none_or(|previous_file_name| *previous_file_name == file_name)
+                    && record.downloaded_at.is_some_and(|downloaded_at| {
+                        previous_file
+                            .metadata()
+                            .and_then(|metadata| metadata.modified())
+                            .is_ok_and(|modified| modified <= downloaded_at)
+                    })
+            })
+            .and_then(|record| record.sha256.clone());
+        // The file of the previous release has another name if it is named after its asset
+        let outdated_file: Option<PathBuf> = previous_record
+            .and_then(|record| record.file_name)
+            .filter(|previous_file_name| {
+                self.use_asset_filename
+                    && self.erase_previous_file
+                    && *previous_file_name != file_name
+                    && Path::new(previous_file_name).file_name()
+                        == Some(OsStr::new(previous_file_name))
+            })
+            .map(|previous_file_name| path.join(previous_file_name));
         let new_version: String = self
             .app_version
             .as_ref()
-            .ok_or_else(|| UpdateError("No version of the application found.".to_owned()))?
+            .ok_or_else(|| UpdateError::new("No version of the application found.".to_owned()))?
             .to_owned();
 
+        if let Some(pre_download_confirm) = &self.pre_download_confirm {
+            let pre_download_info = PreDownloadInfo {
+                previous_version: previous_version.clone(),
+                new_version: new_version.clone(),
+                size: self.asset_size,
+                release_notes: self.release_notes.clone(),
+                released_at: self.released_at,
+            };
+            if !pre_download_confirm(&pre_download_info) {
+                let forced_update: bool = self.forced_update;
+                self.forced_update = true;
+
+                return Ok(DownloadInfos {
+                    previous_version: previous_version.clone(),
+                    new_version: previous_version.unwrap_or_default(),
+                    has_been_updated: false,
+                    forced_update,
+                    latest_available: Some(new_version),
+                    downgrade_refused: false,
+                    downloaded_sha256: None,
+                    released_at: self.released_at,
+                    release_too_recent: false,
+                    timings: None,
+                    update_declined: true,
+                    check_skipped: false,
+                    changed: false,
+                });
+            }
+        }
+
+        self.check_directory_exists(&path)?;
         if !path.exists() {
-            tokio::fs::create_dir_all(path).await?;
+            tokio::fs::create_dir_all(&path).await?;
         }
         if new_file.exists() {
             tokio::fs::remove_file(&new_file).await?;
         }
+        let download_file: PathBuf = match &self.pending_file_extension {
+            Some(extension) => {
+                let mut download_file = new_file.clone().into_os_string();
+                download_file.push(format!(".{}", extension));
+                PathBuf::from(download_file)
+            }
+            None => new_file.clone(),
+        };
 
-        let mut build_request = self
-            .reqwest_client
-            .as_ref()
-            .ok_or(BuilderNotInitialized)?
-            .get(release_url)
-            .header("User-Agent", "GitHub-Updater")
-            .header("Accept", "application/octet-stream");
-        if let Some(token) = &self.github_token {
-            build_request = build_request.header("Authorization", format!("token {}", token));
+        let previous_sha256: Option<String> = match recorded_sha256 {
+            _ if !previous_file.is_file() => None,
+            Some(recorded_sha256) => Some(recorded_sha256),
+            None => Some(self.file_sha256(&previous_file).await?),
+        };
+        let mut file: File = File::create(&download_file).await?;
+        let mut guard = PartialDownloadGuard::file(download_file.clone());
+        let download_started_at: Instant = Instant::now();
+        let downloaded_asset: DownloadedAsset = match self
+            .download_release_to(&mut file, Some(&download_file))
+            .await
+        {
+            Ok(downloaded_asset) => downloaded_asset,
+            Err(error) => {
+                drop(file);
+                guard.disarm();
+                tokio::fs::remove_file(&download_file).await?;
+                return Err(error);
+            }
+        };
+        drop(file);
+        timings.download = download_started_at.elapsed();
+        let verification_started_at: Instant = Instant::now();
+        if let Err(error) = self.verify_downloaded_file(&download_file).await {
+            guard.disarm();
+            tokio::fs::remove_file(&download_file).await?;
+            return Err(error);
         }
+        timings.verification = verification_started_at.elapsed();
 
-        let response = build_request.send().await?;
-        if !response.status().is_success() {
-            return Err(UpdateError(format!(
-                "An error occurred while downloading the file, HTTP code: {}",
-                response.status()
-            )));
+        if download_file != new_file {
+            self.rename_with_retry(&download_file, &new_file).await?;
+            guard.track(new_file.clone());
+        }
+        // The rename replaces the previous file atomically: if the future is dropped before, the
+        // guard only removes the new file and the previous one is still installed
+        if self.erase_previous_file && previous_file != new_file {
+            self.rename_with_retry(&new_file, &previous_file).await?;
         }
+        guard.disarm();
 
-        let github_md5: Option<String> = response
-            .headers()
-            .get("content-md5")
-            .and_then(|h| h.to_str().ok())
-            .map(String::from);
-        let content_length: usize = response
-            .headers()
-            .get("content-length")
-            .ok_or_else(|| UpdateError("The content-length header is absent.".to_owned()))?
-            .to_str()?
-            .parse::<usize>()?;
-
-        let mut file: File = File::create(&new_file).await?;
-        let body = response.bytes().await?;
-        file.write_all(&body).await?;
-
-        // Verify file integrity with md5 and content-size
-        let file_md5: Option<String> = if github_md5.is_some() {
-            let mut hasher = md5::Md5::new();
-            let mut file: File = File::open(&new_file).await?;
-            let mut content: Vec<u8> = Vec::new();
-            file.read_to_end(&mut content).await?;
-            hasher.update(&content);
-
-            Some(STANDARD.encode(hasher.finalize()))
+        // Write version in file
+        let version_file_content: String =
+            self.version_file_content(&new_version, &downloaded_asset.sha256);
+        self.write_version_record(&app_name, &path, &version_file_content)
+            .await?;
+
+        let installed_file: &Path = if self.erase_previous_file {
+            &previous_file
         } else {
-            None
+            &new_file
         };
-
-        if github_md5 != file_md5 || content_length != body.len() {
-            tokio::fs::remove_file(&new_file).await?;
-
-            if github_md5 != file_md5 {
-                return Err(UpdateError(
-                    "File corrupted: MD5 checksum does not match.".to_owned(),
-                ));
-            }
-            return Err(UpdateError(
-                "File corrupted: Incorrect file size detected.".to_owned(),
-            ));
+        self.apply_file_owner(installed_file);
+        if let Some(outdated_file) = outdated_file.filter(|outdated_file| outdated_file.is_file()) {
+            tokio::fs::remove_file(&outdated_file).await?;
         }
+        self.install_in_additional_paths(installed_file, &app_name, &version_file_content)
+            .await?;
+        self.store_resolution_cache(&downloaded_asset.sha256).await;
+        timings.file_operations = steps_started_at
+            .elapsed()
+            .saturating_sub(timings.download + timings.verification);
 
-        if self.erase_previous_file && previous_file != new_file {
-            tokio::fs::remove_file(&previous_file).await?;
-            tokio::fs::rename(&new_file, &previous_file).await?;
+        if let Some(notify_socket) = &self.notify_socket {
+            let update_event = UpdateEvent {
+                app: app_name.clone(),
+                old_version: previous_version.clone(),
+                new_version: new_version.clone(),
+                path: installed_file.to_path_buf(),
+            };
+            if let Err(error) = update_event.notify(notify_socket).await {
+                log::warn!(
+                    "Unable to notify {} of the update: {}",
+                    notify_socket.display(),
+                    error
+                );
+            }
         }
 
-        // Write version in file
-        let mut file: File =
-            File::create(path.join(format!("binary-version-{}.txt", app_name))).await?;
-        file.write_all(new_version.as_bytes()).await?;
-
         let forced_update: bool = self.forced_update;
         self.forced_update = true;
+        // A decompressed file is compared with the previous one rather than with the asset
+        let installed_sha256: String = match self.store_decompressed {
+            Some(_) => self.file_sha256(installed_file).await?,
+            None => downloaded_asset.sha256.clone(),
+        };
+        let changed: bool = previous_sha256 != Some(installed_sha256);
 
         Ok(DownloadInfos {
             previous_version,
+            latest_available: Some(new_version.clone()),
             new_version,
             has_been_updated: true,
             forced_update,
+            downgrade_refused: false,
+            downloaded_sha256: Some(downloaded_asset.sha256),
+            released_at: self.released_at,
+            release_too_recent: false,
+            timings: self.timing_metrics.then_some(timings),
+            update_declined: false,
+            check_skipped: false,
+            changed,
         })
     }
 
+    /// Force download a file from an explicit URL, e.g.: an asset resolved by the caller through
+    /// its own query, skipping the fetch of the latest release.
+    ///
+    /// The file goes through the same pipeline as with `force_update`: integrity checks, renames
+    /// and version file write.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the file, e.g.: the API URL of a release asset.
+    /// * `version` - The version of the file, written to the version file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if an error occurs during file operations, or if an error occurs while downloading the file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the download information (`DownloadInfos`) if the update is successful.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let download_infos = updater_builder
+    ///     .force_update_from_url(
+    ///         "https://api.github.com/repos/Asthowen/AFetch/releases/assets/1234",
+    ///         "1.0.0",
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn force_update_from_url(
+        &mut self,
+        url: &str,
+        version: &str,
+    ) -> Result<DownloadInfos, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+
+        self.clear_fetched_release();
+        self.release_url = Some(url.to_owned());
+        self.app_version = Some(self.normalize_version(version));
+        self.need_refresh = false;
+
+        let result: Result<DownloadInfos, UpdateError> = self.force_update().await;
+        self.need_refresh = true;
+
+        result
+    }
+
     /// Check and download, if necessary, the latest version of the release on GitHub.
     ///
     /// # Errors
@@ -665,13 +7414,52 @@ impl GithubUpdater {
             return Err(BuilderNotInitialized.into());
         }
 
+        if let Some(latest_version) = self.recently_checked_version().await {
+            let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
+            let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
+            let current_version: Option<String> = self.get_current_version(app_name, path).await?;
+
+            return Ok(DownloadInfos {
+                previous_version: current_version.clone(),
+                new_version: current_version.unwrap_or_default(),
+                latest_available: Some(latest_version),
+                check_skipped: true,
+                ..Default::default()
+            });
+        }
+
         self.fetch_last_release().await?;
         self.need_refresh = false;
 
+        let mut downgrade_refused: bool = false;
+        let mut release_too_recent: bool = false;
         if self.check_if_update_is_needed().await.unwrap_or(false) {
-            self.forced_update = false;
-            return self.force_update().await;
+            let download_infos: DownloadInfos =
+                match (self.is_downgrade().await?, self.downgrade_policy) {
+                    (true, DowngradePolicy::Refuse) => {
+                        downgrade_refused = true;
+                        None
+                    }
+                    _ if self.is_release_too_recent() => {
+                        release_too_recent = true;
+                        None
+                    }
+                    (true, DowngradePolicy::Force) => {
+                        self.forced_update = true;
+                        Some(self.force_update().await?)
+                    }
+                    _ => {
+                        self.forced_update = false;
+                        Some(self.force_update().await?)
+                    }
+                }
+                .unwrap_or_default();
+            if download_infos.has_been_updated || download_infos.update_declined {
+                self.record_check().await?;
+                return Ok(download_infos);
+            }
         }
+        self.record_check().await?;
 
         let path: &PathBuf = self.download_path.as_ref().ok_or(BuilderNotInitialized)?;
         let app_name: &String = self.app_name.as_ref().ok_or(BuilderNotInitialized)?;
@@ -682,6 +7470,382 @@ impl GithubUpdater {
             new_version: current_version.unwrap_or_default(),
             has_been_updated: false,
             forced_update: false,
+            latest_available: self.app_version.clone(),
+            downgrade_refused,
+            downloaded_sha256: None,
+            released_at: self.released_at,
+            release_too_recent,
+            timings: None,
+            update_declined: false,
+            check_skipped: false,
+            changed: false,
+        })
+    }
+
+    /// Runs `check_for_update` on several updaters concurrently, e.g.: for a launcher managing
+    /// several applications.
+    ///
+    /// At most `CHECK_MANY_CONCURRENCY` checks are in flight at the same time, each one using the
+    /// client and the token of its own updater.
+    ///
+    /// # Arguments
+    ///
+    /// * `updaters` - The built updaters to check.
+    ///
+    /// # Returns
+    ///
+    /// The result of each check, in the order of `updaters`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let results = GithubUpdater::check_many(vec![afetch_updater, other_updater]).await;
+    /// ```
+    pub async fn check_many(
+        updaters: Vec<GithubUpdater>,
+    ) -> Vec<Result<UpdateStatus, UpdateError>> {
+        stream::iter(updaters)
+            .map(|mut updater| async move { GithubUpdater::check_for_update(&mut updater).await })
+            .buffered(CHECK_MANY_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// Finds the newest release across the repositories of several updaters, e.g.: for an update
+    /// channel aggregating the releases of plugins published in separate repositories.
+    ///
+    /// The releases are fetched concurrently, at most `CHECK_MANY_CONCURRENCY` at the same time,
+    /// and their versions, extracted as configured in each updater, are compared according to the
+    /// semantic versioning precedence. On a tie, the first updater wins. The updaters are left
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `updaters` - The built updaters whose repositories are compared.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) if the release of no updater could be fetched. The
+    /// errors of the other updaters are reported in the `errors` of the result.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the newest release (`NewestRelease`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let newest_release = GithubUpdater::newest_across(&[stable_updater, mirror_updater]).await?;
+    /// ```
+    pub async fn newest_across(updaters: &[GithubUpdater]) -> Result<NewestRelease, UpdateError> {
+        let results: Vec<Result<(String, Release), UpdateError>> = stream::iter(updaters)
+            .map(|updater| async move {
+                if !updater.built {
+                    return Err(BuilderNotInitialized.into());
+                }
+
+                let mut updater: GithubUpdater = updater.clone();
+                let release: Release = updater.fetch_release().await?;
+                let version: String = updater.extract_version(&release.name, &release.tag_name)?;
+
+                Ok((updater.normalize_version(&version), release))
+            })
+            .buffered(CHECK_MANY_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut newest: Option<(usize, String, Release)> = None;
+        let mut errors: Vec<(usize, UpdateError)> = Vec::new();
+        for (index, result) in results.into_iter().enumerate() {
+            match result {
+                Ok((version, release)) => {
+                    let is_newer: bool = newest.as_ref().is_none_or(|(_, newest_version, _)| {
+                        compare_versions(&version, newest_version) == Some(Ordering::Greater)
+                    });
+                    if is_newer {
+                        newest = Some((index, version, release));
+                    }
+                }
+                Err(error) => errors.push((index, error)),
+            }
+        }
+
+        let (index, version, release) = newest.ok_or_else(|| {
+            UpdateError::new(format!(
+                "No release could be fetched from the {} updaters{}",
+                updaters.len(),
+                errors
+                    .iter()
+                    .map(|(index, error)| format!("\n{}: {}", index, error))
+                    .collect::<String>()
+            ))
+        })?;
+
+        Ok(NewestRelease {
+            index,
+            version,
+            release: release.to_summary(),
+            errors,
         })
     }
+
+    /// Synchronizes the download path with the manifest of the latest release, set with
+    /// `with_manifest_sync`: only the files which are new or whose SHA-256 differs from the one
+    /// of the manifest are downloaded, and the files of the previous manifest which are no longer
+    /// listed are removed.
+    ///
+    /// Each file is downloaded next to its destination, then verified against the SHA-256 of the
+    /// manifest before replacing it. The manifest is kept in the `manifest-<app name>.json` file
+    /// of the download path, and the version of the release is written to the version file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the builder is not initialized (`BuilderNotInitialized` error).
+    ///
+    /// But return (`UpdateError` error) if no manifest asset is set, if the manifest is not found
+    /// in the release or is invalid, if a file is not attached to the release or does not match
+    /// its SHA-256, or if an error occurs while downloading or writing the files.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the changes made (`ManifestSyncReport`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let report = updater_builder.sync_manifest().await?;
+    /// println!("{} files downloaded", report.downloaded.len());
+    /// ```
+    pub async fn sync_manifest(&mut self) -> Result<ManifestSyncReport, UpdateError> {
+        if !self.built {
+            return Err(BuilderNotInitialized.into());
+        }
+        let manifest_asset: String = self.manifest_asset.clone().ok_or_else(|| {
+            UpdateError::new("No manifest asset set, see with_manifest_sync.".to_owned())
+        })?;
+        let app_name: String = self.app_name.clone().ok_or(BuilderNotInitialized)?;
+        let path: PathBuf = self.download_path.clone().ok_or(BuilderNotInitialized)?;
+
+        let release: Release = self.fetch_release().await?;
+        let version: String = self.extract_version(&release.name, &release.tag_name)?;
+        let version: String = self.normalize_version(&version);
+        let manifest_url: String = release
+            .assets
+            .iter()
+            .find(|asset| asset.name == manifest_asset)
+            .map(|asset| self.asset_download_url(asset))
+            .transpose()?
+            .ok_or_else(|| {
+                UpdateError::new(format!(
+                    "No manifest asset named \"{}\" was found in the release.",
+                    manifest_asset
+                ))
+            })?;
+        let manifest_content: Vec<u8> = self.read_attached_asset(&manifest_url).await?;
+        let manifest: Manifest = serde_json::from_slice(&manifest_content)
+            .map_err(|error| UpdateError::new(format!("Invalid manifest: {}", error)))?;
+        let relative_paths: Vec<PathBuf> = manifest
+            .files
+            .iter()
+            .map(ManifestFile::relative_path)
+            .collect::<Result<_, _>>()?;
+
+        self.check_directory_exists(&path)?;
+        if !path.exists() {
+            tokio::fs::create_dir_all(&path).await?;
+        }
+        let mut report = ManifestSyncReport {
+            version: version.clone(),
+            ..Default::default()
+        };
+        for (manifest_file, relative_path) in manifest.files.iter().zip(&relative_paths) {
+            let file: PathBuf = path.join(relative_path);
+            if file.is_file()
+                && self
+                    .file_sha256(&file)
+                    .await?
+                    .eq_ignore_ascii_case(&manifest_file.sha256)
+            {
+                report.unchanged += 1;
+                continue;
+            }
+
+            let asset: &Asset = release
+                .assets
+                .iter()
+                .find(|asset| asset.name == manifest_file.asset_name())
+                .ok_or_else(|| {
+                    UpdateError::new(format!(
+                        "No asset named \"{}\" was found in the release for {}.",
+                        manifest_file.asset_name(),
+                        manifest_file.path
+                    ))
+                })?;
+            if let Some(parent) = file.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let mut download_file = file.clone().into_os_string();
+            download_file.push(".part");
+            let download_file: PathBuf = PathBuf::from(download_file);
+            let mut guard = PartialDownloadGuard::file(download_file.clone());
+            let sha256: String = self
+                .download_manifest_file(&self.asset_download_url(asset)?, &download_file)
+                .await?;
+            if !sha256.eq_ignore_ascii_case(&manifest_file.sha256) {
+                return Err(UpdateError::new(format!(
+                    "File corrupted: the SHA-256 of {} does not match the manifest.",
+                    manifest_file.path
+                )));
+            }
+            self.rename_with_retry(&download_file, &file).await?;
+            guard.disarm();
+            self.apply_file_owner(&file);
+            report.downloaded.push(manifest_file.path.clone());
+        }
+
+        let manifest_file_path: PathBuf = path.join(format!("manifest-{}.json", app_name));
+        if let Ok(previous_manifest) = tokio::fs::read(&manifest_file_path).await {
+            let previous_manifest: Manifest =
+                serde_json::from_slice(&previous_manifest).map_err(|error| {
+                    UpdateError::new(format!("Invalid installed manifest: {}", error))
+                })?;
+            for previous_file in previous_manifest.files {
+                let Ok(relative_path) = previous_file.relative_path() else {
+                    continue;
+                };
+                let file: PathBuf = path.join(&relative_path);
+                if !relative_paths.contains(&relative_path) && file.is_file() {
+                    tokio::fs::remove_file(&file).await?;
+                    report.removed.push(previous_file.path);
+                }
+            }
+        }
+        tokio::fs::write(&manifest_file_path, &manifest_content).await?;
+        self.apply_file_owner(&manifest_file_path);
+
+        self.app_version = Some(version.clone());
+        self.release_tag = Some(release.tag_name).filter(|tag_name| !tag_name.is_empty());
+        self.release_url = Some(manifest_url);
+        let mut manifest_hasher: Hasher = Hasher::new(DigestAlgorithm::Sha256);
+        manifest_hasher.update(&manifest_content);
+        let version_file_content: String =
+            self.version_file_content(&version, &manifest_hasher.finalize_hex());
+        self.write_version_record(&app_name, &path, &version_file_content)
+            .await?;
+
+        Ok(report)
+    }
+
+    /// Computes the SHA-256 of the local `file`, in hexadecimal.
+    async fn file_sha256(&self, file: &Path) -> Result<String, UpdateError> {
+        Ok(self
+            .hash_file(file, DigestAlgorithm::Sha256)
+            .await?
+            .finalize_hex())
+    }
+
+    /// Returns the hasher of `algorithm` fed with the content of `file`.
+    async fn hash_file(
+        &self,
+        file: &Path,
+        algorithm: DigestAlgorithm,
+    ) -> Result<Hasher, UpdateError> {
+        let mut body = AssetBody::new(
+            AssetSource::File(File::open(file).await?),
+            self.chunk_buffer_size,
+        );
+        let mut hasher: Hasher = Hasher::new(algorithm);
+        while let Some(chunk) = body.next_chunk().await? {
+            hasher.update(&chunk);
+        }
+
+        Ok(hasher)
+    }
+
+    /// Downloads the file of a manifest at `url` into `destination`, returning its SHA-256 in
+    /// hexadecimal.
+    async fn download_manifest_file(
+        &self,
+        url: &str,
+        destination: &Path,
+    ) -> Result<String, UpdateError> {
+        let url: &str = &self.rewrite_asset_url(url);
+        self.check_download_host(url)?;
+        let source: AssetSource = match Url::parse(url) {
+            Ok(parsed_url) if parsed_url.scheme() == "file" => {
+                let path: PathBuf = parsed_url.to_file_path().map_err(|_| {
+                    UpdateError::new(format!("Invalid local asset URL \"{}\".", url))
+                })?;
+                AssetSource::File(File::open(path).await?)
+            }
+            _ => {
+                let response: Response = self
+                    .send_request_with_timeout(
+                        url,
+                        "application/octet-stream",
+                        self.download_timeout,
+                    )
+                    .await?;
+                self.check_download_host(response.url().as_str())?;
+                if !response.status().is_success() {
+                    return Err(UpdateError::new(format!(
+                        "An error occurred while downloading the file, HTTP code: {}",
+                        response.status()
+                    )));
+                }
+                AssetSource::Http(response)
+            }
+        };
+
+        let mut body = AssetBody::new(source, self.chunk_buffer_size);
+        let mut writer: BufWriter<File> =
+            BufWriter::with_capacity(self.chunk_buffer_size, File::create(destination).await?);
+        let mut hasher: Hasher = Hasher::new(DigestAlgorithm::Sha256);
+        let mut throttle: Option<Throttle> = self.download_rate_limit.map(Throttle::new);
+        while let Some(chunk) = body.next_chunk().await? {
+            if let Some(throttle) = throttle.as_mut() {
+                throttle.consume(chunk.len()).await;
+            }
+            hasher.update(&chunk);
+            writer.write_all(&chunk).await?;
+        }
+        writer.flush().await?;
+
+        Ok(hasher.finalize_hex())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn download_without_fetched_release_is_not_fetched() {
+        let mut updater = GithubUpdater::builder()
+            .with_initialized_reqwest_client()
+            .with_app_name("afetch")
+            .with_repository("Asthowen/AFetch")
+            .with_release_file_name_pattern("{app_name}")
+            .with_download_path(&std::env::temp_dir())
+            .build()
+            .unwrap();
+        updater.need_refresh = false;
+
+        let error: UpdateError = updater.force_update().await.unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::NotFetched);
+    }
+
+    #[test]
+    fn insufficient_disk_space_reports_the_sizes() {
+        let error: UpdateError =
+            ensure_available_space(&std::env::temp_dir(), u64::MAX).unwrap_err();
+
+        match error.kind() {
+            ErrorKind::InsufficientDiskSpace { needed, available } => {
+                assert_eq!(*needed, u64::MAX);
+                assert!(*available < u64::MAX);
+            }
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+    }
 }
@@ -0,0 +1,172 @@
+use std::path::Path;
+
+/// The ELF program header type of the program interpreter, the dynamic loader.
+const PT_INTERP: u32 = 3;
+/// Executables whose loader is inspected, the first one found being used.
+const REFERENCE_EXECUTABLES: [&str; 3] = ["/proc/self/exe", "/bin/sh", "/usr/bin/env"];
+
+/// The C standard library of a Linux system, substituted to the `{libc}` placeholder of the file
+/// name pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Libc {
+    /// The GNU C library, used by most distributions, e.g.: Debian or Fedora.
+    Gnu,
+    /// The musl C library, used by e.g.: Alpine or Void Linux (musl variant).
+    Musl,
+}
+
+impl Libc {
+    /// Detects the C library of the running system.
+    ///
+    /// The dynamic loader requested by the current executable is inspected first: `ld-musl-*`
+    /// means musl and `ld-linux*` means glibc. The current executable may be statically linked,
+    /// e.g.: when built for a `*-linux-musl` target, in which case `/bin/sh` then `/usr/bin/env`
+    /// are inspected. If none of them names a known loader, the presence of a musl loader, then of
+    /// `libc.so.6`, in the usual library directories is checked.
+    ///
+    /// This is a heuristic: a system providing both libraries, or a container without any of these
+    /// executables, may be misdetected. Returns `None` if nothing conclusive was found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::Libc;
+    ///
+    /// if let Some(libc) = Libc::detect() {
+    ///     println!("Running on a {} system", libc);
+    /// }
+    /// ```
+    pub fn detect() -> Option<Self> {
+        REFERENCE_EXECUTABLES
+            .iter()
+            .filter_map(|path| std::fs::read(path).ok())
+            .find_map(|content| Self::from_interpreter(&read_elf_interpreter(&content)?))
+            .or_else(Self::from_library_directories)
+    }
+
+    /// Returns the name of the library as used in Rust targets, e.g.: `musl`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gnu => "gnu",
+            Self::Musl => "musl",
+        }
+    }
+
+    fn from_interpreter(interpreter: &str) -> Option<Self> {
+        let file_name: &str = interpreter.rsplit('/').next()?;
+        if file_name.starts_with("ld-musl-") {
+            Some(Self::Musl)
+        } else if file_name.starts_with("ld-linux") {
+            Some(Self::Gnu)
+        } else {
+            None
+        }
+    }
+
+    fn from_library_directories() -> Option<Self> {
+        const LIBRARY_DIRECTORIES: [&str; 4] = ["/lib", "/lib64", "/usr/lib", "/usr/lib64"];
+
+        let has_musl_loader: bool = LIBRARY_DIRECTORIES.iter().any(|directory| {
+            std::fs::read_dir(directory).is_ok_and(|mut entries| {
+                entries.any(|entry| {
+                    entry.is_ok_and(|entry| {
+                        entry.file_name().to_string_lossy().starts_with("ld-musl-")
+                    })
+                })
+            })
+        });
+        if has_musl_loader {
+            return Some(Self::Musl);
+        }
+
+        LIBRARY_DIRECTORIES
+            .iter()
+            .any(|directory| Path::new(directory).join("libc.so.6").exists())
+            .then_some(Self::Gnu)
+    }
+}
+
+impl std::fmt::Display for Libc {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Returns the program interpreter (`PT_INTERP`) requested by an ELF executable, if any.
+fn read_elf_interpreter(content: &[u8]) -> Option<String> {
+    if content.get(..4)? != b"\x7FELF" {
+        return None;
+    }
+    let is_64_bits: bool = *content.get(4)? == 2;
+    let is_little_endian: bool = *content.get(5)? == 1;
+
+    let read_u16 = |offset: usize| -> Option<u64> {
+        let bytes: [u8; 2] = content
+            .get(offset..offset.checked_add(2)?)?
+            .try_into()
+            .ok()?;
+        Some(u64::from(if is_little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        }))
+    };
+    let read_u32 = |offset: usize| -> Option<u64> {
+        let bytes: [u8; 4] = content
+            .get(offset..offset.checked_add(4)?)?
+            .try_into()
+            .ok()?;
+        Some(u64::from(if is_little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        }))
+    };
+    let read_u64 = |offset: usize| -> Option<u64> {
+        let bytes: [u8; 8] = content
+            .get(offset..offset.checked_add(8)?)?
+            .try_into()
+            .ok()?;
+        Some(if is_little_endian {
+            u64::from_le_bytes(bytes)
+        } else {
+            u64::from_be_bytes(bytes)
+        })
+    };
+
+    let (program_headers_offset, program_header_size, program_headers_count) = if is_64_bits {
+        (read_u64(32)?, read_u16(54)?, read_u16(56)?)
+    } else {
+        (read_u32(28)?, read_u16(42)?, read_u16(44)?)
+    };
+
+    (0..program_headers_count).find_map(|index| {
+        let header_offset: u64 = index
+            .checked_mul(program_header_size)?
+            .checked_add(program_headers_offset)?;
+        let header_offset: usize = usize::try_from(header_offset).ok()?;
+        if read_u32(header_offset)? != u64::from(PT_INTERP) {
+            return None;
+        }
+        let (offset, size) = if is_64_bits {
+            (
+                read_u64(header_offset.checked_add(8)?)?,
+                read_u64(header_offset.checked_add(32)?)?,
+            )
+        } else {
+            (
+                read_u32(header_offset.checked_add(4)?)?,
+                read_u32(header_offset.checked_add(16)?)?,
+            )
+        };
+        let start: usize = usize::try_from(offset).ok()?;
+        let end: usize = start.checked_add(usize::try_from(size).ok()?)?;
+        let interpreter: &[u8] = content.get(start..end)?;
+
+        Some(
+            String::from_utf8_lossy(interpreter)
+                .trim_end_matches('\0')
+                .to_owned(),
+        )
+    })
+}
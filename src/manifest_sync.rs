@@ -0,0 +1,68 @@
+use crate::errors::update_error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Manifest struct, describing the files of an application distributed as a directory, read from
+/// the manifest asset of the release, see `GithubUpdater::with_manifest_sync`.
+///
+/// ```json
+/// {"files": [{"path": "bin/afetch", "sha256": "<hex digest>"}, {"path": "share/logo.png", "sha256": "<hex digest>", "asset": "logo.png"}]}
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The files of the application.
+    pub files: Vec<ManifestFile>,
+}
+
+/// Manifest file struct, describing one file of a `Manifest`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManifestFile {
+    /// The path of the file, relative to the download path, with `/` as separator.
+    pub path: String,
+    /// The SHA-256 of the file, in hexadecimal.
+    pub sha256: String,
+    /// The name of the release asset holding the file. The value is null if the asset is named
+    /// after the file, i.e. the last component of its path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+}
+
+impl ManifestFile {
+    /// Returns the name of the release asset holding the file.
+    pub(crate) fn asset_name(&self) -> &str {
+        self.asset
+            .as_deref()
+            .unwrap_or_else(|| self.path.rsplit('/').next().unwrap_or_default())
+    }
+
+    /// Returns the path of the file, relative to the download path, refusing the paths which would
+    /// leave it, e.g.: absolute paths or paths containing `..`.
+    pub(crate) fn relative_path(&self) -> Result<PathBuf, UpdateError> {
+        let path: &Path = Path::new(&self.path);
+        if self.path.is_empty()
+            || !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(UpdateError::new(format!(
+                "Invalid path \"{}\" in the manifest.",
+                self.path
+            )));
+        }
+
+        Ok(path.to_path_buf())
+    }
+}
+
+/// Manifest sync report struct, describing the changes made by `GithubUpdater::sync_manifest`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManifestSyncReport {
+    /// The version of the release.
+    pub version: String,
+    /// The paths of the files downloaded because they were new or changed.
+    pub downloaded: Vec<String>,
+    /// The paths of the files removed because they are no longer in the manifest.
+    pub removed: Vec<String>,
+    /// The number of files already up to date.
+    pub unchanged: usize,
+}
@@ -0,0 +1,67 @@
+use crate::errors::update_error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// The maximum duration of the notification, after which it is abandoned.
+const NOTIFY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Update event struct, describing a successful update, written as a line of JSON to the socket
+/// set with `GithubUpdater::with_notify_socket`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateEvent {
+    /// The name of the application.
+    pub app: String,
+    /// The version installed before the update. The value is null if no version was installed.
+    pub old_version: Option<String>,
+    /// The version installed by the update.
+    pub new_version: String,
+    /// The path of the installed file.
+    pub path: PathBuf,
+}
+
+impl UpdateEvent {
+    /// Writes the event to the Unix socket, or the Windows named pipe, at `socket`.
+    pub(crate) async fn notify(&self, socket: &Path) -> Result<(), UpdateError> {
+        let mut line: Vec<u8> = serde_json::to_vec(self).map_err(|error| {
+            UpdateError::new(format!("Unable to serialize the update event: {}", error))
+        })?;
+        line.push(b'\n');
+
+        tokio::time::timeout(NOTIFY_TIMEOUT, Self::write(socket, &line))
+            .await
+            .map_err(|_| {
+                UpdateError::new(format!(
+                    "{} did not accept the update event within {} seconds.",
+                    socket.display(),
+                    NOTIFY_TIMEOUT.as_secs()
+                ))
+            })?
+    }
+
+    #[cfg(unix)]
+    async fn write(socket: &Path, line: &[u8]) -> Result<(), UpdateError> {
+        let mut stream = tokio::net::UnixStream::connect(socket).await?;
+        stream.write_all(line).await?;
+        stream.shutdown().await?;
+
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    async fn write(socket: &Path, line: &[u8]) -> Result<(), UpdateError> {
+        let mut pipe = tokio::net::windows::named_pipe::ClientOptions::new().open(socket)?;
+        pipe.write_all(line).await?;
+        pipe.flush().await?;
+
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    async fn write(_socket: &Path, _line: &[u8]) -> Result<(), UpdateError> {
+        Err(UpdateError::new(
+            "Sockets are not supported on this platform.".to_owned(),
+        ))
+    }
+}
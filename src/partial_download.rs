@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+/// Removes a partial download when dropped, unless it has been disarmed, e.g.: when the future of
+/// an update is dropped while downloading.
+///
+/// The removal is best-effort: `Drop` cannot be async, so it is done synchronously and its errors
+/// are ignored.
+#[derive(Debug)]
+pub(crate) struct PartialDownloadGuard {
+    path: Option<PathBuf>,
+    is_directory: bool,
+}
+
+impl PartialDownloadGuard {
+    /// Guards a file being downloaded.
+    pub(crate) fn file(path: PathBuf) -> Self {
+        Self {
+            path: Some(path),
+            is_directory: false,
+        }
+    }
+
+    /// Guards a directory in which a file is being downloaded, e.g.: a staging directory.
+    pub(crate) fn directory(path: PathBuf) -> Self {
+        Self {
+            path: Some(path),
+            is_directory: true,
+        }
+    }
+
+    /// Guards the new path of the partial download, after it has been renamed.
+    pub(crate) fn track(&mut self, path: PathBuf) {
+        self.path = Some(path);
+    }
+
+    /// Keeps the download, which is no longer partial.
+    pub(crate) fn disarm(&mut self) {
+        self.path = None;
+    }
+}
+
+impl Drop for PartialDownloadGuard {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            let _ = if self.is_directory {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+/// Expands a path read from an environment variable, see
+/// `GithubUpdater::with_download_path_from_env`:
+/// * a leading `~` is replaced by the home directory (`HOME`, or `USERPROFILE` on Windows);
+/// * `$NAME` and `${NAME}` are replaced by the value of the environment variable `NAME`;
+/// * a `$` which is not followed by a variable name is kept as is.
+///
+/// Returns an `Err` describing the problem if a variable, or the home directory, is not set.
+pub(crate) fn expand_path(value: &str) -> Result<PathBuf, String> {
+    let mut expanded: String = String::with_capacity(value.len());
+    let mut rest: &str = value;
+
+    if let Some(after_tilde) = rest.strip_prefix('~') {
+        if after_tilde.is_empty() || after_tilde.starts_with(['/', '\\']) {
+            expanded.push_str(&home_directory()?);
+            rest = after_tilde;
+        }
+    }
+
+    while let Some(index) = rest.find('$') {
+        expanded.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+
+        let (name, remaining): (&str, &str) = if let Some(braced) = rest.strip_prefix('{') {
+            let end: usize = braced
+                .find('}')
+                .ok_or_else(|| format!("unclosed variable in {}", value))?;
+            (&braced[..end], &braced[end + 1..])
+        } else {
+            let end: usize = rest
+                .find(|character: char| !(character.is_ascii_alphanumeric() || character == '_'))
+                .unwrap_or(rest.len());
+            (&rest[..end], &rest[end..])
+        };
+        if name.is_empty() {
+            expanded.push('$');
+            continue;
+        }
+
+        expanded.push_str(
+            &std::env::var(name).map_err(|_| format!("the variable {} is not set", name))?,
+        );
+        rest = remaining;
+    }
+    expanded.push_str(rest);
+
+    Ok(PathBuf::from(expanded))
+}
+
+fn home_directory() -> Result<String, String> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .map_err(|_| "the home directory is not set".to_owned())
+}
@@ -0,0 +1,80 @@
+use crate::errors::error_kind::ErrorKind;
+use crate::errors::update_error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Output;
+
+/// The extension of the provenance attestations attached to a release, e.g.:
+/// `afetch.intoto.jsonl` for the asset `afetch`, or `multiple.intoto.jsonl` for an attestation
+/// covering several assets.
+pub(crate) const ATTESTATION_EXTENSION: &str = "intoto.jsonl";
+
+/// Provenance configuration struct, describing which builder must have produced the assets, see
+/// `GithubUpdater::with_provenance_verification`.
+///
+/// The verification is done by the `slsa-verifier` executable (version 2 or later), which checks
+/// that the digest of the file is a subject of the attestation, the Sigstore signature of the
+/// attestation, and that the builder, the source repository and the tag match the expected ones.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProvenanceConfig {
+    /// The identifier of the trusted builder, e.g.:
+    /// `https://github.com/slsa-framework/slsa-github-generator/.github/workflows/generator_generic_slsa3.yml`.
+    pub builder_id: String,
+    /// The repository from which the assets must have been built, e.g.:
+    /// `github.com/Asthowen/AFetch`. The value is null to use the repository of the releases.
+    pub source_uri: Option<String>,
+    /// The path of the `slsa-verifier` executable. The value is null to search it in the `PATH`.
+    pub slsa_verifier_path: Option<PathBuf>,
+}
+
+impl ProvenanceConfig {
+    /// Verifies `file` against the provenance `attestation` with `slsa-verifier verify-artifact`,
+    /// the source being built from `source_uri` at the tag `source_tag`, if known.
+    pub(crate) async fn verify(
+        &self,
+        file: &Path,
+        attestation: &Path,
+        source_uri: &str,
+        source_tag: Option<&str>,
+    ) -> Result<(), UpdateError> {
+        let slsa_verifier_path: &Path = self
+            .slsa_verifier_path
+            .as_deref()
+            .unwrap_or(Path::new("slsa-verifier"));
+        let mut command = tokio::process::Command::new(slsa_verifier_path);
+        command
+            .arg("verify-artifact")
+            .arg(file)
+            .arg("--provenance-path")
+            .arg(attestation)
+            .arg("--source-uri")
+            .arg(self.source_uri.as_deref().unwrap_or(source_uri))
+            .arg("--builder-id")
+            .arg(&self.builder_id);
+        if let Some(source_tag) = source_tag {
+            command.arg("--source-tag").arg(source_tag);
+        }
+        let output: Output = command.kill_on_drop(true).output().await.map_err(|error| {
+            UpdateError::with_kind(
+                format!(
+                    "Provenance verification failed: unable to run {}: {}",
+                    slsa_verifier_path.display(),
+                    error
+                ),
+                ErrorKind::ProvenanceVerificationFailed,
+            )
+        })?;
+
+        if !output.status.success() {
+            return Err(UpdateError::with_kind(
+                format!(
+                    "Provenance verification failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+                ErrorKind::ProvenanceVerificationFailed,
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::errors::secondary_rate_limited::SecondaryRateLimited;
+use crate::errors::update_error::UpdateError;
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Response, ResponseBuilderExt};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Rate limit struct, describing the quota of GitHub API requests left, see
+/// `GithubUpdater::rate_limit_status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// The quota of the REST API, used by every request of the crate.
+    pub core: RateLimitBucket,
+    /// The quota of the search API, tracked separately by GitHub.
+    pub search: RateLimitBucket,
+}
+
+/// Rate limit bucket struct, describing the quota of one group of GitHub API endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitBucket {
+    /// The maximum number of requests allowed in the current window.
+    pub limit: u32,
+    /// The number of requests left in the current window.
+    pub remaining: u32,
+    /// When the current window ends and the quota is reset.
+    pub reset_at: SystemTime,
+}
+
+/// The response of the `/rate_limit` endpoint.
+#[derive(Debug, Deserialize)]
+pub(crate) struct RateLimitResponse {
+    resources: RateLimitResources,
+}
+
+#[derive(Debug, Deserialize)]
+struct RateLimitResources {
+    core: ApiRateLimitBucket,
+    search: ApiRateLimitBucket,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiRateLimitBucket {
+    limit: u32,
+    remaining: u32,
+    reset: u64,
+}
+
+impl From<ApiRateLimitBucket> for RateLimitBucket {
+    fn from(bucket: ApiRateLimitBucket) -> Self {
+        Self {
+            limit: bucket.limit,
+            remaining: bucket.remaining,
+            reset_at: UNIX_EPOCH + Duration::from_secs(bucket.reset),
+        }
+    }
+}
+
+impl From<RateLimitResponse> for RateLimit {
+    fn from(response: RateLimitResponse) -> Self {
+        Self {
+            core: response.resources.core.into(),
+            search: response.resources.search.into(),
+        }
+    }
+}
+
+/// Returns the secondary rate limit which rejected `response`, if any, with the response.
+///
+/// The secondary rate limits are reported by a 403 or 429 status, along with a `Retry-After`
+/// header or a message mentioning them. Responses exhausting the primary rate limit, which have no
+/// request left, are not reported. The body of the other 403 and 429 responses is only read to find
+/// the message for an `api_response`, i.e. served by the GitHub API, so that the response is
+/// rebuilt from it.
+pub(crate) async fn detect_secondary_rate_limit(
+    response: Response,
+    api_response: bool,
+) -> Result<(Response, Option<SecondaryRateLimited>), UpdateError> {
+    let headers: &HeaderMap = response.headers();
+    if !matches!(response.status().as_u16(), 403 | 429)
+        || headers
+            .get("x-ratelimit-remaining")
+            .is_some_and(|remaining| remaining == "0")
+    {
+        return Ok((response, None));
+    }
+
+    let retry_after: Option<Duration> = headers
+        .get(RETRY_AFTER)
+        .and_then(|retry_after| retry_after.to_str().ok())
+        .and_then(|retry_after| retry_after.trim().parse().ok())
+        .map(Duration::from_secs);
+    if retry_after.is_some() {
+        return Ok((response, Some(SecondaryRateLimited { retry_after })));
+    }
+    if !api_response {
+        return Ok((response, None));
+    }
+
+    let mut rebuilt_response = http::Response::builder()
+        .status(response.status())
+        .version(response.version())
+        .url(response.url().clone());
+    if let Some(rebuilt_headers) = rebuilt_response.headers_mut() {
+        *rebuilt_headers = response.headers().clone();
+    }
+    let body: Bytes = response.bytes().await?;
+    let secondary_rate_limit: Option<SecondaryRateLimited> = String::from_utf8_lossy(&body)
+        .to_lowercase()
+        .contains("secondary rate limit")
+        .then_some(SecondaryRateLimited { retry_after: None });
+    let rebuilt_response: Response = rebuilt_response
+        .body(body)
+        .map_err(|error| UpdateError::new(format!("Unable to rebuild the response: {}", error)))?
+        .into();
+
+    Ok((rebuilt_response, secondary_rate_limit))
+}
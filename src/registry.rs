@@ -0,0 +1,127 @@
+use crate::errors::update_error::UpdateError;
+use crate::version_store::VersionStore;
+use async_trait::async_trait;
+use windows_sys::Win32::Foundation::{ERROR_FILE_NOT_FOUND, ERROR_SUCCESS};
+use windows_sys::Win32::System::Registry::{
+    RegGetValueW, RegSetKeyValueW, HKEY_CURRENT_USER, REG_SZ, RRF_RT_REG_SZ,
+};
+
+/// The name of the registry value holding the version record.
+const VERSION_VALUE_NAME: &str = "version";
+
+/// Version store keeping the records in the registry of the current user. This store requires the
+/// `registry` feature and is only available on Windows.
+///
+/// The record of an application is the `version` string value of the key
+/// `HKEY_CURRENT_USER\<key>\<app name>`, `key` being `Software\github-updater` by default.
+#[derive(Debug, Clone)]
+pub struct RegistryVersionStore {
+    key: String,
+}
+
+impl RegistryVersionStore {
+    /// Creates a store under the `Software\github-updater` key.
+    pub fn new() -> Self {
+        Self::with_key(r"Software\github-updater")
+    }
+
+    /// Creates a store under another key of `HKEY_CURRENT_USER`, e.g.: `Software\<vendor>`.
+    pub fn with_key<S: Into<String>>(key: S) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn sub_key(&self, app_name: &str) -> Vec<u16> {
+        to_wide(&format!("{}\\{}", self.key, app_name))
+    }
+}
+
+impl Default for RegistryVersionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn to_wide(value: &str) -> Vec<u16> {
+    value.encode_utf16().chain([0]).collect()
+}
+
+#[async_trait]
+impl VersionStore for RegistryVersionStore {
+    async fn load(&self, app_name: &str) -> Result<Option<String>, UpdateError> {
+        let sub_key: Vec<u16> = self.sub_key(app_name);
+        let value_name: Vec<u16> = to_wide(VERSION_VALUE_NAME);
+        let mut size: u32 = 0;
+
+        // SAFETY: the strings are null-terminated, and the buffer given to the second call has the
+        // size, in bytes, returned by the first one.
+        unsafe {
+            let status: u32 = RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                value_name.as_ptr(),
+                RRF_RT_REG_SZ,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut size,
+            );
+            if status == ERROR_FILE_NOT_FOUND {
+                return Ok(None);
+            }
+            if status != ERROR_SUCCESS {
+                return Err(UpdateError::new(format!(
+                    "Unable to read the version from the registry: error {}.",
+                    status
+                )));
+            }
+
+            let mut buffer: Vec<u16> = vec![0; (size as usize).div_ceil(2)];
+            let status: u32 = RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                value_name.as_ptr(),
+                RRF_RT_REG_SZ,
+                std::ptr::null_mut(),
+                buffer.as_mut_ptr() as *mut _,
+                &mut size,
+            );
+            if status != ERROR_SUCCESS {
+                return Err(UpdateError::new(format!(
+                    "Unable to read the version from the registry: error {}.",
+                    status
+                )));
+            }
+            buffer.truncate(size as usize / 2);
+            while buffer.last() == Some(&0) {
+                buffer.pop();
+            }
+
+            Ok(Some(String::from_utf16_lossy(&buffer)))
+        }
+    }
+
+    async fn store(&self, app_name: &str, record: &str) -> Result<(), UpdateError> {
+        let sub_key: Vec<u16> = self.sub_key(app_name);
+        let value_name: Vec<u16> = to_wide(VERSION_VALUE_NAME);
+        let data: Vec<u16> = to_wide(record);
+
+        // SAFETY: the strings are null-terminated, and the size of `data` is given in bytes.
+        let status: u32 = unsafe {
+            RegSetKeyValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                value_name.as_ptr(),
+                REG_SZ,
+                data.as_ptr() as *const _,
+                (data.len() * 2) as u32,
+            )
+        };
+        if status != ERROR_SUCCESS {
+            return Err(UpdateError::new(format!(
+                "Unable to write the version to the registry: error {}.",
+                status
+            )));
+        }
+
+        Ok(())
+    }
+}
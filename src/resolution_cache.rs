@@ -0,0 +1,48 @@
+use crate::errors::update_error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// A release resolved by a previous process, persisted to skip the fetch of its metadata, see
+/// `GithubUpdater::with_resolution_cache`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ResolvedRelease {
+    /// Identifies the configuration the release was resolved for: the repository, the requested
+    /// release and the pattern.
+    pub(crate) key: String,
+    pub(crate) tag: Option<String>,
+    pub(crate) version: String,
+    pub(crate) asset_name: Option<String>,
+    pub(crate) asset_url: String,
+    pub(crate) size: Option<u64>,
+    /// The SHA-256 of the downloaded asset, encoded in lowercase hexadecimal.
+    pub(crate) sha256: String,
+    pub(crate) resolved_at: SystemTime,
+}
+
+impl ResolvedRelease {
+    /// Reads the release cached in `path`, or `None` if the file is missing or unreadable.
+    pub(crate) async fn load(path: &Path) -> Option<Self> {
+        let content: String = tokio::fs::read_to_string(path).await.ok()?;
+
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Returns whether the release was resolved for the configuration `key` less than `ttl` ago.
+    pub(crate) fn is_valid(&self, key: &str, ttl: Duration) -> bool {
+        self.key == key
+            && SystemTime::now()
+                .duration_since(self.resolved_at)
+                .is_ok_and(|elapsed| elapsed < ttl)
+    }
+
+    /// Writes the release to `path`.
+    pub(crate) async fn store(&self, path: &Path) -> Result<(), UpdateError> {
+        let content: String = serde_json::to_string(self).map_err(|error| {
+            UpdateError::new(format!("Unable to serialize the release: {}", error))
+        })?;
+        tokio::fs::write(path, content).await?;
+
+        Ok(())
+    }
+}
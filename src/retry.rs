@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of error which made a request fail before any response was received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetryErrorKind {
+    /// The connection to the server could not be established.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// Any other error, e.g.: the connection was closed while sending the request.
+    Other,
+}
+
+impl From<&reqwest::Error> for RetryErrorKind {
+    fn from(error: &reqwest::Error) -> Self {
+        if error.is_connect() {
+            Self::Connect
+        } else if error.is_timeout() {
+            Self::Timeout
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Retry context struct, describing the outcome of a request attempt, see
+/// `GithubUpdater::with_retry_predicate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryContext {
+    /// The number of the attempt which just completed, starting at 1.
+    pub attempt: u32,
+    /// The HTTP status code of the response. The value is null if no response was received.
+    pub status: Option<u16>,
+    /// The kind of the error. The value is null if a response was received.
+    pub error_kind: Option<RetryErrorKind>,
+    /// Whether the request was rejected by the secondary rate limits of GitHub, in which case the
+    /// retry waits at least the delay requested by GitHub, or one minute.
+    #[serde(default)]
+    pub secondary_rate_limited: bool,
+}
+
+impl RetryContext {
+    /// The retry policy used when no predicate is set: server errors (5xx), rate limiting (429),
+    /// secondary rate limits and requests which did not get any response are retried.
+    pub fn is_retryable_by_default(&self) -> bool {
+        if self.secondary_rate_limited {
+            return true;
+        }
+
+        match self.status {
+            Some(status) => status == 429 || (500..600).contains(&status),
+            None => self.error_kind.is_some(),
+        }
+    }
+}
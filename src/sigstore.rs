@@ -0,0 +1,68 @@
+use crate::errors::error_kind::ErrorKind;
+use crate::errors::update_error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Output;
+
+/// The extensions of the Sigstore bundle attached next to an asset, in order of preference, e.g.:
+/// `afetch.sigstore` for the asset `afetch`.
+pub(crate) const BUNDLE_EXTENSIONS: [&str; 2] = ["sigstore", "sigstore.json"];
+
+/// Sigstore configuration struct, describing who must have signed the assets, see
+/// `GithubUpdater::with_sigstore_verification`.
+///
+/// The verification is done by the `cosign` executable (version 2 or later), which checks the
+/// keyless signature of the bundle, its certificate chain up to the Sigstore roots and its
+/// inclusion in the transparency log.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SigstoreConfig {
+    /// The identity of the signing certificate, e.g.: the URL of the GitHub Actions workflow
+    /// `https://github.com/Asthowen/AFetch/.github/workflows/release.yml@refs/tags/v1.0.0`.
+    pub identity: String,
+    /// The OIDC issuer of the signing certificate, e.g.:
+    /// `https://token.actions.githubusercontent.com`.
+    pub issuer: String,
+    /// The path of the `cosign` executable. The value is null to search it in the `PATH`.
+    pub cosign_path: Option<PathBuf>,
+}
+
+impl SigstoreConfig {
+    /// Verifies `file` against the Sigstore `bundle` with `cosign verify-blob`.
+    pub(crate) async fn verify(&self, file: &Path, bundle: &Path) -> Result<(), UpdateError> {
+        let cosign_path: &Path = self.cosign_path.as_deref().unwrap_or(Path::new("cosign"));
+        let output: Output = tokio::process::Command::new(cosign_path)
+            .arg("verify-blob")
+            .arg("--bundle")
+            .arg(bundle)
+            .arg("--certificate-identity")
+            .arg(&self.identity)
+            .arg("--certificate-oidc-issuer")
+            .arg(&self.issuer)
+            .arg(file)
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| {
+                UpdateError::with_kind(
+                    format!(
+                        "Signature verification failed: unable to run {}: {}",
+                        cosign_path.display(),
+                        error
+                    ),
+                    ErrorKind::SignatureVerificationFailed,
+                )
+            })?;
+
+        if !output.status.success() {
+            return Err(UpdateError::with_kind(
+                format!(
+                    "Signature verification failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+                ErrorKind::SignatureVerificationFailed,
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::errors::update_error::UpdateError;
+use crate::executable_format::{ExecutableFormat, MAGIC_BYTES_LENGTH};
+use std::path::Path;
+use std::process::Output;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+
+/// The maximum duration of a smoke test, after which the process is killed and the test fails.
+const SMOKE_TEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Smoke test run on a downloaded executable, see `GithubUpdater::with_smoke_test`.
+#[derive(Debug, Clone)]
+pub(crate) struct SmokeTest {
+    pub(crate) args: Vec<String>,
+    pub(crate) expected_exit: i32,
+    pub(crate) expected_output: Option<String>,
+}
+
+impl SmokeTest {
+    /// Runs `file` with the arguments of the test, and checks its exit code and its standard
+    /// output.
+    ///
+    /// Files which are not executables of the current platform, e.g.: archives, are not run. On
+    /// Unix, the file is made executable by its owner first.
+    pub(crate) async fn run(&self, file: &Path) -> Result<(), UpdateError> {
+        let mut magic_bytes: Vec<u8> = Vec::with_capacity(MAGIC_BYTES_LENGTH);
+        tokio::fs::File::open(file)
+            .await?
+            .take(MAGIC_BYTES_LENGTH as u64)
+            .read_to_end(&mut magic_bytes)
+            .await?;
+        let format: Option<ExecutableFormat> = ExecutableFormat::detect(&magic_bytes);
+        if format.is_none() || format != ExecutableFormat::expected_for_target(std::env::consts::OS)
+        {
+            return Ok(());
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mut permissions: std::fs::Permissions =
+                tokio::fs::metadata(file).await?.permissions();
+            permissions.set_mode(permissions.mode() | 0o100);
+            tokio::fs::set_permissions(file, permissions).await?;
+        }
+
+        let output: Output = tokio::time::timeout(
+            SMOKE_TEST_TIMEOUT,
+            tokio::process::Command::new(file)
+                .args(&self.args)
+                .stdin(std::process::Stdio::null())
+                .kill_on_drop(true)
+                .output(),
+        )
+        .await
+        .map_err(|_| {
+            UpdateError::new(format!(
+                "Smoke test failed: the new file did not exit within {} seconds.",
+                SMOKE_TEST_TIMEOUT.as_secs()
+            ))
+        })?
+        .map_err(|error| {
+            UpdateError::new(format!(
+                "Smoke test failed: unable to run the new file: {}",
+                error
+            ))
+        })?;
+
+        if output.status.code() != Some(self.expected_exit) {
+            return Err(UpdateError::new(format!(
+                "Smoke test failed: the new file exited with {} instead of the code {}.",
+                output.status, self.expected_exit
+            )));
+        }
+        if let Some(expected_output) = &self.expected_output {
+            if !String::from_utf8_lossy(&output.stdout).contains(expected_output.as_str()) {
+                return Err(UpdateError::new(format!(
+                    "Smoke test failed: the output of the new file does not contain \"{}\".",
+                    expected_output
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::errors::update_error::UpdateError;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A verified download waiting to be installed, returned by `GithubUpdater::stage_download`.
+///
+/// The file lives in its own temporary directory, nothing has been written to the download path.
+/// The caller can install it by its own means, e.g.: with elevated privileges, or call `commit`.
+#[derive(Debug, Clone)]
+pub struct StagedUpdate {
+    /// The path of the downloaded file, in a dedicated temporary directory.
+    pub path: PathBuf,
+    /// The version of the downloaded file.
+    pub version: String,
+    /// The release notes of the downloaded version, if the release has a description.
+    pub release_notes: Option<String>,
+    pub(crate) target_path: PathBuf,
+    pub(crate) version_file_path: PathBuf,
+    pub(crate) version_file_content: String,
+}
+
+impl StagedUpdate {
+    pub(crate) fn new(
+        path: PathBuf,
+        version: String,
+        release_notes: Option<String>,
+        target_path: PathBuf,
+        version_file_path: PathBuf,
+        version_file_content: String,
+    ) -> Self {
+        Self {
+            path,
+            version,
+            release_notes,
+            target_path,
+            version_file_path,
+            version_file_content,
+        }
+    }
+
+    /// Returns the path where `commit` installs the file, inside the configured download path.
+    pub fn target_path(&self) -> &Path {
+        &self.target_path
+    }
+
+    /// Finishes the installation like `force_update` would: the file is moved to the download
+    /// path, replacing the previous one, the version file is written and the temporary directory
+    /// is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) if an error occurs during file operations.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let staged_update = updater_builder.stage_download().await?;
+    /// staged_update.commit().await?;
+    /// ```
+    pub async fn commit(self) -> Result<(), UpdateError> {
+        if let Some(parent) = self.target_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        move_file(&self.path, &self.target_path).await?;
+        tokio::fs::write(
+            &self.version_file_path,
+            self.version_file_content.as_bytes(),
+        )
+        .await?;
+
+        self.remove_staging_dir().await
+    }
+
+    /// Removes the temporary directory of the download, and the file if it is still in it.
+    pub(crate) async fn remove_staging_dir(&self) -> Result<(), UpdateError> {
+        if let Some(staging_dir) = self.path.parent() {
+            if staging_dir.exists() {
+                tokio::fs::remove_dir_all(staging_dir).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Moves `from` to `to`, copying the file when both paths are not on the same file system, e.g.:
+/// from the temporary directory to the download path.
+pub(crate) async fn move_file(from: &Path, to: &Path) -> Result<(), UpdateError> {
+    if tokio::fs::rename(from, to).await.is_err() {
+        tokio::fs::copy(from, to).await?;
+        tokio::fs::remove_file(from).await?;
+    }
+
+    Ok(())
+}
+
+/// Creates a new, empty, temporary directory for a staged download of `app_name`.
+pub(crate) async fn create_staging_dir(app_name: &str) -> Result<PathBuf, UpdateError> {
+    let timestamp: u128 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let staging_dir: PathBuf = std::env::temp_dir().join(format!(
+        "github-updater-{}-{}-{}",
+        app_name,
+        std::process::id(),
+        timestamp
+    ));
+    tokio::fs::create_dir(&staging_dir).await?;
+
+    Ok(staging_dir)
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Paces a download to a maximum rate, see `GithubUpdater::with_download_rate_limit`.
+///
+/// The transferred bytes are compared with those allowed since the start of the download, so that
+/// short pauses of the server do not slow the rest of the download down.
+pub(crate) struct Throttle {
+    bytes_per_second: u64,
+    started_at: Instant,
+    transferred: u64,
+}
+
+impl Throttle {
+    pub(crate) fn new(bytes_per_second: u64) -> Self {
+        Self {
+            bytes_per_second: bytes_per_second.max(1),
+            started_at: Instant::now(),
+            transferred: 0,
+        }
+    }
+
+    /// Records `length` more bytes, sleeping until the rate allows them.
+    pub(crate) async fn consume(&mut self, length: usize) {
+        self.transferred += length as u64;
+        let allowed_at: Duration =
+            Duration::from_secs_f64(self.transferred as f64 / self.bytes_per_second as f64);
+        let elapsed: Duration = self.started_at.elapsed();
+        if allowed_at > elapsed {
+            tokio::time::sleep(allowed_at - elapsed).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn consume_sleeps_until_the_rate_allows_the_bytes() {
+        let started_at: Instant = Instant::now();
+        let mut throttle: Throttle = Throttle::new(40 * 1024);
+
+        // 20 KiB at 40 KiB/s, in chunks of 4 KiB
+        for _ in 0..5 {
+            throttle.consume(4 * 1024).await;
+        }
+
+        assert_eq!(started_at.elapsed(), Duration::from_millis(500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pauses_of_the_server_are_not_slowed_down() {
+        let mut throttle: Throttle = Throttle::new(1024);
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        // The 2 KiB allowed during the pause are transferred without sleeping
+        let resumed_at: Instant = Instant::now();
+        throttle.consume(2 * 1024).await;
+        assert_eq!(resumed_at.elapsed(), Duration::ZERO);
+
+        throttle.consume(1024).await;
+        assert_eq!(resumed_at.elapsed(), Duration::from_secs(1));
+    }
+}
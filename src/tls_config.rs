@@ -0,0 +1,139 @@
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{
+    CertificateError, ClientConfig, DigitallySignedStruct, Error, RootCertStore, SignatureScheme,
+};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// The length of a SHA-256 certificate fingerprint, in bytes.
+const FINGERPRINT_LENGTH: usize = 32;
+
+/// A server certificate verifier which, on top of the usual validation against the Mozilla root
+/// certificates:
+/// * only accepts the leaf certificates whose SHA-256 is one of the pinned ones, if any;
+/// * also accepts the certificates valid for `accepted_server_name`, if set, instead of the name
+///   of the server contacted.
+#[derive(Debug)]
+struct CustomCertVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+    pinned_fingerprints: Vec<[u8; FINGERPRINT_LENGTH]>,
+    accepted_server_name: Option<ServerName<'static>>,
+}
+
+impl ServerCertVerifier for CustomCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, Error> {
+        let fingerprint: [u8; FINGERPRINT_LENGTH] = Sha256::digest(end_entity.as_ref()).into();
+        if !self.pinned_fingerprints.is_empty() && !self.pinned_fingerprints.contains(&fingerprint)
+        {
+            return Err(Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ));
+        }
+
+        let result: Result<ServerCertVerified, Error> = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        );
+        match (&result, &self.accepted_server_name) {
+            (
+                Err(Error::InvalidCertificate(
+                    CertificateError::NotValidForName
+                    | CertificateError::NotValidForNameContext { .. },
+                )),
+                Some(accepted_server_name),
+            ) => self.inner.verify_server_cert(
+                end_entity,
+                intermediates,
+                accepted_server_name,
+                ocsp_response,
+                now,
+            ),
+            _ => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// Parses a SHA-256 certificate fingerprint written in hexadecimal, the bytes being optionally
+/// separated by colons, e.g.: `AB:CD:...` as displayed by `openssl x509 -fingerprint -sha256`.
+pub(crate) fn parse_fingerprint(fingerprint: &str) -> Option<[u8; FINGERPRINT_LENGTH]> {
+    let hex: String = fingerprint
+        .trim()
+        .chars()
+        .filter(|character| *character != ':')
+        .collect();
+    if hex.len() != FINGERPRINT_LENGTH * 2 || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut bytes = [0u8; FINGERPRINT_LENGTH];
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+/// Parses the server name for which the certificates are also accepted, a DNS name or an IP
+/// address.
+pub(crate) fn parse_server_name(server_name: &str) -> Option<ServerName<'static>> {
+    ServerName::try_from(server_name.to_owned()).ok()
+}
+
+/// Creates the TLS configuration of a client only accepting the pinned certificates, if any, and
+/// also accepting the certificates valid for `accepted_server_name`, if set.
+pub(crate) fn custom_tls_config(
+    pinned_fingerprints: Vec<[u8; FINGERPRINT_LENGTH]>,
+    accepted_server_name: Option<ServerName<'static>>,
+) -> Result<ClientConfig, Error> {
+    let provider: Arc<CryptoProvider> = Arc::new(rustls::crypto::ring::default_provider());
+    let roots: RootCertStore = webpki_roots::TLS_SERVER_ROOTS.iter().cloned().collect();
+    let inner: Arc<WebPkiServerVerifier> =
+        WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+            .build()
+            .map_err(|error| Error::General(error.to_string()))?;
+
+    Ok(ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(CustomCertVerifier {
+            inner,
+            pinned_fingerprints,
+            accepted_server_name,
+        }))
+        .with_no_client_auth())
+}
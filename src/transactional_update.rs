@@ -0,0 +1,219 @@
+use crate::errors::update_error::UpdateError;
+use crate::staged_update::{move_file, StagedUpdate};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// A set of verified downloads installed all together or not at all, returned by
+/// `GithubUpdater::prepare`.
+///
+/// Transactions of several updaters can be combined with `merge`, so that all the files are
+/// downloaded and verified before any of them replaces an installed file.
+#[derive(Debug, Default)]
+pub struct TransactionalUpdate {
+    staged_updates: Vec<StagedUpdate>,
+}
+
+/// What is needed to restore a file, and its version file, replaced by an installation.
+#[derive(Debug)]
+pub(crate) struct Backup {
+    target_path: PathBuf,
+    backup_path: Option<PathBuf>,
+    version_file_path: PathBuf,
+    previous_version: Option<Vec<u8>>,
+}
+
+impl TransactionalUpdate {
+    /// Creates an empty transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&mut self, staged_update: StagedUpdate) {
+        self.staged_updates.push(staged_update);
+    }
+
+    /// Adds the downloads staged by another transaction to this one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let transaction = afetch_updater
+    ///     .prepare()
+    ///     .await?
+    ///     .merge(plugin_updater.prepare().await?);
+    /// transaction.commit().await?;
+    /// ```
+    pub fn merge(mut self, other: TransactionalUpdate) -> Self {
+        self.staged_updates.extend(other.staged_updates);
+
+        self
+    }
+
+    /// Returns the downloads staged by this transaction.
+    pub fn staged_updates(&self) -> &[StagedUpdate] {
+        &self.staged_updates
+    }
+
+    /// Installs every staged download and writes the version files.
+    ///
+    /// Each replaced file is first set aside: if any installation fails, the files installed so
+    /// far are removed and the previous files and version files are restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) with the first error which occurred during file
+    /// operations, after the rollback.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let transaction = updater_builder.prepare().await?;
+    /// transaction.commit().await?;
+    /// ```
+    pub async fn commit(self) -> Result<(), UpdateError> {
+        let mut backups: Vec<Backup> = Vec::with_capacity(self.staged_updates.len());
+        for staged_update in &self.staged_updates {
+            let result: Result<Backup, UpdateError> = install_file(
+                &staged_update.path,
+                &staged_update.target_path,
+                &staged_update.version_file_path,
+                &staged_update.version_file_content,
+                false,
+            )
+            .await;
+            match result {
+                Ok(backup) => backups.push(backup),
+                Err(error) => {
+                    for backup in backups.iter().rev() {
+                        backup.restore().await;
+                    }
+                    self.abort().await?;
+                    return Err(error);
+                }
+            }
+        }
+
+        for backup in &backups {
+            backup.remove().await?;
+        }
+
+        self.abort().await
+    }
+
+    /// Removes every staged download without installing anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` (`UpdateError` error) if an error occurs while removing the files.
+    pub async fn abort(self) -> Result<(), UpdateError> {
+        for staged_update in &self.staged_updates {
+            staged_update.remove_staging_dir().await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Installs `source` at `target_path` and writes `version_file_content` to `version_file_path`,
+/// setting aside
+/// the replaced file so that the installation can be rolled back. The source is copied if `copy`
+/// is `true`, moved otherwise. The previous state is restored if the installation fails.
+pub(crate) async fn install_file(
+    source: &Path,
+    target_path: &Path,
+    version_file_path: &Path,
+    version_file_content: &str,
+    copy: bool,
+) -> Result<Backup, UpdateError> {
+    if let Some(parent) = target_path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let previous_version: Option<Vec<u8>> = if version_file_path.exists() {
+        Some(tokio::fs::read(version_file_path).await?)
+    } else {
+        None
+    };
+    let backup_path: Option<PathBuf> = if target_path.exists() {
+        let mut backup_path: OsString = target_path.as_os_str().to_owned();
+        backup_path.push(".backup");
+        let backup_path: PathBuf = PathBuf::from(backup_path);
+        tokio::fs::rename(target_path, &backup_path).await?;
+        Some(backup_path)
+    } else {
+        None
+    };
+    let backup = Backup {
+        target_path: target_path.to_path_buf(),
+        backup_path,
+        version_file_path: version_file_path.to_path_buf(),
+        previous_version,
+    };
+
+    let result: Result<(), UpdateError> = async {
+        if copy {
+            tokio::fs::copy(source, target_path).await?;
+        } else {
+            move_file(source, target_path).await?;
+        }
+        tokio::fs::write(version_file_path, version_file_content.as_bytes()).await?;
+        Ok(())
+    }
+    .await;
+    if let Err(error) = result {
+        backup.restore().await;
+        return Err(error);
+    }
+
+    Ok(backup)
+}
+
+impl Backup {
+    /// Restores the file and the version file replaced by an installation, on a best-effort
+    /// basis: errors are logged since the rollback is already handling an error.
+    pub(crate) async fn restore(&self) {
+        if self.target_path.exists() {
+            if let Err(error) = tokio::fs::remove_file(&self.target_path).await {
+                log::warn!(
+                    "Unable to remove {} during the rollback: {}",
+                    self.target_path.display(),
+                    error
+                );
+            }
+        }
+        if let Some(backup_path) = &self.backup_path {
+            if let Err(error) = tokio::fs::rename(backup_path, &self.target_path).await {
+                log::warn!(
+                    "Unable to restore {} during the rollback: {}",
+                    self.target_path.display(),
+                    error
+                );
+            }
+        }
+
+        let result = match &self.previous_version {
+            Some(previous_version) => {
+                tokio::fs::write(&self.version_file_path, previous_version).await
+            }
+            None if self.version_file_path.exists() => {
+                tokio::fs::remove_file(&self.version_file_path).await
+            }
+            None => Ok(()),
+        };
+        if let Err(error) = result {
+            log::warn!(
+                "Unable to restore {} during the rollback: {}",
+                self.version_file_path.display(),
+                error
+            );
+        }
+    }
+
+    /// Removes the file set aside, once the installation no longer needs to be rolled back.
+    pub(crate) async fn remove(&self) -> Result<(), UpdateError> {
+        if let Some(backup_path) = &self.backup_path {
+            tokio::fs::remove_file(backup_path).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::errors::update_error::UpdateError;
+use crate::{DownloadInfos, GithubUpdater, UpdateStatus};
+use async_trait::async_trait;
+
+/// The update operations of `GithubUpdater`, as a trait that applications can depend on, e.g.:
+/// through an `Arc<dyn Updater>`, to replace the updater by a fake one in their tests.
+///
+/// The methods take `&self` so that a shared updater can be used: `GithubUpdater` runs each call
+/// on a clone of itself, which is cheap since its client is reference counted. As a consequence,
+/// the state resolved by a call, such as the fetched release, is not kept for the next one.
+///
+/// # Example
+///
+/// A fake updater always reporting an available update:
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use github_updater::errors::update_error::UpdateError;
+/// use github_updater::{DownloadInfos, UpdateStatus, Updater};
+///
+/// struct FakeUpdater;
+///
+/// #[async_trait]
+/// impl Updater for FakeUpdater {
+///     async fn check_for_update(&self) -> Result<UpdateStatus, UpdateError> {
+///         Ok(UpdateStatus {
+///             current_version: Some("1.0.0".to_owned()),
+///             latest_version: "1.1.0".to_owned(),
+///             update_available: true,
+///             check_skipped: false,
+///         })
+///     }
+///
+///     async fn update_if_needed(&self) -> Result<DownloadInfos, UpdateError> {
+///         self.force_update().await
+///     }
+///
+///     async fn force_update(&self) -> Result<DownloadInfos, UpdateError> {
+///         Ok(DownloadInfos {
+///             previous_version: Some("1.0.0".to_owned()),
+///             new_version: "1.1.0".to_owned(),
+///             has_been_updated: true,
+///             ..Default::default()
+///         })
+///     }
+/// }
+///
+/// let updater: std::sync::Arc<dyn Updater> = std::sync::Arc::new(FakeUpdater);
+/// ```
+#[async_trait]
+pub trait Updater: Send + Sync {
+    /// See `GithubUpdater::check_for_update`.
+    async fn check_for_update(&self) -> Result<UpdateStatus, UpdateError>;
+
+    /// See `GithubUpdater::update_if_needed`.
+    async fn update_if_needed(&self) -> Result<DownloadInfos, UpdateError>;
+
+    /// See `GithubUpdater::force_update`.
+    async fn force_update(&self) -> Result<DownloadInfos, UpdateError>;
+}
+
+#[async_trait]
+impl Updater for GithubUpdater {
+    async fn check_for_update(&self) -> Result<UpdateStatus, UpdateError> {
+        GithubUpdater::check_for_update(&mut self.clone()).await
+    }
+
+    async fn update_if_needed(&self) -> Result<DownloadInfos, UpdateError> {
+        GithubUpdater::update_if_needed(&mut self.clone()).await
+    }
+
+    async fn force_update(&self) -> Result<DownloadInfos, UpdateError> {
+        GithubUpdater::force_update(&mut self.clone()).await
+    }
+}
@@ -0,0 +1,35 @@
+use semver::Version;
+use std::cmp::Ordering;
+
+/// Parses a version as found in release names and tags, e.g.: `v1.2.3` or `1.2`.
+///
+/// A leading `v` is ignored and missing minor or patch components are treated as `0`. Returns
+/// `None` if the version is not a semantic version.
+pub(crate) fn parse_version(version: &str) -> Option<Version> {
+    let version: &str = version.trim();
+    let version: &str = version
+        .strip_prefix('v')
+        .or_else(|| version.strip_prefix('V'))
+        .unwrap_or(version);
+
+    Version::parse(version).ok().or_else(|| {
+        let (core, suffix) = match version.find(['-', '+']) {
+            Some(index) => version.split_at(index),
+            None => (version, ""),
+        };
+        let padding: &str = match core.matches('.').count() {
+            0 => ".0.0",
+            1 => ".0",
+            _ => return None,
+        };
+
+        Version::parse(&format!("{}{}{}", core, padding, suffix)).ok()
+    })
+}
+
+/// Compares two versions following the semantic versioning precedence.
+///
+/// Returns `None` if one of the versions is not a semantic version.
+pub(crate) fn compare_versions(version: &str, other_version: &str) -> Option<Ordering> {
+    Some(parse_version(version)?.cmp_precedence(&parse_version(other_version)?))
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+/// Version record struct, the content of the version file written next to the downloaded file.
+///
+/// By default, the version file only contains the version. With
+/// `GithubUpdater::with_json_version_record`, the whole record is written as JSON.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VersionRecord {
+    /// The installed version.
+    pub version: String,
+    /// The Git tag of the installed release. The value is null if the version file only contains
+    /// the version.
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// When the file was downloaded. The value is null if the version file only contains the
+    /// version.
+    #[serde(default)]
+    pub downloaded_at: Option<SystemTime>,
+    /// The SHA-256 of the downloaded file, encoded in lowercase hexadecimal. The value is null if
+    /// the version file only contains the version.
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// The URL from which the file was downloaded. The value is null if the version file only
+    /// contains the version.
+    #[serde(default)]
+    pub asset_url: Option<String>,
+    /// The name of the downloaded file, e.g.: the name of the asset with
+    /// `GithubUpdater::with_use_asset_filename`. The value is null if the version file only
+    /// contains the version.
+    #[serde(default)]
+    pub file_name: Option<String>,
+}
+
+impl VersionRecord {
+    /// Reads the content of a version file, either a JSON record or a plain version.
+    pub(crate) fn parse(content: &str) -> Self {
+        if content.trim_start().starts_with('{') {
+            if let Ok(record) = serde_json::from_str::<VersionRecord>(content) {
+                return record;
+            }
+        }
+
+        Self {
+            version: content.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the content of the version file, the JSON record if `json` is `true`, otherwise
+    /// the version only.
+    pub(crate) fn to_file_content(&self, json: bool) -> String {
+        if json {
+            if let Ok(content) = serde_json::to_string(self) {
+                return content;
+            }
+        }
+
+        self.version.clone()
+    }
+}
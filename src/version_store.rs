@@ -0,0 +1,212 @@
+use crate::errors::update_error::UpdateError;
+use async_trait::async_trait;
+use std::process::{Output, Stdio};
+use tokio::io::AsyncWriteExt;
+
+/// Where the version record of the installed file is kept instead of the version file of the
+/// download path, see `GithubUpdater::with_version_store`.
+///
+/// The record is the content the version file would have: the version alone, or the JSON record
+/// with `with_json_version_record`.
+///
+/// # Example
+///
+/// A store keeping the records in memory:
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use github_updater::errors::update_error::UpdateError;
+/// use github_updater::VersionStore;
+/// use std::collections::HashMap;
+/// use std::sync::Mutex;
+///
+/// #[derive(Default)]
+/// struct MemoryVersionStore(Mutex<HashMap<String, String>>);
+///
+/// #[async_trait]
+/// impl VersionStore for MemoryVersionStore {
+///     async fn load(&self, app_name: &str) -> Result<Option<String>, UpdateError> {
+///         Ok(self.0.lock().unwrap().get(app_name).cloned())
+///     }
+///
+///     async fn store(&self, app_name: &str, record: &str) -> Result<(), UpdateError> {
+///         self.0.lock().unwrap().insert(app_name.to_owned(), record.to_owned());
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait VersionStore: Send + Sync {
+    /// Returns the version record of `app_name`, or `None` if none has been stored.
+    async fn load(&self, app_name: &str) -> Result<Option<String>, UpdateError>;
+
+    /// Stores the version `record` of `app_name`, replacing the previous one.
+    async fn store(&self, app_name: &str, record: &str) -> Result<(), UpdateError>;
+}
+
+/// Version store keeping the records in the keyring of the user, with the `security` executable
+/// on macOS and the `secret-tool` executable of libsecret on the other Unix systems.
+///
+/// Each record is a generic password whose service is `service`, `github-updater` by default, and
+/// whose account is the name of the application. Windows is not supported, use
+/// `RegistryVersionStore` instead.
+#[derive(Debug, Clone)]
+pub struct KeyringVersionStore {
+    service: String,
+}
+
+impl KeyringVersionStore {
+    /// Creates a store using the `github-updater` service.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use github_updater::{GithubUpdater, KeyringVersionStore};
+    ///
+    /// let updater_builder = GithubUpdater::builder()
+    ///     .with_version_store(KeyringVersionStore::new());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_service("github-updater")
+    }
+
+    /// Creates a store using another service, e.g.: the name of the vendor of the application.
+    pub fn with_service<S: Into<String>>(service: S) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+
+    async fn run(program: &str, args: &[&str], stdin: Option<&str>) -> Result<Output, UpdateError> {
+        let mut child = tokio::process::Command::new(program)
+            .args(args)
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|error| {
+                UpdateError::new(format!(
+                    "Unable to run {} to access the keyring: {}",
+                    program, error
+                ))
+            })?;
+        if let (Some(stdin), Some(mut child_stdin)) = (stdin, child.stdin.take()) {
+            child_stdin.write_all(stdin.as_bytes()).await?;
+        }
+
+        Ok(child.wait_with_output().await?)
+    }
+
+    fn error(program: &str, output: &Output) -> UpdateError {
+        UpdateError::new(format!(
+            "Unable to access the keyring with {} ({}): {}",
+            program,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+impl Default for KeyringVersionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl VersionStore for KeyringVersionStore {
+    async fn load(&self, app_name: &str) -> Result<Option<String>, UpdateError> {
+        if cfg!(target_os = "macos") {
+            let output: Output = Self::run(
+                "security",
+                &[
+                    "find-generic-password",
+                    "-s",
+                    &self.service,
+                    "-a",
+                    app_name,
+                    "-w",
+                ],
+                None,
+            )
+            .await?;
+            // 44 is `errSecItemNotFound`
+            return match output.status.code() {
+                Some(0) => Ok(Some(
+                    String::from_utf8_lossy(&output.stdout)
+                        .trim_end_matches('\n')
+                        .to_owned(),
+                )),
+                Some(44) => Ok(None),
+                _ => Err(Self::error("security", &output)),
+            };
+        }
+        if cfg!(windows) {
+            return Err(UpdateError::new(
+                "The keyring is not supported on Windows, use RegistryVersionStore instead."
+                    .to_owned(),
+            ));
+        }
+
+        let output: Output = Self::run(
+            "secret-tool",
+            &["lookup", "service", &self.service, "account", app_name],
+            None,
+        )
+        .await?;
+        // `secret-tool` fails without any message when the secret does not exist
+        if output.status.success() {
+            Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned()))
+        } else if output.stderr.is_empty() {
+            Ok(None)
+        } else {
+            Err(Self::error("secret-tool", &output))
+        }
+    }
+
+    async fn store(&self, app_name: &str, record: &str) -> Result<(), UpdateError> {
+        let (program, output): (&str, Output) = if cfg!(target_os = "macos") {
+            let args: [&str; 8] = [
+                "add-generic-password",
+                "-U",
+                "-s",
+                &self.service,
+                "-a",
+                app_name,
+                "-w",
+                record,
+            ];
+            ("security", Self::run("security", &args, None).await?)
+        } else if cfg!(windows) {
+            return Err(UpdateError::new(
+                "The keyring is not supported on Windows, use RegistryVersionStore instead."
+                    .to_owned(),
+            ));
+        } else {
+            let label: String = format!("Version of {}", app_name);
+            let args: [&str; 6] = [
+                "store",
+                &format!("--label={}", label),
+                "service",
+                &self.service,
+                "account",
+                app_name,
+            ];
+            (
+                "secret-tool",
+                Self::run("secret-tool", &args, Some(record)).await?,
+            )
+        };
+
+        if !output.status.success() {
+            return Err(Self::error(program, &output));
+        }
+
+        Ok(())
+    }
+}
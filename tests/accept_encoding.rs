@@ -0,0 +1,40 @@
+mod common;
+
+use common::{MockClient, TempDir};
+use github_updater::{GithubUpdater, HttpRequest};
+
+fn accept_encoding(request: &HttpRequest) -> Option<&str> {
+    request
+        .headers
+        .get(reqwest::header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+}
+
+#[tokio::test]
+async fn assets_are_requested_uncompressed() {
+    let directory = TempDir::new("accept-encoding");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client.clone())
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_metadata_compression(true)
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    let requests: Vec<HttpRequest> = client.requests();
+    let (metadata, assets): (Vec<&HttpRequest>, Vec<&HttpRequest>) = requests
+        .iter()
+        .partition(|request| request.url.path().ends_with("/releases/latest"));
+    assert!(metadata
+        .iter()
+        .all(|request| accept_encoding(request) == Some("gzip")));
+    assert!(!assets.is_empty());
+    assert!(assets
+        .iter()
+        .all(|request| accept_encoding(request) == Some("identity")));
+}
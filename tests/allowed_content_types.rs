@@ -0,0 +1,54 @@
+mod common;
+
+use common::{MockResponse, MockServer, TempDir};
+use github_updater::GithubUpdater;
+use std::path::Path;
+
+fn github_updater(download_path: &Path) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_allowed_content_types(vec![
+            "application/octet-stream".to_owned(),
+            "application/x-executable".to_owned(),
+        ])
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn disallowed_content_type_is_refused() {
+    let directory = TempDir::new("content-types-refused");
+    let server = MockServer::start(|_| {
+        MockResponse::ok("notes").with_header("Content-Type", "text/plain; charset=utf-8")
+    });
+
+    let error = github_updater(directory.path())
+        .force_update_from_url(&server.url("/afetch"), "1.0.0")
+        .await
+        .unwrap_err();
+
+    assert!(error.0.contains("text/plain"));
+    assert!(!directory.path().join("afetch").exists());
+}
+
+#[tokio::test]
+async fn content_type_is_compared_without_parameters_and_case() {
+    let directory = TempDir::new("content-types-allowed");
+    let server = MockServer::start(|_| {
+        MockResponse::ok("binary").with_header("Content-Type", "Application/Octet-Stream; q=1")
+    });
+
+    github_updater(directory.path())
+        .force_update_from_url(&server.url("/afetch"), "1.0.0")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("afetch")).unwrap(),
+        "binary"
+    );
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::{MockResponse, MockServer, TempDir};
+use github_updater::errors::disallowed_host::DisallowedHost;
+use github_updater::GithubUpdater;
+use std::path::Path;
+
+fn github_updater(download_path: &Path, allowed_download_hosts: &[&str]) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_allowed_download_hosts(
+            allowed_download_hosts
+                .iter()
+                .map(|host| (*host).to_owned())
+                .collect(),
+        )
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn download_from_another_host_is_refused() {
+    let directory = TempDir::new("allowed-hosts-refused");
+    let server = MockServer::start(|_| MockResponse::ok("binary"));
+
+    let error = github_updater(directory.path(), &["objects.githubusercontent.com"])
+        .force_update_from_url(&server.url("/afetch"), "1.0.0")
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        DisallowedHost::from_error(&error).unwrap().host,
+        "127.0.0.1"
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn download_from_an_allowed_host_succeeds() {
+    let directory = TempDir::new("allowed-hosts-allowed");
+    let server = MockServer::start(|_| MockResponse::ok("binary"));
+
+    github_updater(directory.path(), &["127.0.0.1"])
+        .force_update_from_url(&server.url("/afetch"), "1.0.0")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("afetch")).unwrap(),
+        "binary"
+    );
+}
+
+#[tokio::test]
+async fn redirect_to_another_host_is_refused() {
+    let directory = TempDir::new("allowed-hosts-redirect");
+    let storage = MockServer::start(|_| MockResponse::ok("binary"));
+    // 127.0.0.1 and localhost being distinct hosts, the redirect leads to another host
+    let location: String = format!("http://localhost:{}/afetch", storage.port());
+    let server =
+        MockServer::start(move |_| MockResponse::new(302, "").with_header("Location", &location));
+
+    let error = github_updater(directory.path(), &["127.0.0.1"])
+        .force_update_from_url(&server.url("/redirect"), "1.0.0")
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        DisallowedHost::from_error(&error).unwrap().host,
+        "localhost"
+    );
+    assert!(storage.requests().is_empty());
+    assert!(!directory.path().join("afetch").exists());
+}
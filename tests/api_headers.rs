@@ -0,0 +1,69 @@
+mod common;
+
+use common::{MockClient, TempDir};
+use github_updater::{GithubUpdater, HttpRequest, DEFAULT_GITHUB_API_VERSION};
+
+fn header<'a>(request: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    request
+        .headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// Installs the latest release, with the API version `api_version` if any, and returns the
+/// requests sent.
+async fn requests_sent(name: &str, api_version: Option<&str>) -> Vec<HttpRequest> {
+    let directory = TempDir::new(name);
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+    let mut builder = GithubUpdater::builder()
+        .with_http_client(client.clone())
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path());
+    if let Some(api_version) = api_version {
+        builder = builder.with_api_version(api_version);
+    }
+
+    builder.build().unwrap().force_update().await.unwrap();
+
+    client.requests()
+}
+
+#[tokio::test]
+async fn api_requests_send_the_media_type_and_the_api_version() {
+    let requests: Vec<HttpRequest> = requests_sent("api-headers-default", None).await;
+
+    let metadata: &HttpRequest = requests
+        .iter()
+        .find(|request| request.url.path().ends_with("/releases/latest"))
+        .unwrap();
+    assert_eq!(
+        header(metadata, "accept"),
+        Some("application/vnd.github+json")
+    );
+    assert_eq!(
+        header(metadata, "x-github-api-version"),
+        Some(DEFAULT_GITHUB_API_VERSION)
+    );
+
+    let asset: &HttpRequest = requests
+        .iter()
+        .find(|request| request.url.path().contains("/releases/assets/"))
+        .unwrap();
+    assert_eq!(header(asset, "accept"), Some("application/octet-stream"));
+    assert_eq!(
+        header(asset, "x-github-api-version"),
+        Some(DEFAULT_GITHUB_API_VERSION)
+    );
+}
+
+#[tokio::test]
+async fn api_version_can_be_overridden() {
+    let requests: Vec<HttpRequest> =
+        requests_sent("api-headers-override", Some("2026-03-10")).await;
+
+    assert!(requests
+        .iter()
+        .all(|request| header(request, "x-github-api-version") == Some("2026-03-10")));
+}
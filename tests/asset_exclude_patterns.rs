@@ -0,0 +1,33 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn excluded_assets_are_not_matched() {
+    let directory = TempDir::new("asset-exclude-patterns");
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[
+            ("afetch.asc", b"signature", None),
+            ("afetch", b"binary", None),
+        ],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_asset_exclude_patterns(vec![".asc".to_owned()])
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "binary"
+    );
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{github_release_json, MockClient, MockResponse, TempDir};
+use github_updater::GithubUpdater;
+
+fn updater(client: MockClient, repository: &str, download_path: &std::path::Path) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository(repository)
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn repository_configured_in_another_case_is_accepted() {
+    let directory = TempDir::new("asset-in-release-case");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+
+    let mut updater = updater(client, "asthowen/afetch", directory.path());
+
+    updater.fetch_last_release().await.unwrap();
+}
+
+#[tokio::test]
+async fn renamed_repository_is_accepted() {
+    let directory = TempDir::new("asset-in-release-renamed");
+    // GitHub redirects the API requests of the former name to the canonical repository
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+
+    let mut updater = updater(client, "Asthowen/AFetch-old", directory.path());
+
+    updater.fetch_last_release().await.unwrap();
+}
+
+#[tokio::test]
+async fn asset_of_another_repository_is_rejected() {
+    let directory = TempDir::new("asset-in-release-other");
+    let release: String = github_release_json("1.0.0", &[("afetch", b"binary", None)]).replace(
+        "/repos/Asthowen/AFetch/releases/assets/",
+        "/repos/Other/Repository/releases/assets/",
+    );
+    let client = MockClient::new(move |_| MockResponse::ok(release.clone()));
+
+    let mut updater = updater(client, "Asthowen/AFetch", directory.path());
+    let error = updater.fetch_last_release().await.unwrap_err();
+
+    assert!(error.to_string().contains("does not belong"), "{}", error);
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{AssetMatch, GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn asset_is_selected_by_the_words_of_its_name() {
+    let directory = TempDir::new("asset-match");
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[
+            ("afetch-linux-gnu", b"afetch-linux-gnu", None),
+            ("afetch-linux-musl-debug", b"afetch-linux-musl-debug", None),
+            (
+                "afetch-linux-musl.sha256",
+                b"afetch-linux-musl.sha256",
+                None,
+            ),
+            ("afetch-linux-musl", b"afetch-linux-musl", None),
+        ],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_asset_match(AssetMatch {
+            must_contain: vec!["linux".to_owned(), "musl".to_owned()],
+            must_not_contain: vec!["debug".to_owned(), "sha256".to_owned()],
+        })
+        .with_download_path(&directory.path().join("install"))
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "afetch-linux-musl"
+    );
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn asset_of_the_release_version_is_matched() {
+    let directory = TempDir::new("asset-version-autodetect");
+    let release_file = write_local_release(
+        directory.path(),
+        "1.2.0",
+        &[
+            ("afetch-v1.1.0-linux", b"1.1.0", None),
+            ("afetch-v1.2.0-linux", b"1.2.0", None),
+        ],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}-{app_version}-linux")
+        .with_download_path(&directory.path().join("install"))
+        .with_asset_version_autodetect()
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "1.2.0"
+    );
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::{github_release_json, MockClient, MockResponse, TempDir};
+use github_updater::{GithubUpdater, HttpRequest};
+
+const STORAGE_URL: &str = "https://objects.githubusercontent.com/afetch";
+
+fn has_authorization(request: &HttpRequest) -> bool {
+    request.headers.contains_key(reqwest::header::AUTHORIZATION)
+}
+
+/// Serves a release whose asset is redirected by the API to `STORAGE_URL`.
+fn redirecting_client() -> MockClient {
+    let release: String = github_release_json("1.0.0", &[("afetch", b"binary", None)]);
+
+    MockClient::new(move |request| match request.url.host_str() {
+        Some("api.github.com") if request.url.path().ends_with("/releases/latest") => {
+            MockResponse::ok(release.clone())
+        }
+        Some("api.github.com") => MockResponse::new(302, "").with_header("location", STORAGE_URL),
+        _ => MockResponse::ok("binary"),
+    })
+}
+
+fn github_updater(client: MockClient, download_path: &std::path::Path) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_github_token("secret-token")
+        .with_manual_redirect_handling(true)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn token_is_not_sent_after_a_redirect() {
+    let directory = TempDir::new("authorization-redirect");
+    let client = redirecting_client();
+    let mut updater = github_updater(client.clone(), directory.path());
+
+    updater.force_update().await.unwrap();
+
+    let requests: Vec<HttpRequest> = client.requests();
+    assert!(requests
+        .iter()
+        .filter(|request| request.url.host_str() == Some("api.github.com"))
+        .all(has_authorization));
+    let storage_request: &HttpRequest = requests
+        .iter()
+        .find(|request| request.url.as_str() == STORAGE_URL)
+        .unwrap();
+    assert!(!has_authorization(storage_request));
+}
+
+#[tokio::test]
+async fn token_is_not_sent_to_a_rewritten_url() {
+    let directory = TempDir::new("authorization-rewritten");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client.clone())
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_github_token("secret-token")
+        .with_url_rewriter(|url| url.replace("api.github.com", "mirror.example.com"))
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    let mirror_requests: Vec<HttpRequest> = client
+        .requests()
+        .into_iter()
+        .filter(|request| request.url.host_str() == Some("mirror.example.com"))
+        .collect();
+    assert!(!mirror_requests.is_empty());
+    assert!(!mirror_requests.iter().any(has_authorization));
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, GithubUpdaterBlocking, ReleaseSource};
+
+#[test]
+fn update_is_checked_without_runtime() {
+    let directory = TempDir::new("blocking");
+    let release_file = write_local_release(directory.path(), "1.0.0", &[("afetch", b"", None)]);
+    let updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+    let mut updater = GithubUpdaterBlocking::new(updater).unwrap();
+
+    let update_status = updater.check_for_update().unwrap();
+
+    assert_eq!(update_status.latest_version, "1.0.0");
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+fn github_updater(release_file: PathBuf, download_path: &Path) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_json_version_record()
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn same_file_is_not_changed() {
+    let directory = TempDir::new("changed-same-file");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = github_updater(release_file, &directory.path().join("install"));
+
+    assert!(updater.force_update().await.unwrap().changed);
+    assert!(!updater.force_update().await.unwrap().changed);
+}
+
+#[tokio::test]
+async fn previous_file_is_compared_through_its_version_record() {
+    let directory = TempDir::new("changed-version-record");
+    let install: PathBuf = directory.path().join("install");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = github_updater(release_file, &install);
+    updater.force_update().await.unwrap();
+
+    // The recorded SHA-256 is the one compared, the previous file is not hashed again
+    let version_file: PathBuf = install.join("binary-version-afetch.txt");
+    let mut record: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&version_file).unwrap()).unwrap();
+    record["sha256"] = serde_json::Value::from("0".repeat(64));
+    std::fs::write(&version_file, record.to_string()).unwrap();
+
+    assert!(updater.force_update().await.unwrap().changed);
+}
+
+#[tokio::test]
+async fn previous_file_modified_since_its_version_record_is_hashed() {
+    let directory = TempDir::new("changed-modified-file");
+    let install: PathBuf = directory.path().join("install");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = github_updater(release_file, &install);
+    updater.force_update().await.unwrap();
+
+    // The file replaced outside of the updater no longer matches its recorded SHA-256
+    let installed_file = std::fs::File::create(install.join("afetch")).unwrap();
+    std::io::Write::write_all(&mut &installed_file, b"replaced").unwrap();
+    installed_file
+        .set_modified(SystemTime::now() + Duration::from_secs(60))
+        .unwrap();
+
+    assert!(updater.force_update().await.unwrap().changed);
+}
+
+#[tokio::test]
+async fn installed_file_without_version_record_is_hashed() {
+    let directory = TempDir::new("changed-without-record");
+    let install: PathBuf = directory.path().join("install");
+    std::fs::create_dir_all(&install).unwrap();
+    std::fs::write(install.join("afetch"), "binary").unwrap();
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = github_updater(release_file, &install);
+
+    let download_infos = updater.force_update().await.unwrap();
+    assert!(download_infos.has_been_updated);
+    assert!(!download_infos.changed);
+
+    // The asset is replaced in the release
+    std::fs::write(directory.path().join("afetch"), "new binary").unwrap();
+    assert!(updater.force_update().await.unwrap().changed);
+}
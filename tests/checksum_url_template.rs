@@ -0,0 +1,45 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, MockResponse, MockServer, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn asset_is_verified_against_the_checksum_file() {
+    let directory = TempDir::new("checksum-url-template");
+    let checksums: String = format!("{}  afetch\n", sha256_hex(b"binary"));
+    let server = MockServer::start(move |request| {
+        if request.starts_with("GET /v1.0.0/SHA256SUMS ") {
+            MockResponse::ok(checksums.clone())
+        } else {
+            MockResponse::new(404, "")
+        }
+    });
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    // The tag of the release, which the local release does not set by default
+    let release: String = std::fs::read_to_string(&release_file).unwrap().replacen(
+        '{',
+        r#"{"tag_name": "v1.0.0", "#,
+        1,
+    );
+    std::fs::write(&release_file, release).unwrap();
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_checksum_url_template(server.url("/{tag}/SHA256SUMS"))
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+    std::fs::write(directory.path().join("afetch"), "tampered").unwrap();
+
+    // The asset does not match the checksum file anymore
+    assert!(updater.force_update().await.is_err());
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "binary"
+    );
+}
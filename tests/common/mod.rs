@@ -0,0 +1,303 @@
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use github_updater::errors::update_error::UpdateError;
+use github_updater::{HttpClient, HttpRequest, HttpResponse};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_LENGTH};
+use reqwest::StatusCode;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// A temporary directory, removed when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let path: PathBuf = std::env::temp_dir().join(format!(
+            "github-updater-test-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Writes a local release `version` to `directory`, whose assets are given as `(name, content,
+/// digest)`, and returns the path of its JSON file.
+pub fn write_local_release(
+    directory: &Path,
+    version: &str,
+    assets: &[(&str, &[u8], Option<&str>)],
+) -> PathBuf {
+    let assets: Vec<serde_json::Value> = assets
+        .iter()
+        .map(|(name, content, digest)| {
+            std::fs::write(directory.join(name), content).unwrap();
+            serde_json::json!({
+                "url": "",
+                "browser_download_url": name,
+                "name": name,
+                "digest": digest,
+            })
+        })
+        .collect();
+    let release_file: PathBuf = directory.join("release.json");
+    std::fs::write(
+        &release_file,
+        serde_json::json!({
+            "name": version,
+            "body": null,
+            "published_at": null,
+            "assets": assets,
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    release_file
+}
+
+/// Returns the SHA-256 of `content`, in lowercase hexadecimal.
+pub fn sha256_hex(content: &[u8]) -> String {
+    use sha2::Digest;
+
+    sha2::Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// The URL of the release API of the repository served by `MockClient::github_release`.
+pub const RELEASES_API_URL: &str = "https://api.github.com/repos/Asthowen/AFetch/releases";
+
+/// A response of `MockClient`.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// Whether the `Content-Length` header is sent.
+    pub content_length: bool,
+}
+
+impl MockResponse {
+    pub fn new<B: Into<Vec<u8>>>(status: u16, body: B) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+            content_length: true,
+        }
+    }
+
+    pub fn ok<B: Into<Vec<u8>>>(body: B) -> Self {
+        Self::new(200, body)
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+
+        self
+    }
+
+    pub fn without_content_length(mut self) -> Self {
+        self.content_length = false;
+
+        self
+    }
+
+    /// Returns the response as sent over HTTP/1.1, closing the connection.
+    fn to_http(&self) -> Vec<u8> {
+        let reason: &str = StatusCode::from_u16(self.status)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or("Unknown");
+        let mut head: String = format!("HTTP/1.1 {} {}\r\n", self.status, reason);
+        if self.content_length {
+            head.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        }
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("Connection: close\r\n\r\n");
+
+        [head.into_bytes(), self.body.clone()].concat()
+    }
+}
+
+type Handler = dyn Fn(&HttpRequest) -> MockResponse + Send + Sync;
+
+/// An `HttpClient` answering with a handler, recording the requests it receives. Clones share
+/// the recorded requests.
+#[derive(Clone)]
+pub struct MockClient {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl MockClient {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&HttpRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        Self {
+            handler: Arc::new(handler),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Serves the latest release `version` of `Asthowen/AFetch`, whose assets are given as
+    /// `(name, content, digest)`.
+    pub fn github_release(version: &str, assets: &[(&str, &[u8], Option<&str>)]) -> Self {
+        let release: String = github_release_json(version, assets);
+        let contents: Vec<Vec<u8>> = assets
+            .iter()
+            .map(|(_, content, _)| content.to_vec())
+            .collect();
+
+        Self::new(move |request| {
+            let path: &str = request.url.path();
+            if path.ends_with("/releases/latest") {
+                return MockResponse::ok(release.clone());
+            }
+            path.rsplit_once("/releases/assets/")
+                .and_then(|(_, id)| contents.get(id.parse::<usize>().ok()?))
+                .map_or_else(
+                    || MockResponse::new(404, ""),
+                    |content| MockResponse::ok(content.clone()),
+                )
+        })
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, UpdateError> {
+        self.requests.lock().unwrap().push(request.clone());
+        let response: MockResponse = (self.handler)(&request);
+
+        let mut headers = HeaderMap::new();
+        if response.content_length {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(response.body.len()));
+        }
+        for (name, value) in &response.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+
+        Ok(HttpResponse::from_bytes(
+            StatusCode::from_u16(response.status).unwrap(),
+            headers,
+            request.url,
+            response.body,
+        ))
+    }
+}
+
+type ServerHandler = dyn Fn(&str) -> MockResponse + Send + Sync;
+
+/// An HTTP server listening on `127.0.0.1`, answering each request with a handler receiving its
+/// head, e.g.: `GET /afetch HTTP/1.1` followed by the headers, and recording these heads.
+pub struct MockServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&str) -> MockResponse + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port: u16 = listener.local_addr().unwrap().port();
+        let handler: Arc<ServerHandler> = Arc::new(handler);
+        let requests: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded_requests = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                while reader.read_line(&mut request).unwrap_or(0) > 2
+                    && !request.ends_with("\r\n\r\n")
+                {}
+                let response: MockResponse = handler(&request);
+                recorded_requests.lock().unwrap().push(request);
+                let _ = stream.write_all(&response.to_http());
+            }
+        });
+
+        Self { port, requests }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the URL of `path` on the server, e.g.: `/afetch`.
+    pub fn url(&self, path: &str) -> String {
+        format!("http://127.0.0.1:{}{}", self.port, path)
+    }
+
+    /// Returns the heads of the requests received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Returns the JSON of the release `version` of `Asthowen/AFetch` in the GitHub API, whose assets
+/// are given as `(name, content, digest)`.
+pub fn github_release_json(version: &str, assets: &[(&str, &[u8], Option<&str>)]) -> String {
+    let assets: Vec<serde_json::Value> = assets
+        .iter()
+        .enumerate()
+        .map(|(id, (name, content, digest))| {
+            serde_json::json!({
+                "url": format!("{}/assets/{}", RELEASES_API_URL, id),
+                "browser_download_url": format!(
+                    "https://github.com/Asthowen/AFetch/releases/download/{}/{}",
+                    version, name
+                ),
+                "name": name,
+                "size": content.len(),
+                "digest": digest,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "id": 1,
+        "url": format!("{}/1", RELEASES_API_URL),
+        "name": version,
+        "tag_name": format!("v{}", version),
+        "body": null,
+        "published_at": null,
+        "assets": assets,
+    })
+    .to_string()
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use async_compression::tokio::write::{GzipEncoder, XzEncoder, ZstdEncoder};
+use common::{write_local_release, TempDir};
+use github_updater::{Compression, GithubUpdater, ReleaseSource};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+async fn compress<E: AsyncWrite + Unpin>(mut encoder: E, into_inner: fn(E) -> Vec<u8>) -> Vec<u8> {
+    encoder.write_all(b"binary").await.unwrap();
+    encoder.shutdown().await.unwrap();
+
+    into_inner(encoder)
+}
+
+/// Installs a release whose asset is `compressed`, decompressing it with `compression`.
+async fn install_decompressed(name: &str, compressed: &[u8], compression: Compression) -> String {
+    let directory = TempDir::new(name);
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[("afetch.compressed", compressed, None)],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}.compressed")
+        .with_download_path(&directory.path().join("install"))
+        .with_store_decompressed(compression)
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    std::fs::read_to_string(directory.path().join("install/afetch")).unwrap()
+}
+
+#[tokio::test]
+async fn gzip_round_trip() {
+    let compressed: Vec<u8> = compress(GzipEncoder::new(Vec::new()), GzipEncoder::into_inner).await;
+
+    assert_eq!(
+        install_decompressed("compression-gzip", &compressed, Compression::Gzip).await,
+        "binary"
+    );
+}
+
+#[tokio::test]
+async fn zstd_round_trip() {
+    let compressed: Vec<u8> = compress(ZstdEncoder::new(Vec::new()), ZstdEncoder::into_inner).await;
+
+    assert_eq!(
+        install_decompressed("compression-zstd", &compressed, Compression::Zstd).await,
+        "binary"
+    );
+}
+
+#[tokio::test]
+async fn xz_round_trip() {
+    let compressed: Vec<u8> = compress(XzEncoder::new(Vec::new()), XzEncoder::into_inner).await;
+
+    assert_eq!(
+        install_decompressed("compression-xz", &compressed, Compression::Xz).await,
+        "binary"
+    );
+}
+
+#[tokio::test]
+async fn uncompressed_asset_is_refused() {
+    let directory = TempDir::new("compression-uncompressed");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch.gz", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}.gz")
+        .with_download_path(&directory.path().join("install"))
+        .with_store_decompressed(Compression::Gzip)
+        .build()
+        .unwrap();
+
+    assert!(updater.force_update().await.is_err());
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::{github_release_json, MockClient, MockResponse, TempDir};
+use github_updater::{GithubUpdater, HttpRequest};
+
+const LAST_MODIFIED: &str = "Wed, 14 Oct 2026 08:00:00 GMT";
+
+#[tokio::test]
+async fn release_is_revalidated_with_last_modified() {
+    let directory = TempDir::new("conditional-last-modified");
+    let release: String = github_release_json("1.0.0", &[("afetch", b"binary", None)]);
+    let client = MockClient::new(move |request| {
+        match request.headers.get(reqwest::header::IF_MODIFIED_SINCE) {
+            Some(if_modified_since) if if_modified_since == LAST_MODIFIED => {
+                MockResponse::new(304, "")
+            }
+            _ => MockResponse::ok(release.clone()).with_header("last-modified", LAST_MODIFIED),
+        }
+    });
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client.clone())
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        updater.check_for_update().await.unwrap().latest_version,
+        "1.0.0"
+    );
+    assert_eq!(
+        updater.check_for_update().await.unwrap().latest_version,
+        "1.0.0"
+    );
+
+    let requests: Vec<HttpRequest> = client.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[0]
+        .headers
+        .contains_key(reqwest::header::IF_MODIFIED_SINCE));
+    assert_eq!(
+        requests[1]
+            .headers
+            .get(reqwest::header::IF_MODIFIED_SINCE)
+            .unwrap(),
+        LAST_MODIFIED
+    );
+    assert!(!requests[1]
+        .headers
+        .contains_key(reqwest::header::IF_NONE_MATCH));
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::Path;
+
+async fn update_available(directory: &Path, installed_sha256: &str) -> bool {
+    let release_file = write_local_release(
+        directory,
+        "1.0.0",
+        &[("afetch", b"", Some(&format!("sha256:{}", "1".repeat(64))))],
+    );
+    std::fs::write(
+        directory.join("binary-version-afetch.txt"),
+        format!(
+            r#"{{"version": "1.0.0", "sha256": "{}"}}"#,
+            installed_sha256
+        ),
+    )
+    .unwrap();
+
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory)
+        .with_json_version_record()
+        .with_content_based_update_check()
+        .build()
+        .unwrap()
+        .check_for_update()
+        .await
+        .unwrap()
+        .update_available
+}
+
+#[tokio::test]
+async fn replaced_asset_of_the_same_version_is_an_update() {
+    let directory = TempDir::new("content-check-replaced");
+
+    assert!(update_available(directory.path(), &"0".repeat(64)).await);
+}
+
+#[tokio::test]
+async fn same_asset_is_not_an_update() {
+    let directory = TempDir::new("content-check-same");
+
+    assert!(!update_available(directory.path(), &"1".repeat(64)).await);
+}
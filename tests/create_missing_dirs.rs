@@ -0,0 +1,38 @@
+mod common;
+
+use common::TempDir;
+use github_updater::GithubUpdater;
+use std::path::{Path, PathBuf};
+
+fn github_updater(download_path: &Path, create_missing_dirs: bool) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_create_missing_dirs(create_missing_dirs)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn missing_download_path_is_created_unless_disabled() {
+    let directory = TempDir::new("create-missing-dirs");
+    let asset: PathBuf = directory.path().join("asset");
+    std::fs::write(&asset, "binary").unwrap();
+    let url: String = format!("file://{}", asset.display());
+    let install: PathBuf = directory.path().join("install");
+
+    assert!(github_updater(&install, false)
+        .force_update_from_url(&url, "1.0.0")
+        .await
+        .is_err());
+    assert!(!install.exists());
+
+    github_updater(&install, true)
+        .force_update_from_url(&url, "1.0.0")
+        .await
+        .unwrap();
+    assert!(install.join("afetch").exists());
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{sha256_hex, MockClient, TempDir};
+use github_updater::GithubUpdater;
+
+#[tokio::test]
+async fn configuration_is_detected_from_the_release() {
+    let directory = TempDir::new("detect-from-repo-url");
+    let platform: String = format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS);
+    let asset_name: String = format!("AFetch-v1.0.0-{}", platform);
+    let checksum: String = format!("{}  {}", sha256_hex(b"binary"), asset_name);
+    // A release publishing a build for the current platform, and one for another platform
+    let client = MockClient::github_release(
+        "1.0.0",
+        &[
+            ("AFetch-v1.0.0-sparc-solaris", b"other", None),
+            (&format!("{}.sha256", asset_name), checksum.as_bytes(), None),
+            (&asset_name, b"binary", None),
+        ],
+    );
+
+    let updater = GithubUpdater::builder()
+        .with_http_client(client)
+        .detect_from_repo_url("https://github.com/Asthowen/AFetch")
+        .await
+        .unwrap();
+    assert_eq!(updater.app_name(), Some("AFetch"));
+    assert_eq!(
+        updater.pattern(),
+        Some(format!("AFetch-{{app_version}}-{}", platform).as_str())
+    );
+
+    let mut updater = updater
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("AFetch")).unwrap(),
+        "binary"
+    );
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::{MockResponse, MockServer, TempDir};
+use github_updater::GithubUpdater;
+use std::path::Path;
+
+fn github_updater(download_path: &Path, expected_size: u64) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_expected_size(expected_size)
+        .build()
+        .unwrap()
+}
+
+/// Serves `hello`, with a `Content-Length` header on `/sized` only.
+fn start_server() -> MockServer {
+    MockServer::start(|request| {
+        let response = MockResponse::ok("hello");
+        if request.starts_with("GET /sized ") {
+            response
+        } else {
+            response.without_content_length()
+        }
+    })
+}
+
+#[tokio::test]
+async fn expected_size_is_verified_without_content_length() {
+    let directory = TempDir::new("expected-size-bare");
+    let server = start_server();
+    let url: String = server.url("/bare");
+
+    let result = github_updater(directory.path(), 5)
+        .force_update_from_url(&url, "1.0.0")
+        .await;
+    assert!(result.is_ok());
+    let result = github_updater(directory.path(), 6)
+        .force_update_from_url(&url, "1.0.0")
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn expected_size_must_match_content_length() {
+    let directory = TempDir::new("expected-size-sized");
+    let server = start_server();
+    let url: String = server.url("/sized");
+
+    let result = github_updater(directory.path(), 5)
+        .force_update_from_url(&url, "1.0.0")
+        .await;
+    assert!(result.is_ok());
+    let result = github_updater(directory.path(), 4)
+        .force_update_from_url(&url, "1.0.0")
+        .await;
+    assert!(result.is_err());
+}
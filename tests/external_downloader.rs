@@ -0,0 +1,88 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::{MockClient, TempDir};
+use github_updater::{ExternalDownloader, GithubUpdater};
+use reqwest::Method;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+/// A downloader recording its arguments and its configuration file into `directory`, then
+/// writing a fixed content.
+fn recording_downloader(directory: &Path) -> ExternalDownloader {
+    let script: String = format!(
+        "echo \"$0 $*\" > '{0}/args'; cp -p \"$1\" '{0}/config'; printf binary > \"$0\"",
+        directory.display()
+    );
+
+    ExternalDownloader {
+        program: "sh".into(),
+        args: vec![
+            "-c".to_owned(),
+            script,
+            "{output}".to_owned(),
+            "{config}".to_owned(),
+        ],
+        config_line: "header = \"{header}\"".to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn headers_are_passed_through_a_private_config_file() {
+    let directory = TempDir::new("external-downloader-config");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client.clone())
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_github_token("secret-token")
+        .with_external_downloader(recording_downloader(directory.path()))
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    let args: String = std::fs::read_to_string(directory.path().join("args")).unwrap();
+    assert!(!args.contains("secret-token"));
+    let config: String = std::fs::read_to_string(directory.path().join("config")).unwrap();
+    assert!(config.contains("header = \"Authorization: token secret-token\""));
+    let mode: u32 = std::fs::metadata(directory.path().join("config"))
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o600);
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "binary"
+    );
+
+    let asset_requests: Vec<Method> = client
+        .requests()
+        .into_iter()
+        .filter(|request| request.url.path().contains("/releases/assets/"))
+        .map(|request| request.method)
+        .collect();
+    assert_eq!(asset_requests, vec![Method::HEAD]);
+}
+
+#[tokio::test]
+async fn content_type_is_checked_before_the_download() {
+    let directory = TempDir::new("external-downloader-content-type");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_allowed_content_types(vec!["application/octet-stream".to_owned()])
+        .with_external_downloader(recording_downloader(directory.path()))
+        .build()
+        .unwrap();
+
+    assert!(updater.force_update().await.is_err());
+    assert!(!directory.path().join("args").exists());
+}
@@ -0,0 +1,68 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::{MockClient, TempDir};
+use github_updater::GithubUpdater;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+
+fn owner(path: &Path) -> (u32, u32) {
+    let metadata = std::fs::metadata(path).unwrap();
+
+    (metadata.uid(), metadata.gid())
+}
+
+/// Installs the latest release with the owner `file_owner`, then returns the owners of the
+/// installed file and of its version file.
+async fn install_with_owner(directory: &TempDir, file_owner: (u32, u32)) -> [(u32, u32); 2] {
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_file_owner(file_owner.0, file_owner.1)
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    [
+        owner(&directory.path().join("afetch")),
+        owner(&directory.path().join("binary-version-afetch.txt")),
+    ]
+}
+
+#[tokio::test]
+async fn owner_is_applied_to_the_installed_files() {
+    let directory = TempDir::new("file-owner");
+    let current_owner: (u32, u32) = owner(directory.path());
+
+    // Only root may give the files to another user, otherwise the current owner is kept.
+    let file_owner: (u32, u32) = if current_owner.0 == 0 {
+        (1001, 1001)
+    } else {
+        current_owner
+    };
+
+    assert_eq!(
+        install_with_owner(&directory, file_owner).await,
+        [file_owner; 2]
+    );
+}
+
+#[tokio::test]
+async fn failing_change_of_owner_does_not_fail_the_update() {
+    let directory = TempDir::new("file-owner-failing");
+    let current_owner: (u32, u32) = owner(directory.path());
+    if current_owner.0 == 0 {
+        return;
+    }
+
+    assert_eq!(
+        install_with_owner(&directory, (0, 0)).await,
+        [current_owner; 2]
+    );
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn shortened_targets_are_matched() {
+    for (rust_target, asset_name) in [
+        (
+            "x86_64-unknown-linux-gnu",
+            "afetch-x86_64-unknown-linux-gnu",
+        ),
+        ("x86_64-unknown-linux-gnu", "afetch-x86_64-linux-gnu"),
+        ("x86_64-unknown-linux-gnu", "afetch-x86_64-linux"),
+        ("aarch64-apple-darwin", "afetch-aarch64-darwin"),
+        ("x86_64-pc-windows-msvc", "afetch-x86_64-windows"),
+        ("aarch64-linux-android", "afetch-aarch64-linux"),
+    ] {
+        let directory = TempDir::new("flexible-target");
+        // Each asset contains its own name
+        let release_file = write_local_release(
+            directory.path(),
+            "1.0.0",
+            &[
+                ("afetch-i686-linux", b"afetch-i686-linux", None),
+                (asset_name, asset_name.as_bytes(), None),
+            ],
+        );
+        let mut updater = GithubUpdater::builder()
+            .with_initialized_reqwest_client()
+            .with_app_name("afetch")
+            .with_release_source(ReleaseSource::LocalJson(release_file))
+            .with_release_file_name_pattern("{app_name}-{rust_target}")
+            .with_rust_target(rust_target)
+            .with_flexible_target_matching()
+            .with_download_path(&directory.path().join("install"))
+            .build()
+            .unwrap();
+
+        updater.force_update().await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(directory.path().join("install").join("afetch")).unwrap(),
+            asset_name
+        );
+    }
+}
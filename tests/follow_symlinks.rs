@@ -0,0 +1,38 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::TempDir;
+use github_updater::GithubUpdater;
+use std::path::{Path, PathBuf};
+
+fn github_updater(download_path: &Path, follow_symlinks: bool) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_follow_symlinks(follow_symlinks)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn symbolic_link_of_the_download_path_is_resolved() {
+    let directory = TempDir::new("follow-symlinks");
+    let target: PathBuf = directory.path().join("bin");
+    let link: PathBuf = directory.path().join("link");
+    std::fs::create_dir_all(&target).unwrap();
+    std::os::unix::fs::symlink(&target, &link).unwrap();
+
+    let target: PathBuf = target.canonicalize().unwrap();
+    assert_eq!(
+        github_updater(&link, true).download_path(),
+        Some(target.as_path())
+    );
+    assert_eq!(
+        github_updater(&link, false).download_path(),
+        Some(link.as_path())
+    );
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use common::{MockClient, TempDir, RELEASES_API_URL};
+use github_updater::GithubUpdater;
+
+#[tokio::test]
+async fn release_is_downloaded_through_the_http_client() {
+    let directory = TempDir::new("http-client");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client.clone())
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("afetch")).unwrap(),
+        "binary"
+    );
+    assert_eq!(
+        client.requests()[0].url.as_str(),
+        format!("{}/latest", RELEASES_API_URL)
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use async_trait::async_trait;
+use common::{write_local_release, TempDir};
+use github_updater::errors::update_error::UpdateError;
+use github_updater::{GithubUpdater, ReleaseSource, VersionStore};
+
+/// A store refusing every write, like a policy blocking the version file.
+struct ReadOnlyVersionStore;
+
+#[async_trait]
+impl VersionStore for ReadOnlyVersionStore {
+    async fn load(&self, _app_name: &str) -> Result<Option<String>, UpdateError> {
+        Ok(None)
+    }
+
+    async fn store(&self, _app_name: &str, _record: &str) -> Result<(), UpdateError> {
+        Err(UpdateError::new("Access denied.".to_owned()))
+    }
+}
+
+#[tokio::test]
+async fn update_completes_despite_a_version_file_error() {
+    let directory = TempDir::new("ignore-version-file-errors");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_version_store(ReadOnlyVersionStore);
+
+    assert!(updater
+        .clone()
+        .build()
+        .unwrap()
+        .force_update()
+        .await
+        .is_err());
+
+    let mut updater = updater.with_ignore_version_file_errors().build().unwrap();
+    assert!(updater.force_update().await.unwrap().has_been_updated);
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "binary"
+    );
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::{github_release_json, MockClient, MockResponse, TempDir};
+use github_updater::errors::update_error::UpdateError;
+use github_updater::GithubUpdater;
+
+/// Serves a release whose asset is sent with the header `name` set to `value`.
+fn client_with_header(name: &'static str, value: &'static str) -> MockClient {
+    let release: String = github_release_json("1.0.0", &[("afetch", b"binary", None)]);
+
+    MockClient::new(move |request| {
+        if request.url.path().ends_with("/releases/latest") {
+            MockResponse::ok(release.clone())
+        } else {
+            MockResponse::ok("binary").with_header(name, value)
+        }
+    })
+}
+
+async fn install(
+    directory: &TempDir,
+    client: MockClient,
+    ignore_malformed: bool,
+) -> Result<(), UpdateError> {
+    let mut builder = GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path());
+    if ignore_malformed {
+        builder = builder.with_ignore_malformed_digest_header();
+    }
+
+    builder.build().unwrap().force_update().await.map(|_| ())
+}
+
+#[tokio::test]
+async fn malformed_headers_fail_the_download() {
+    for (name, value) in [
+        ("content-digest", "sha-256=:bogus!:"),
+        ("repr-digest", "sha-512=:dHJ1bmNhdGVk:"),
+        ("content-md5", "bogus"),
+    ] {
+        let directory = TempDir::new(&format!("malformed-{}", name));
+
+        let error: UpdateError = install(&directory, client_with_header(name, value), false)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.0,
+            format!("Server provided an invalid {} header.", name)
+        );
+    }
+}
+
+#[tokio::test]
+async fn malformed_headers_can_be_ignored() {
+    for (name, value) in [
+        ("content-digest", "sha-256=:bogus!:"),
+        ("content-md5", "bogus"),
+    ] {
+        let directory = TempDir::new(&format!("malformed-ignored-{}", name));
+
+        install(&directory, client_with_header(name, value), true)
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(directory.path().join("afetch")).unwrap(),
+            "binary"
+        );
+    }
+}
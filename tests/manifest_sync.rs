@@ -0,0 +1,51 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::{Path, PathBuf};
+
+/// Publishes a release whose manifest lists `files`, given as `(name, content)`, under `data/`.
+fn publish(directory: &Path, files: &[(&str, &[u8])]) -> PathBuf {
+    let entries: Vec<serde_json::Value> = files
+        .iter()
+        .map(|(name, content)| {
+            serde_json::json!({
+                "path": format!("data/{}", name),
+                "sha256": sha256_hex(content),
+            })
+        })
+        .collect();
+    let manifest: String = serde_json::json!({ "files": entries }).to_string();
+    let mut assets: Vec<(&str, &[u8], Option<&str>)> =
+        vec![("manifest.json", manifest.as_bytes(), None)];
+    assets.extend(files.iter().map(|(name, content)| (*name, *content, None)));
+
+    write_local_release(directory, "1.0.0", &assets)
+}
+
+#[tokio::test]
+async fn only_changed_files_are_synchronized() {
+    let directory = TempDir::new("manifest-sync");
+    let install: PathBuf = directory.path().join("install");
+    let release_file: PathBuf = publish(directory.path(), &[("a.txt", b"a"), ("b.txt", b"b")]);
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&install)
+        .with_manifest_sync("manifest.json")
+        .build()
+        .unwrap();
+
+    let report = updater.sync_manifest().await.unwrap();
+    assert_eq!(report.downloaded, ["data/a.txt", "data/b.txt"]);
+
+    // The next release changes `b.txt` into `c.txt`
+    publish(directory.path(), &[("a.txt", b"a"), ("c.txt", b"c")]);
+    let report = updater.sync_manifest().await.unwrap();
+    assert_eq!(report.downloaded, ["data/c.txt"]);
+    assert_eq!(report.removed, ["data/b.txt"]);
+    assert_eq!(report.unchanged, 1);
+    assert!(!install.join("data/b.txt").exists());
+}
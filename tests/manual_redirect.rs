@@ -0,0 +1,38 @@
+mod common;
+
+use common::{MockResponse, MockServer, TempDir};
+use github_updater::GithubUpdater;
+
+#[tokio::test]
+async fn redirects_are_followed_by_the_crate() {
+    let directory = TempDir::new("manual-redirect");
+    let storage = MockServer::start(|_| MockResponse::ok("hello"));
+    // 127.0.0.1 and localhost being distinct hosts, the redirect leads to another host
+    let location: String = format!("http://localhost:{}/signed", storage.port());
+    let redirect =
+        MockServer::start(move |_| MockResponse::new(302, "").with_header("Location", &location));
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_manual_redirect_handling(true)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_github_token("secret-token")
+        .build()
+        .unwrap();
+
+    updater
+        .force_update_from_url(&redirect.url("/asset"), "1.0.0")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read(directory.path().join("afetch")).unwrap(),
+        b"hello"
+    );
+    let storage_requests: Vec<String> = storage.requests();
+    assert_eq!(storage_requests.len(), 1);
+    assert!(storage_requests[0].starts_with("GET /signed "));
+    assert!(!storage_requests[0].to_lowercase().contains("authorization"));
+}
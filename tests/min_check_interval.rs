@@ -0,0 +1,32 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn check_made_too_soon_is_skipped() {
+    let directory = TempDir::new("min-check-interval");
+    let release_file = write_local_release(directory.path(), "1.0.0", &[("afetch", b"", None)]);
+    std::fs::write(directory.path().join("binary-version-afetch.txt"), "1.0.0").unwrap();
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_min_check_interval(Duration::from_secs(24 * 60 * 60))
+        .build()
+        .unwrap();
+
+    assert!(!updater.check_for_update().await.unwrap().check_skipped);
+
+    // A new release is published, but the previous check is too recent
+    write_local_release(directory.path(), "2.0.0", &[("afetch", b"", None)]);
+    let update_status = updater.check_for_update().await.unwrap();
+    assert!(update_status.check_skipped);
+    assert!(!update_status.update_available);
+
+    let mut updater = updater.with_force_check(true).build().unwrap();
+    assert!(updater.check_for_update().await.unwrap().update_available);
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn parts_are_joined_and_verified() {
+    let directory = TempDir::new("multipart-asset");
+    let checksums: String = format!("{}  afetch\n", sha256_hex(b"binary"));
+    let part_digest = |content: &[u8]| format!("sha256:{}", sha256_hex(content));
+    let (part1_digest, part2_digest) = (part_digest(b"bin"), part_digest(b"ary"));
+    // The release publishes the parts "bin" and "ary" of the file "binary"
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[
+            ("afetch.part2", b"ary", Some(&part2_digest)),
+            ("afetch.part1", b"bin", Some(&part1_digest)),
+            ("SHA256SUMS", checksums.as_bytes(), None),
+        ],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_multipart_asset("{app_name}", None)
+        .with_download_path(&directory.path().join("install"))
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "binary"
+    );
+
+    // A corrupted part is refused
+    std::fs::write(directory.path().join("afetch.part2"), "arx").unwrap();
+    assert!(updater.force_update().await.is_err());
+}
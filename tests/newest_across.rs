@@ -0,0 +1,40 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::{Path, PathBuf};
+
+fn github_updater(directory: &Path, plugin: &str) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("plugin")
+        .with_release_source(ReleaseSource::LocalJson(
+            directory.join(plugin).join("release.json"),
+        ))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn newest_release_is_found_despite_an_error() {
+    let directory = TempDir::new("newest-across");
+    for (plugin, version) in [("first", "1.2.0"), ("second", "1.10.0")] {
+        let plugin_directory: PathBuf = directory.path().join(plugin);
+        std::fs::create_dir_all(&plugin_directory).unwrap();
+        write_local_release(&plugin_directory, version, &[("plugin", b"", None)]);
+    }
+
+    let updaters = [
+        github_updater(directory.path(), "first"),
+        github_updater(directory.path(), "second"),
+        github_updater(directory.path(), "unavailable"),
+    ];
+    let newest_release = GithubUpdater::newest_across(&updaters).await.unwrap();
+
+    assert_eq!(newest_release.index, 1);
+    assert_eq!(newest_release.version, "1.10.0");
+    assert_eq!(newest_release.errors.len(), 1);
+    assert_eq!(newest_release.errors[0].0, 2);
+}
@@ -0,0 +1,42 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource, UpdateEvent};
+use std::io::{BufRead, BufReader};
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+
+#[tokio::test]
+async fn update_event_is_sent_to_the_socket() {
+    let directory = TempDir::new("notify-socket");
+    let release_file = write_local_release(directory.path(), "1.0.0", &[("afetch", b"", None)]);
+    // The supervisor listens on the socket
+    let socket: PathBuf = directory.path().join("update.sock");
+    let listener = UnixListener::bind(&socket).unwrap();
+    let supervisor = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        serde_json::from_str::<UpdateEvent>(&line).unwrap()
+    });
+
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_notify_socket(&socket)
+        .build()
+        .unwrap();
+    updater.force_update().await.unwrap();
+
+    let update_event: UpdateEvent = supervisor.join().unwrap();
+    assert_eq!(update_event.new_version, "1.0.0");
+    assert_eq!(
+        update_event.path,
+        directory.path().join("install").join("afetch")
+    );
+}
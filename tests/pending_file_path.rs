@@ -0,0 +1,23 @@
+mod common;
+
+use common::TempDir;
+use github_updater::GithubUpdater;
+
+#[test]
+fn pending_file_is_staged_next_to_the_installed_file() {
+    let directory = TempDir::new("pending-file-path");
+    let updater = GithubUpdater::builder()
+        .with_app_name("afetch")
+        .with_download_path(&directory.path())
+        .without_erase_previous_file();
+    assert_eq!(
+        updater.pending_file_path(),
+        Some(directory.path().join("afetch"))
+    );
+
+    std::fs::write(directory.path().join("afetch"), "binary").unwrap();
+    assert_eq!(
+        updater.pending_file_path(),
+        Some(directory.path().join("new_afetch"))
+    );
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn declined_update_is_not_downloaded() {
+    let directory = TempDir::new("pre-download-confirm");
+    let release_file =
+        write_local_release(directory.path(), "2.0.0", &[("afetch", b"binary", None)]);
+    let new_version: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    let confirmed_version = Arc::clone(&new_version);
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_pre_download_confirm(move |info| {
+            *confirmed_version.lock().unwrap() = Some(info.new_version.clone());
+            false
+        })
+        .build()
+        .unwrap();
+
+    let download_infos = updater.update_if_needed().await.unwrap();
+
+    assert_eq!(new_version.lock().unwrap().as_deref(), Some("2.0.0"));
+    assert!(download_infos.update_declined);
+    assert!(!download_infos.has_been_updated);
+    assert!(!directory.path().join("install/afetch").exists());
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::Path;
+
+fn updater(release_file: &Path, download_path: &Path) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file.to_path_buf()))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn download_matching_the_api_digest_is_installed() {
+    let directory = TempDir::new("api-digest-match");
+    let digest: String = format!("sha256:{}", sha256_hex(b"binary"));
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[("afetch", b"binary", Some(&digest))],
+    );
+
+    let mut updater = updater(&release_file, &directory.path().join("install"));
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read(directory.path().join("install/afetch")).unwrap(),
+        b"binary"
+    );
+}
+
+#[tokio::test]
+async fn download_not_matching_the_api_digest_is_refused() {
+    let directory = TempDir::new("api-digest-mismatch");
+    let digest: String = format!("sha256:{}", sha256_hex(b"another binary"));
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[("afetch", b"binary", Some(&digest))],
+    );
+
+    let mut updater = updater(&release_file, &directory.path().join("install"));
+    let error = updater.force_update().await.unwrap_err();
+
+    assert!(error.to_string().contains("SHA-256"), "{}", error);
+    assert!(!directory.path().join("install/afetch").exists());
+}
+
+#[tokio::test]
+async fn download_not_matching_the_checksum_asset_is_refused() {
+    let directory = TempDir::new("checksum-asset-mismatch");
+    let checksums: String = format!("{}  afetch\n", sha256_hex(b"another binary"));
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[
+            ("afetch", b"binary", None),
+            ("SHA256SUMS", checksums.as_bytes(), None),
+        ],
+    );
+
+    let mut updater = updater(&release_file, &directory.path().join("install"));
+
+    assert!(updater.force_update().await.is_err());
+    assert!(!directory.path().join("install/afetch").exists());
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::PathBuf;
+
+#[tokio::test]
+async fn corrupt_download_is_quarantined() {
+    let directory = TempDir::new("quarantine-corrupt-downloads");
+    let quarantine: PathBuf = directory.path().join("quarantine");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    // The release announces a larger asset than the one served
+    let mut release: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&release_file).unwrap()).unwrap();
+    release["assets"][0]["size"] = serde_json::Value::from(100);
+    std::fs::write(&release_file, release.to_string()).unwrap();
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_quarantine_corrupt_downloads(&quarantine)
+        .build()
+        .unwrap();
+
+    assert!(updater.force_update().await.is_err());
+    assert!(!directory.path().join("install/afetch").exists());
+
+    let mut quarantined: Vec<String> = std::fs::read_dir(&quarantine)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    quarantined.sort();
+    assert!(quarantined[0].starts_with("afetch-") && quarantined[0].ends_with(".corrupt"));
+    assert_eq!(quarantined[1], format!("{}.json", quarantined[0]));
+    let note: String = std::fs::read_to_string(quarantine.join(&quarantined[1])).unwrap();
+    assert!(note.contains(r#""expected_length": 100"#));
+}
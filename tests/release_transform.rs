@@ -0,0 +1,39 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn transformed_release_is_used() {
+    let directory = TempDir::new("release-transform");
+    let release_file = write_local_release(
+        directory.path(),
+        "AFetch 2.0.0",
+        &[
+            ("afetch-debug", b"debug binary", None),
+            ("afetch", b"binary", None),
+        ],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_release_transform(|release| {
+            release.name = release.name.trim_start_matches("AFetch ").to_owned();
+            release
+                .assets
+                .retain(|asset| !asset.name.ends_with("-debug"));
+        })
+        .build()
+        .unwrap();
+
+    let download_infos = updater.force_update().await.unwrap();
+
+    assert_eq!(download_infos.new_version, "2.0.0");
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "binary"
+    );
+}
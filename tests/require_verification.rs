@@ -0,0 +1,104 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, MockClient, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use reqwest::Url;
+
+fn github_updater(client: MockClient, download_path: &std::path::Path) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_require_verification()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn build_fails_without_verification_mechanism() {
+    let result = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson("release.json".into()))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&std::env::temp_dir())
+        .with_require_verification()
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn local_release_with_checksum_template_is_verified() {
+    let directory = TempDir::new("require-verification-template");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    std::fs::write(
+        directory.path().join("SHA256SUMS"),
+        format!("{}  afetch\n", sha256_hex(b"binary")),
+    )
+    .unwrap();
+    let template: Url = Url::from_file_path(directory.path().join("SHA256SUMS")).unwrap();
+
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_checksum_url_template(template.as_str())
+        .with_require_verification()
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+}
+
+#[tokio::test]
+async fn api_digest_is_enough_without_checksum_header() {
+    let directory = TempDir::new("require-verification-api-digest");
+    let digest: String = format!("sha256:{}", sha256_hex(b"binary"));
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", Some(&digest))]);
+
+    let mut updater = github_updater(client, directory.path());
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read(directory.path().join("afetch")).unwrap(),
+        b"binary"
+    );
+}
+
+#[tokio::test]
+async fn checksum_asset_is_enough_without_checksum_header() {
+    let directory = TempDir::new("require-verification-checksum-asset");
+    let checksums: String = format!("{}  afetch\n", sha256_hex(b"binary"));
+    let client = MockClient::github_release(
+        "1.0.0",
+        &[
+            ("afetch", b"binary", None),
+            ("SHA256SUMS", checksums.as_bytes(), None),
+        ],
+    );
+
+    let mut updater = github_updater(client, directory.path());
+    updater.force_update().await.unwrap();
+}
+
+#[tokio::test]
+async fn download_without_any_checksum_is_refused() {
+    let directory = TempDir::new("require-verification-none");
+    let client = MockClient::github_release("1.0.0", &[("afetch", b"binary", None)]);
+
+    let mut updater = github_updater(client, directory.path());
+    let error = updater.force_update().await.unwrap_err();
+
+    assert!(
+        error.to_string().contains("cannot be verified"),
+        "{}",
+        error
+    );
+    assert!(!directory.path().join("afetch").exists());
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn write_release(directory: &Path, version: &str) -> PathBuf {
+    let release_file: PathBuf =
+        write_local_release(directory, version, &[("afetch", b"binary", None)]);
+    let mut release: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&release_file).unwrap()).unwrap();
+    release["tag_name"] = serde_json::Value::from(format!("v{}", version));
+    std::fs::write(&release_file, release.to_string()).unwrap();
+
+    release_file
+}
+
+#[tokio::test]
+async fn resolved_release_is_reused_until_its_ttl() {
+    let directory = TempDir::new("resolution-cache");
+    let release_file: PathBuf = write_release(directory.path(), "1.0.0");
+    let cache: PathBuf = directory.path().join("resolved-release.json");
+    let github_updater = |ttl: Duration| {
+        GithubUpdater::builder()
+            .with_initialized_reqwest_client()
+            .with_app_name("afetch")
+            .with_release_source(ReleaseSource::LocalJson(release_file.clone()))
+            .with_release_file_name_pattern("{app_name}")
+            .with_download_path(&directory.path().join("install"))
+            .with_resolution_cache(&cache, ttl)
+            .build()
+            .unwrap()
+    };
+
+    github_updater(Duration::from_secs(60))
+        .force_update()
+        .await
+        .unwrap();
+    assert!(cache.is_file());
+
+    // After a restart, the release is not fetched again, even if it cannot be
+    std::fs::remove_file(&release_file).unwrap();
+    let download_infos = github_updater(Duration::from_secs(60))
+        .force_update()
+        .await
+        .unwrap();
+    assert_eq!(download_infos.new_version, "1.0.0");
+
+    // Once the TTL has elapsed, the release is fetched again
+    assert!(github_updater(Duration::ZERO).force_update().await.is_err());
+
+    // A fetch finding another release invalidates the cache
+    write_release(directory.path(), "2.0.0");
+    let mut updater = github_updater(Duration::from_secs(60));
+    assert!(updater.check_for_update().await.unwrap().update_available);
+    assert!(!cache.exists());
+}
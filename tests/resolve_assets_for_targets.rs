@@ -0,0 +1,37 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn asset_of_each_target_is_resolved() {
+    let directory = TempDir::new("resolve-assets-for-targets");
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[
+            ("afetch-x86_64-unknown-linux-gnu", b"", None),
+            ("afetch-x86_64-pc-windows-msvc", b"", None),
+        ],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}-{rust_target}")
+        .with_rust_target("x86_64-unknown-linux-gnu")
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+
+    let asset_urls = updater
+        .resolve_assets_for_targets(&["x86_64-unknown-linux-gnu", "aarch64-apple-darwin"])
+        .await
+        .unwrap();
+
+    assert!(asset_urls["x86_64-unknown-linux-gnu"]
+        .as_ref()
+        .unwrap()
+        .ends_with("afetch-x86_64-unknown-linux-gnu"));
+    assert_eq!(asset_urls["aarch64-apple-darwin"], None);
+}
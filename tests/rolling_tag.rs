@@ -0,0 +1,38 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::PathBuf;
+
+#[tokio::test]
+async fn same_version_without_published_sha256_is_an_update() {
+    let directory = TempDir::new("rolling-tag");
+    let release_file: PathBuf =
+        write_local_release(directory.path(), "nightly", &[("afetch", b"", None)]);
+    let mut release: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&release_file).unwrap()).unwrap();
+    release["tag_name"] = serde_json::Value::from("nightly");
+    std::fs::write(&release_file, release.to_string()).unwrap();
+    std::fs::write(
+        directory.path().join("binary-version-afetch.txt"),
+        format!(
+            r#"{{"version": "nightly", "sha256": "{}"}}"#,
+            "0".repeat(64)
+        ),
+    )
+    .unwrap();
+
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_json_version_record()
+        .with_rolling_tag("nightly")
+        .build()
+        .unwrap();
+
+    // Same version, and no published SHA-256 to tell whether the asset has changed
+    assert!(updater.check_for_update().await.unwrap().update_available);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::{MockClient, MockResponse, MockServer, TempDir};
+use github_updater::errors::secondary_rate_limited::SecondaryRateLimited;
+use github_updater::GithubUpdater;
+use std::time::Duration;
+
+#[tokio::test]
+async fn secondary_rate_limit_is_reported_distinctly() {
+    let directory = TempDir::new("secondary-rate-limit");
+    // `/limited` is rejected by the secondary rate limits, `/forbidden` by the permissions
+    let server = MockServer::start(|request| {
+        if request.starts_with("GET /limited ") {
+            MockResponse::new(
+                403,
+                r#"{"message": "You have exceeded a secondary rate limit."}"#,
+            )
+            .with_header("Retry-After", "30")
+        } else {
+            MockResponse::new(403, r#"{"message": "Resource not accessible."}"#)
+                .with_header("X-RateLimit-Remaining", "59")
+        }
+    });
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+
+    let error = updater
+        .force_update_from_url(&server.url("/limited"), "1.0.0")
+        .await
+        .unwrap_err();
+    let secondary_rate_limit = SecondaryRateLimited::from_error(&error).unwrap();
+    assert_eq!(
+        secondary_rate_limit.retry_after,
+        Some(Duration::from_secs(30))
+    );
+
+    let error = updater
+        .force_update_from_url(&server.url("/forbidden"), "1.0.0")
+        .await
+        .unwrap_err();
+    assert!(SecondaryRateLimited::from_error(&error).is_none());
+}
+
+#[tokio::test]
+async fn message_is_only_read_from_api_responses() {
+    let directory = TempDir::new("secondary-rate-limit-message");
+    let body: &str = r#"{"message": "You have exceeded a secondary rate limit."}"#;
+    let client = MockClient::new(move |_| MockResponse::new(403, body));
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_retry(0, Duration::ZERO)
+        .build()
+        .unwrap();
+
+    let error = updater.fetch_last_release().await.unwrap_err();
+    assert_eq!(
+        SecondaryRateLimited::from_error(&error),
+        Some(SecondaryRateLimited { retry_after: None })
+    );
+
+    let error = updater
+        .force_update_from_url("https://objects.githubusercontent.com/afetch", "1.0.0")
+        .await
+        .unwrap_err();
+    assert!(SecondaryRateLimited::from_error(&error).is_none());
+}
@@ -0,0 +1,78 @@
+#![cfg(all(unix, any(feature = "sigstore", feature = "provenance")))]
+
+mod common;
+
+use common::{github_release_json, MockClient, MockResponse, TempDir};
+use github_updater::errors::error_kind::ErrorKind;
+use github_updater::GithubUpdater;
+
+/// Serves a release holding `afetch` and the attached file `attachment`, answered with `status`.
+fn client_with_attachment(attachment: &str, status: u16) -> MockClient {
+    let release: String = github_release_json(
+        "1.0.0",
+        &[("afetch", b"binary", None), (attachment, b"{}", None)],
+    );
+
+    MockClient::new(move |request| {
+        let path: &str = request.url.path();
+        if path.ends_with("/releases/latest") {
+            MockResponse::ok(release.clone())
+        } else if path.ends_with("/releases/assets/0") {
+            MockResponse::ok("binary")
+        } else {
+            MockResponse::new(status, "{}")
+        }
+    })
+}
+
+fn builder(client: MockClient, directory: &TempDir) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+}
+
+#[cfg(feature = "sigstore")]
+#[tokio::test]
+async fn invalid_signature_is_a_typed_error() {
+    use github_updater::SigstoreConfig;
+
+    let directory = TempDir::new("signature-invalid");
+    let mut updater = builder(client_with_attachment("afetch.sigstore", 200), &directory)
+        .with_sigstore_verification(SigstoreConfig {
+            identity: "https://github.com/Asthowen/AFetch/.github/workflows/release.yml".to_owned(),
+            issuer: "https://token.actions.githubusercontent.com".to_owned(),
+            cosign_path: Some("false".into()),
+        })
+        .build()
+        .unwrap();
+
+    let error = updater.force_update().await.unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::SignatureVerificationFailed);
+    assert!(!directory.path().join("install/afetch").exists());
+}
+
+#[cfg(feature = "provenance")]
+#[tokio::test]
+async fn missing_attestation_is_a_typed_error() {
+    use github_updater::ProvenanceConfig;
+
+    let directory = TempDir::new("provenance-missing");
+    let mut updater = builder(
+        client_with_attachment("afetch.intoto.jsonl", 404),
+        &directory,
+    )
+    .with_provenance_verification(ProvenanceConfig {
+        builder_id: "https://github.com/slsa-framework/slsa-github-generator".to_owned(),
+        source_uri: None,
+        slsa_verifier_path: Some("true".into()),
+    })
+    .build()
+    .unwrap();
+
+    let error = updater.force_update().await.unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::ProvenanceVerificationFailed);
+    assert!(error.0.contains("404"));
+}
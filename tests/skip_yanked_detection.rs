@@ -0,0 +1,53 @@
+mod common;
+
+use common::{MockClient, MockResponse, TempDir, RELEASES_API_URL};
+use github_updater::GithubUpdater;
+
+fn release(version: u64, draft: bool) -> String {
+    serde_json::json!({
+        "id": version,
+        "name": format!("{}.0.0", version),
+        "draft": draft,
+        "body": null,
+        "published_at": null,
+        "assets": [{
+            "name": "afetch",
+            "url": format!("{}/assets/{}", RELEASES_API_URL, version),
+            "browser_download_url": "afetch",
+        }],
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn pulled_latest_release_falls_back_to_the_previous_one() {
+    let directory = TempDir::new("skip-yanked-detection");
+    // The latest release 2.0.0 is turned into a draft once resolved
+    let client = MockClient::new(|request| {
+        MockResponse::ok(match request.url.path() {
+            "/repos/Asthowen/AFetch/releases/latest" => release(2, false),
+            "/repos/Asthowen/AFetch/releases/2" => release(2, true),
+            "/repos/Asthowen/AFetch/releases" => {
+                format!("[{}, {}]", release(2, true), release(1, false))
+            }
+            asset => format!("binary {}", asset.rsplit('/').next().unwrap()),
+        })
+    });
+    let mut updater = GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_skip_yanked_detection()
+        .build()
+        .unwrap();
+
+    let download_infos = updater.force_update().await.unwrap();
+
+    assert_eq!(download_infos.new_version, "1.0.0");
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("afetch")).unwrap(),
+        "binary 1"
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::path::{Path, PathBuf};
+
+fn github_updater(
+    release_file: PathBuf,
+    download_path: &Path,
+    expected_exit: i32,
+) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("sh")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&download_path)
+        .with_smoke_test(
+            vec!["-c".to_owned(), "echo ready; exit 3".to_owned()],
+            expected_exit,
+        )
+        .with_smoke_test_expected_output("ready")
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn executable_failing_the_smoke_test_is_not_installed() {
+    let directory = TempDir::new("smoke-test");
+    let install: PathBuf = directory.path().join("install");
+    let sh: Vec<u8> = std::fs::read("/bin/sh").unwrap();
+    let release_file = write_local_release(directory.path(), "1.0.0", &[("sh", &sh, None)]);
+
+    assert!(github_updater(release_file.clone(), &install, 0)
+        .force_update()
+        .await
+        .is_err());
+    assert!(!install.join("sh").exists());
+    assert!(github_updater(release_file, &install, 3)
+        .force_update()
+        .await
+        .is_ok());
+}
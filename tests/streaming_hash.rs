@@ -0,0 +1,33 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn recorded_sha256_matches_the_buffered_one() {
+    let directory = TempDir::new("streaming-hash");
+    let content: Vec<u8> = (0..10_000u32)
+        .map(|index| (index * 31 % 251) as u8)
+        .collect();
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", &content, None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_chunk_buffer_size(7)
+        .with_json_version_record()
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    let record = updater.installed_version_record().await.unwrap().unwrap();
+    assert_eq!(record.sha256, Some(sha256_hex(&content)));
+    assert_eq!(
+        std::fs::read(directory.path().join("install/afetch")).unwrap(),
+        content
+    );
+}
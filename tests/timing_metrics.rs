@@ -0,0 +1,29 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+use std::time::Instant;
+
+#[tokio::test]
+async fn timings_do_not_exceed_the_update() {
+    let directory = TempDir::new("timing-metrics");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_timing_metrics()
+        .build()
+        .unwrap();
+
+    let started_at: Instant = Instant::now();
+    let timings = updater.force_update().await.unwrap().timings.unwrap();
+
+    assert!(
+        timings.metadata + timings.download + timings.verification + timings.file_operations
+            <= started_at.elapsed()
+    );
+}
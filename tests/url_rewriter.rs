@@ -0,0 +1,32 @@
+mod common;
+
+use common::{write_local_release, MockResponse, MockServer, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn assets_are_downloaded_from_the_rewritten_url() {
+    let directory = TempDir::new("url-rewriter");
+    let cache = MockServer::start(|_| MockResponse::ok("cached"));
+    let release_file = write_local_release(directory.path(), "1.0.0", &[("afetch", b"", None)]);
+    let cache_url: String = cache.url("/cache");
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path().join("install"))
+        .with_url_rewriter(move |url| {
+            let name: &str = url.rsplit('/').next().unwrap_or_default();
+            format!("{}/{}", cache_url, name)
+        })
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(directory.path().join("install/afetch")).unwrap(),
+        "cached"
+    );
+    assert!(cache.requests()[0].starts_with("GET /cache/afetch "));
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn file_is_named_after_the_asset() {
+    let directory = TempDir::new("use-asset-filename");
+    let install = directory.path().join("install");
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[("afetch-1.0.0-linux", b"binary", None)],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}-{app_version}-linux")
+        .with_download_path(&install)
+        .with_json_version_record()
+        .with_use_asset_filename()
+        .build()
+        .unwrap();
+
+    updater.update_if_needed().await.unwrap();
+    assert!(install.join("afetch-1.0.0-linux").is_file());
+
+    // The file of the previous release is removed
+    write_local_release(
+        directory.path(),
+        "1.1.0",
+        &[("afetch-1.1.0-linux", b"binary", None)],
+    );
+    updater.update_if_needed().await.unwrap();
+    assert!(install.join("afetch-1.1.0-linux").is_file());
+    assert!(!install.join("afetch-1.0.0-linux").exists());
+}
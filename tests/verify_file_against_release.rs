@@ -0,0 +1,34 @@
+mod common;
+
+use common::{sha256_hex, write_local_release, TempDir};
+use github_updater::{GithubUpdater, ReleaseSource};
+
+#[tokio::test]
+async fn file_is_compared_with_the_published_sha256() {
+    let directory = TempDir::new("verify-file-against-release");
+    let digest: String = format!("sha256:{}", sha256_hex(b"binary"));
+    let release_file = write_local_release(
+        directory.path(),
+        "1.0.0",
+        &[("afetch", b"binary", Some(&digest))],
+    );
+    std::fs::write(directory.path().join("deployed"), "binary").unwrap();
+    std::fs::write(directory.path().join("tampered"), "tampered").unwrap();
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .build()
+        .unwrap();
+
+    assert!(updater
+        .verify_file_against_release(&directory.path().join("deployed"))
+        .await
+        .unwrap());
+    assert!(!updater
+        .verify_file_against_release(&directory.path().join("tampered"))
+        .await
+        .unwrap());
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::{MockClient, MockResponse};
+use github_updater::GithubUpdater;
+
+fn github_updater(client: MockClient) -> GithubUpdater {
+    GithubUpdater::builder()
+        .with_http_client(client)
+        .with_app_name("afetch")
+        .with_repository("Asthowen/AFetch")
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&std::env::temp_dir())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn repository_information_is_returned() {
+    let client = MockClient::new(|_| {
+        MockResponse::ok(r#"{"private": false, "default_branch": "main", "archived": false}"#)
+    });
+
+    let repo_info = github_updater(client.clone())
+        .verify_repository()
+        .await
+        .unwrap();
+
+    assert_eq!(client.requests()[0].url.path(), "/repos/Asthowen/AFetch");
+    assert!(!repo_info.private);
+    assert_eq!(repo_info.default_branch, "main");
+}
+
+#[tokio::test]
+async fn inaccessible_repository_is_an_error() {
+    let client = MockClient::new(|_| MockResponse::new(404, ""));
+
+    assert!(github_updater(client).verify_repository().await.is_err());
+}
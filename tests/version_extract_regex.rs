@@ -0,0 +1,27 @@
+mod common;
+
+use common::{write_local_release, TempDir};
+use github_updater::{GithubUpdater, Regex, ReleaseSource};
+
+#[tokio::test]
+async fn version_is_extracted_from_the_release_name() {
+    let directory = TempDir::new("version-extract-regex");
+    let release_file = write_local_release(
+        directory.path(),
+        "MyApp 2.0 (Stable 1.4.2)",
+        &[("afetch", b"", None)],
+    );
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&directory.path())
+        .with_version_extract_regex(Regex::new(r"Stable (\d+\.\d+\.\d+)").unwrap())
+        .build()
+        .unwrap();
+
+    let update_status = updater.check_for_update().await.unwrap();
+
+    assert_eq!(update_status.latest_version, "1.4.2");
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use async_trait::async_trait;
+use common::{write_local_release, TempDir};
+use github_updater::errors::update_error::UpdateError;
+use github_updater::{GithubUpdater, ReleaseSource, VersionStore};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+struct MemoryVersionStore(Arc<Mutex<HashMap<String, String>>>);
+
+#[async_trait]
+impl VersionStore for MemoryVersionStore {
+    async fn load(&self, app_name: &str) -> Result<Option<String>, UpdateError> {
+        Ok(self.0.lock().unwrap().get(app_name).cloned())
+    }
+
+    async fn store(&self, app_name: &str, record: &str) -> Result<(), UpdateError> {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(app_name.to_owned(), record.to_owned());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn version_is_recorded_in_the_store() {
+    let directory = TempDir::new("version-store");
+    let install: PathBuf = directory.path().join("install");
+    let release_file =
+        write_local_release(directory.path(), "1.0.0", &[("afetch", b"binary", None)]);
+    let version_store = MemoryVersionStore::default();
+    let mut updater = GithubUpdater::builder()
+        .with_initialized_reqwest_client()
+        .with_app_name("afetch")
+        .with_release_source(ReleaseSource::LocalJson(release_file))
+        .with_release_file_name_pattern("{app_name}")
+        .with_download_path(&install)
+        .with_version_store(version_store.clone())
+        .build()
+        .unwrap();
+
+    updater.force_update().await.unwrap();
+
+    assert_eq!(
+        version_store.load("afetch").await.unwrap().as_deref(),
+        Some("1.0.0")
+    );
+    assert!(!install.join("binary-version-afetch.txt").exists());
+    assert!(!updater.check_for_update().await.unwrap().update_available);
+}